@@ -224,7 +224,7 @@ pub mod relay_chain_driven {
 		};
 
 		overseer_handle
-			.send_msg(CollationGenerationMessage::Initialize(config), "StartCollator")
+			.send_msg(CollationGenerationMessage::Initialize(config, None), "StartCollator")
 			.await;
 
 		overseer_handle
@@ -249,11 +249,11 @@ pub async fn initialize_collator_subsystems(
 
 	if reinitialize {
 		overseer_handle
-			.send_msg(CollationGenerationMessage::Reinitialize(config), "StartCollator")
+			.send_msg(CollationGenerationMessage::Reinitialize(config, None), "StartCollator")
 			.await;
 	} else {
 		overseer_handle
-			.send_msg(CollationGenerationMessage::Initialize(config), "StartCollator")
+			.send_msg(CollationGenerationMessage::Initialize(config, None), "StartCollator")
 			.await;
 	}
 
@@ -436,10 +436,10 @@ mod tests {
 			.expect("message should be send by `start_collator` above.");
 
 		let collator_fn = match msg {
-			CollationGenerationMessage::Initialize(CollationGenerationConfig {
-				collator: Some(c),
-				..
-			}) => c,
+			CollationGenerationMessage::Initialize(
+				CollationGenerationConfig { collator: Some(c), .. },
+				_,
+			) => c,
 			_ => panic!("unexpected message or no collator fn"),
 		};
 
@@ -469,6 +469,7 @@ where
 										result_sender: None,
 										core_index,
 									},
+									None,
 								),
 								"SubmitCollation",
 							)
@@ -150,14 +150,17 @@ async fn handle_collation_message<Block: BlockT>(
 	tracing::debug!(target: LOG_TARGET, ?core_index, %hash, %number, "Submitting collation for core.");
 	overseer_handle
 		.send_msg(
-			CollationGenerationMessage::SubmitCollation(SubmitCollationParams {
-				relay_parent,
-				collation,
-				parent_head: parent_header.encode().into(),
-				validation_code_hash,
-				core_index,
-				result_sender: None,
-			}),
+			CollationGenerationMessage::SubmitCollation(
+				SubmitCollationParams {
+					relay_parent,
+					collation,
+					parent_head: parent_header.encode().into(),
+					validation_code_hash,
+					core_index,
+					result_sender: None,
+				},
+				None,
+			),
 			"SubmitCollation",
 		)
 		.await;
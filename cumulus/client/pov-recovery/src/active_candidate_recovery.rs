@@ -18,7 +18,7 @@
 use sp_runtime::traits::Block as BlockT;
 
 use polkadot_node_primitives::PoV;
-use polkadot_node_subsystem::messages::AvailabilityRecoveryMessage;
+use polkadot_node_subsystem::messages::{AvailabilityRecoveryMessage, RecoveryStrategy};
 
 use futures::{channel::oneshot, stream::FuturesUnordered, Future, FutureExt, StreamExt};
 
@@ -56,6 +56,7 @@ impl<Block: BlockT> ActiveCandidateRecovery<Block> {
 					candidate.session_index,
 					None,
 					None,
+					RecoveryStrategy::FullNetwork,
 					tx,
 				),
 				"ActiveCandidateRecovery",
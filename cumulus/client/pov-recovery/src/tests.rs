@@ -31,7 +31,10 @@ use cumulus_test_client::{
 };
 use futures::{channel::mpsc, SinkExt};
 use polkadot_node_primitives::AvailableData;
-use polkadot_node_subsystem::{messages::AvailabilityRecoveryMessage, RecoveryError, TimeoutExt};
+use polkadot_node_subsystem::{
+	messages::{AvailabilityRecoveryMessage, RecoveryStrategy},
+	RecoveryError, TimeoutExt,
+};
 use rstest::rstest;
 use sc_client_api::{
 	BlockImportNotification, ClientInfo, CompactProof, FinalityNotification, FinalityNotifications,
@@ -685,6 +688,7 @@ async fn single_pending_candidate_recovery_success(#[case] runtime_version: u32)
 			session_index,
 			None,
 			None,
+			RecoveryStrategy::FullNetwork,
 			response_tx
 		)) => {
 			assert_eq!(receipt.hash(), candidate_hash);
@@ -769,6 +773,7 @@ async fn single_pending_candidate_recovery_retry_succeeds() {
 			session_index,
 			None,
 			None,
+			RecoveryStrategy::FullNetwork,
 			response_tx
 		)) => {
 			assert_eq!(receipt.hash(), candidate_hash);
@@ -789,6 +794,7 @@ async fn single_pending_candidate_recovery_retry_succeeds() {
 			session_index,
 			None,
 			None,
+			RecoveryStrategy::FullNetwork,
 			response_tx
 		)) => {
 			assert_eq!(receipt.hash(), candidate_hash);
@@ -872,6 +878,7 @@ async fn single_pending_candidate_recovery_retry_fails() {
 			session_index,
 			None,
 			None,
+			RecoveryStrategy::FullNetwork,
 			response_tx
 		)) => {
 			assert_eq!(receipt.hash(), candidate_hash);
@@ -892,6 +899,7 @@ async fn single_pending_candidate_recovery_retry_fails() {
 			session_index,
 			None,
 			None,
+			RecoveryStrategy::FullNetwork,
 			response_tx
 		)) => {
 			assert_eq!(receipt.hash(), candidate_hash);
@@ -958,6 +966,7 @@ async fn single_pending_candidate_recovery_irrecoverable_error() {
 			session_index,
 			None,
 			None,
+			RecoveryStrategy::FullNetwork,
 			response_tx
 		)) => {
 			assert_eq!(receipt.hash(), candidate_hash);
@@ -1080,6 +1089,7 @@ async fn candidate_is_imported_while_awaiting_recovery() {
 			session_index,
 			None,
 			None,
+			RecoveryStrategy::FullNetwork,
 			response_tx
 		)) => {
 			assert_eq!(receipt.hash(), candidate_hash);
@@ -1181,6 +1191,7 @@ async fn candidate_is_finalized_while_awaiting_recovery() {
 			session_index,
 			None,
 			None,
+			RecoveryStrategy::FullNetwork,
 			response_tx
 		)) => {
 			assert_eq!(receipt.hash(), candidate_hash);
@@ -1283,6 +1294,7 @@ async fn chained_recovery_success() {
 				session_index,
 				None,
 				None,
+				RecoveryStrategy::FullNetwork,
 				response_tx
 			)) => {
 				assert_eq!(receipt.hash(), candidate_hash);
@@ -1391,6 +1403,7 @@ async fn chained_recovery_child_succeeds_before_parent() {
 				session_index,
 				None,
 				None,
+				RecoveryStrategy::FullNetwork,
 				response_tx
 			)) => {
 				assert_eq!(receipt.hash(), *candidate_hash);
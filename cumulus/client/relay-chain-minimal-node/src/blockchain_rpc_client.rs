@@ -469,6 +469,53 @@ impl RuntimeApiSubsystemClient for BlockChainRpcClient {
 	async fn scheduling_lookahead(&self, at: Hash) -> Result<u32, sp_api::ApiError> {
 		Ok(self.rpc_client.parachain_host_scheduling_lookahead(at).await?)
 	}
+
+	async fn inbound_hrmp_channels_contents_all(
+		&self,
+		at: Hash,
+	) -> Result<
+		BTreeMap<
+			ParaId,
+			BTreeMap<ParaId, Vec<polkadot_core_primitives::InboundHrmpMessage<BlockNumber>>>,
+		>,
+		sp_api::ApiError,
+	> {
+		Ok(self.rpc_client.parachain_host_inbound_hrmp_channels_contents_all(at).await?)
+	}
+
+	async fn minimum_backing_votes_for_paras(
+		&self,
+		at: Hash,
+		para_ids: Vec<ParaId>,
+	) -> Result<Vec<u32>, ApiError> {
+		Ok(self
+			.rpc_client
+			.parachain_host_minimum_backing_votes_for_paras(at, para_ids)
+			.await?)
+	}
+
+	async fn unapplied_slashes_for_session(
+		&self,
+		at: Hash,
+		session_index: polkadot_primitives::SessionIndex,
+	) -> Result<Vec<(polkadot_primitives::CandidateHash, slashing::PendingSlashes)>, ApiError> {
+		Ok(self
+			.rpc_client
+			.parachain_host_unapplied_slashes_for_session(at, session_index)
+			.await?)
+	}
+
+	async fn key_ownership_proof_for_session(
+		&self,
+		at: Hash,
+		session_index: polkadot_primitives::SessionIndex,
+		validator_id: polkadot_primitives::ValidatorId,
+	) -> Result<Option<slashing::OpaqueKeyOwnershipProof>, ApiError> {
+		Ok(self
+			.rpc_client
+			.parachain_host_key_ownership_proof_for_session(at, session_index, validator_id)
+			.await?)
+	}
 }
 
 #[async_trait::async_trait]
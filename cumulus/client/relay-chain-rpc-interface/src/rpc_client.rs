@@ -715,6 +715,62 @@ impl RelayChainRpcClient {
 			.await
 	}
 
+	/// Get the contents of all inbound HRMP channels, keyed by recipient and then by sender.
+	pub async fn parachain_host_inbound_hrmp_channels_contents_all(
+		&self,
+		at: RelayHash,
+	) -> Result<BTreeMap<ParaId, BTreeMap<ParaId, Vec<InboundHrmpMessage>>>, RelayChainError> {
+		self.call_remote_runtime_function(
+			"ParachainHost_inbound_hrmp_channels_contents_all",
+			at,
+			None::<()>,
+		)
+		.await
+	}
+
+	/// Get the minimum number of backing votes for a candidate of each of the given paras.
+	pub async fn parachain_host_minimum_backing_votes_for_paras(
+		&self,
+		at: RelayHash,
+		para_ids: Vec<ParaId>,
+	) -> Result<Vec<u32>, RelayChainError> {
+		self.call_remote_runtime_function(
+			"ParachainHost_minimum_backing_votes_for_paras",
+			at,
+			Some(para_ids),
+		)
+		.await
+	}
+
+	/// Returns the validators that lost a dispute in the given session and need to be slashed.
+	pub async fn parachain_host_unapplied_slashes_for_session(
+		&self,
+		at: RelayHash,
+		session_index: SessionIndex,
+	) -> Result<Vec<(CandidateHash, slashing::PendingSlashes)>, RelayChainError> {
+		self.call_remote_runtime_function(
+			"ParachainHost_unapplied_slashes_for_session",
+			at,
+			Some(session_index),
+		)
+		.await
+	}
+
+	/// Returns a merkle proof of a validator session key in the given session.
+	pub async fn parachain_host_key_ownership_proof_for_session(
+		&self,
+		at: RelayHash,
+		session_index: SessionIndex,
+		validator_id: ValidatorId,
+	) -> Result<Option<slashing::OpaqueKeyOwnershipProof>, RelayChainError> {
+		self.call_remote_runtime_function(
+			"ParachainHost_key_ownership_proof_for_session",
+			at,
+			Some((session_index, validator_id)),
+		)
+		.await
+	}
+
 	pub async fn validation_code_hash(
 		&self,
 		at: RelayHash,
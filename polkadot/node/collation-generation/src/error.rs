@@ -37,6 +37,8 @@ pub enum Error {
 	CandidateReceiptCheck(CommittedCandidateReceiptError),
 	#[error("PoV size {0} exceeded maximum size of {1}")]
 	POVSizeExceeded(usize, usize),
+	#[error("Invalid collation params submitted: {0}")]
+	InvalidSubmitCollationParams(polkadot_node_subsystem::messages::SubmitCollationError),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -35,11 +35,14 @@ use codec::Encode;
 use error::{Error, Result};
 use futures::{channel::oneshot, future::FutureExt, select};
 use polkadot_node_primitives::{
-	AvailableData, Collation, CollationGenerationConfig, CollationSecondedSignal, PoV,
-	SubmitCollationParams,
+	AvailableData, Collation, CollationGenerationConfig, CollationSecondedSignal,
+	MaybeCompressedPoV, PoV, SubmitCollationParams, POV_BOMB_LIMIT,
 };
 use polkadot_node_subsystem::{
-	messages::{CollationGenerationMessage, CollatorProtocolMessage, RuntimeApiMessage},
+	messages::{
+		CollationGenerationMessage, CollatorProtocolMessage, RuntimeApiMessage,
+		SubmitCollationError,
+	},
 	overseer, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, SpawnedSubsystem,
 	SubsystemContext, SubsystemError, SubsystemResult, SubsystemSender,
 };
@@ -133,25 +136,44 @@ impl CollationGenerationSubsystem {
 			},
 			Ok(FromOrchestra::Signal(OverseerSignal::Conclude)) => true,
 			Ok(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::Initialize(config),
+				msg: CollationGenerationMessage::Initialize(config, initialized),
 			}) => {
 				if self.config.is_some() {
 					gum::error!(target: LOG_TARGET, "double initialization");
 				} else {
 					self.config = Some(Arc::new(config));
+					if let Some(initialized) = initialized {
+						let _ = initialized.send(());
+					}
 				}
 				false
 			},
 			Ok(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::Reinitialize(config),
+				msg: CollationGenerationMessage::Reinitialize(config, initialized),
 			}) => {
 				self.config = Some(Arc::new(config));
+				if let Some(initialized) = initialized {
+					let _ = initialized.send(());
+				}
+				false
+			},
+			Ok(FromOrchestra::Communication { msg: CollationGenerationMessage::Shutdown }) => {
+				if let Some(config) = self.config.take() {
+					gum::info!(
+						target: LOG_TARGET,
+						para_id = %config.para_id,
+						"Stopping collation generation",
+					);
+					ctx.send_message(CollatorProtocolMessage::StopCollating).await;
+				} else {
+					gum::debug!(target: LOG_TARGET, "Shutdown requested before initialization");
+				}
 				false
 			},
 			Ok(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::SubmitCollation(params),
+				msg: CollationGenerationMessage::SubmitCollation(params, response_sender),
 			}) => {
-				if let Err(err) = self.handle_submit_collation(params, ctx).await {
+				if let Err(err) = self.handle_submit_collation(params, response_sender, ctx).await {
 					gum::error!(target: LOG_TARGET, ?err, "Failed to submit collation");
 				}
 
@@ -173,9 +195,17 @@ impl CollationGenerationSubsystem {
 	async fn handle_submit_collation<Context>(
 		&mut self,
 		params: SubmitCollationParams,
+		response_sender: Option<oneshot::Sender<std::result::Result<(), SubmitCollationError>>>,
 		ctx: &mut Context,
 	) -> Result<()> {
+		let respond = |result| {
+			if let Some(sender) = response_sender {
+				let _ = sender.send(result);
+			}
+		};
+
 		let Some(config) = &self.config else {
+			respond(Err(SubmitCollationError::NotInitialized));
 			return Err(Error::SubmittedBeforeInit);
 		};
 		let _timer = self.metrics.time_submit_collation();
@@ -206,10 +236,18 @@ impl CollationGenerationSubsystem {
 					our_para = %config.para_id,
 					"No validation data for para - does it exist at this relay-parent?",
 				);
+				respond(Err(SubmitCollationError::NoValidationData(relay_parent)));
 				return Ok(())
 			},
 		};
 
+		if let Err(err) = check_pov_size(&collation.proof_of_validity, validation_data.max_pov_size)
+		{
+			respond(Err(err.clone()));
+			return Err(Error::InvalidSubmitCollationParams(err))
+		}
+		respond(Ok(()));
+
 		// We need to swap the parent-head data, but all other fields here will be correct.
 		validation_data.parent_head = parent_head;
 
@@ -526,6 +564,30 @@ impl SessionInfoCache {
 	}
 }
 
+/// Rejects the PoVs which are known to exceed the maximum PoV size before the candidate is
+/// constructed.
+///
+/// Compressed PoVs are checked right away, while raw PoVs are only rejected if they are too large
+/// to be compressed at all. The final check is done once the PoV is compressed.
+fn check_pov_size(
+	pov: &MaybeCompressedPoV,
+	max_pov_size: u32,
+) -> std::result::Result<(), SubmitCollationError> {
+	let max_pov_size = max_pov_size as usize;
+	let encoded_size = match pov {
+		MaybeCompressedPoV::Compressed(pov) => pov.encoded_size(),
+		MaybeCompressedPoV::Raw(pov) if pov.block_data.0.len() > POV_BOMB_LIMIT =>
+			pov.encoded_size(),
+		MaybeCompressedPoV::Raw(_) => return Ok(()),
+	};
+
+	if encoded_size > max_pov_size {
+		return Err(SubmitCollationError::PoVSizeExceeded(encoded_size, max_pov_size))
+	}
+
+	Ok(())
+}
+
 struct PreparedCollation {
 	collation: Collation,
 	para_id: ParaId,
@@ -179,19 +179,156 @@ fn node_features_with_v2_enabled() -> NodeFeatures {
 #[test]
 fn submit_collation_is_no_op_before_initialization() {
 	test_harness(|mut virtual_overseer| async move {
+		let (tx, rx) = oneshot::channel();
 		virtual_overseer
 			.send(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::SubmitCollation(SubmitCollationParams {
-					relay_parent: Hash::repeat_byte(0),
-					collation: test_collation(),
-					parent_head: vec![1, 2, 3].into(),
-					validation_code_hash: Hash::repeat_byte(1).into(),
-					result_sender: None,
-					core_index: CoreIndex(0),
-				}),
+				msg: CollationGenerationMessage::SubmitCollation(
+					SubmitCollationParams {
+						relay_parent: Hash::repeat_byte(0),
+						collation: test_collation(),
+						parent_head: vec![1, 2, 3].into(),
+						validation_code_hash: Hash::repeat_byte(1).into(),
+						result_sender: None,
+						core_index: CoreIndex(0),
+					},
+					Some(tx),
+				),
 			})
 			.await;
 
+		assert_eq!(rx.await.unwrap(), Err(SubmitCollationError::NotInitialized));
+
+		virtual_overseer
+	});
+}
+
+#[test]
+fn submit_collation_rejects_oversized_pov() {
+	let relay_parent = Hash::repeat_byte(0);
+	let para_id = ParaId::from(5);
+	let expected_pvd = PersistedValidationData {
+		parent_head: dummy_head_data(),
+		relay_parent_number: 10,
+		relay_parent_storage_root: Hash::repeat_byte(1),
+		max_pov_size: 1024,
+	};
+	let mut collation = test_collation();
+	collation.proof_of_validity =
+		MaybeCompressedPoV::Compressed(PoV { block_data: BlockData(vec![0; 2048]) });
+	let pov_size = PoV { block_data: BlockData(vec![0; 2048]) }.encoded_size();
+
+	test_harness(|mut virtual_overseer| async move {
+		virtual_overseer
+			.send(FromOrchestra::Communication {
+				msg: CollationGenerationMessage::Initialize(test_config_no_collator(para_id), None),
+			})
+			.await;
+
+		let (tx, rx) = oneshot::channel();
+		virtual_overseer
+			.send(FromOrchestra::Communication {
+				msg: CollationGenerationMessage::SubmitCollation(
+					SubmitCollationParams {
+						relay_parent,
+						collation,
+						parent_head: dummy_head_data(),
+						validation_code_hash: Hash::repeat_byte(1).into(),
+						result_sender: None,
+						core_index: CoreIndex(0),
+					},
+					Some(tx),
+				),
+			})
+			.await;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(rp, RuntimeApiRequest::PersistedValidationData(id, _, tx))) => {
+				assert_eq!(rp, relay_parent);
+				assert_eq!(id, para_id);
+				tx.send(Ok(Some(expected_pvd))).unwrap();
+			}
+		);
+
+		// The collation is rejected before any further runtime request.
+		assert_eq!(rx.await.unwrap(), Err(SubmitCollationError::PoVSizeExceeded(pov_size, 1024)));
+
+		virtual_overseer
+	});
+}
+
+#[test]
+fn shutdown_stops_collation_generation() {
+	test_harness(|mut virtual_overseer| async move {
+		virtual_overseer
+			.send(FromOrchestra::Communication {
+				msg: CollationGenerationMessage::Initialize(test_config_no_collator(5), None),
+			})
+			.await;
+
+		virtual_overseer
+			.send(FromOrchestra::Communication { msg: CollationGenerationMessage::Shutdown })
+			.await;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::CollatorProtocol(CollatorProtocolMessage::StopCollating)
+		);
+
+		// Collations are no longer accepted.
+		virtual_overseer
+			.send(FromOrchestra::Communication {
+				msg: CollationGenerationMessage::SubmitCollation(
+					SubmitCollationParams {
+						relay_parent: Hash::repeat_byte(0),
+						collation: test_collation(),
+						parent_head: vec![1, 2, 3].into(),
+						validation_code_hash: Hash::repeat_byte(1).into(),
+						result_sender: None,
+						core_index: CoreIndex(0),
+					},
+					None,
+				),
+			})
+			.await;
+
+		// Repeated shutdown is a no-op.
+		virtual_overseer
+			.send(FromOrchestra::Communication { msg: CollationGenerationMessage::Shutdown })
+			.await;
+
+		virtual_overseer
+	});
+}
+
+#[test]
+fn initialization_is_acknowledged() {
+	test_harness(|mut virtual_overseer| async move {
+		let (tx, rx) = oneshot::channel();
+		virtual_overseer
+			.send(FromOrchestra::Communication {
+				msg: CollationGenerationMessage::Initialize(test_config_no_collator(5), Some(tx)),
+			})
+			.await;
+		assert_matches!(rx.await, Ok(()));
+
+		// Double initialization is rejected, so it is not acknowledged.
+		let (tx, rx) = oneshot::channel();
+		virtual_overseer
+			.send(FromOrchestra::Communication {
+				msg: CollationGenerationMessage::Initialize(test_config_no_collator(6), Some(tx)),
+			})
+			.await;
+		assert_matches!(rx.await, Err(oneshot::Canceled));
+
+		let (tx, rx) = oneshot::channel();
+		virtual_overseer
+			.send(FromOrchestra::Communication {
+				msg: CollationGenerationMessage::Reinitialize(test_config_no_collator(6), Some(tx)),
+			})
+			.await;
+		assert_matches!(rx.await, Ok(()));
+
 		virtual_overseer
 	});
 }
@@ -212,20 +349,24 @@ fn submit_collation_leads_to_distribution() {
 	test_harness(|mut virtual_overseer| async move {
 		virtual_overseer
 			.send(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::Initialize(test_config_no_collator(para_id)),
+				msg: CollationGenerationMessage::Initialize(test_config_no_collator(para_id), None),
 			})
 			.await;
 
+		let (tx, rx) = oneshot::channel();
 		virtual_overseer
 			.send(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::SubmitCollation(SubmitCollationParams {
-					relay_parent,
-					collation: test_collation(),
-					parent_head: dummy_head_data(),
-					validation_code_hash,
-					result_sender: None,
-					core_index: CoreIndex(0),
-				}),
+				msg: CollationGenerationMessage::SubmitCollation(
+					SubmitCollationParams {
+						relay_parent,
+						collation: test_collation(),
+						parent_head: dummy_head_data(),
+						validation_code_hash,
+						result_sender: None,
+						core_index: CoreIndex(0),
+					},
+					Some(tx),
+				),
 			})
 			.await;
 
@@ -239,6 +380,8 @@ fn submit_collation_leads_to_distribution() {
 		)
 		.await;
 
+		assert_eq!(rx.await.unwrap(), Ok(()));
+
 		assert_matches!(
 			overseer_recv(&mut virtual_overseer).await,
 			AllMessages::CollatorProtocol(CollatorProtocolMessage::DistributeCollation {
@@ -476,20 +619,23 @@ fn test_candidate_receipt_versioning(#[case] v2_receipts: bool) {
 	test_harness(|mut virtual_overseer| async move {
 		virtual_overseer
 			.send(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::Initialize(test_config_no_collator(para_id)),
+				msg: CollationGenerationMessage::Initialize(test_config_no_collator(para_id), None),
 			})
 			.await;
 
 		virtual_overseer
 			.send(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::SubmitCollation(SubmitCollationParams {
-					relay_parent,
-					collation: test_collation(),
-					parent_head: dummy_head_data(),
-					validation_code_hash,
-					result_sender: None,
-					core_index: CoreIndex(0),
-				}),
+				msg: CollationGenerationMessage::SubmitCollation(
+					SubmitCollationParams {
+						relay_parent,
+						collation: test_collation(),
+						parent_head: dummy_head_data(),
+						validation_code_hash,
+						result_sender: None,
+						core_index: CoreIndex(0),
+					},
+					None,
+				),
 			})
 			.await;
 
@@ -540,20 +686,23 @@ fn v2_receipts_failed_core_index_check() {
 	test_harness(|mut virtual_overseer| async move {
 		virtual_overseer
 			.send(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::Initialize(test_config_no_collator(para_id)),
+				msg: CollationGenerationMessage::Initialize(test_config_no_collator(para_id), None),
 			})
 			.await;
 
 		virtual_overseer
 			.send(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::SubmitCollation(SubmitCollationParams {
-					relay_parent,
-					collation: test_collation(),
-					parent_head: dummy_head_data(),
-					validation_code_hash,
-					result_sender: None,
-					core_index: CoreIndex(0),
-				}),
+				msg: CollationGenerationMessage::SubmitCollation(
+					SubmitCollationParams {
+						relay_parent,
+						collation: test_collation(),
+						parent_head: dummy_head_data(),
+						validation_code_hash,
+						result_sender: None,
+						core_index: CoreIndex(0),
+					},
+					None,
+				),
 			})
 			.await;
 
@@ -585,10 +734,10 @@ mod helpers {
 	) {
 		virtual_overseer
 			.send(FromOrchestra::Communication {
-				msg: CollationGenerationMessage::Initialize(test_config(
-					para_id,
-					core_selector_data,
-				)),
+				msg: CollationGenerationMessage::Initialize(
+					test_config(para_id, core_selector_data),
+					None,
+				),
 			})
 			.await;
 	}
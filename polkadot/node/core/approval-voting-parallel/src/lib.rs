@@ -346,11 +346,12 @@ async fn run_main_loop<Context>(
 					FromOrchestra::Communication { msg } => match msg {
 						// The message the approval voting subsystem would've handled.
 						ApprovalVotingParallelMessage::ApprovedAncestor(_, _,_) |
-						ApprovalVotingParallelMessage::GetApprovalSignaturesForCandidate(_, _)  => {
+						ApprovalVotingParallelMessage::GetApprovalSignaturesForCandidate(_, _) |
+						ApprovalVotingParallelMessage::GetApprovedCandidates(_, _) => {
 							to_approval_voting_worker.send_message(
 								msg.try_into().expect(
-									"Message is one of ApprovedAncestor, GetApprovalSignaturesForCandidate
-									 and that can be safely converted to ApprovalVotingMessage; qed"
+									"Message is one of ApprovedAncestor, GetApprovalSignaturesForCandidate,
+									 GetApprovedCandidates and that can be safely converted to ApprovalVotingMessage; qed"
 								)
 							).await;
 						},
@@ -422,6 +423,17 @@ async fn run_main_loop<Context>(
 									).await;
 							}
 						},
+						ApprovalVotingParallelMessage::GetAssignmentsForBlock(hash, tx) => {
+							handle_get_assignments_for_block(&mut ctx, &mut to_approval_distribution_workers, hash, tx).await;
+						},
+						ApprovalVotingParallelMessage::PurgeBlock(hash) => {
+							for worker in to_approval_distribution_workers.iter_mut() {
+								worker
+									.send_message(
+										ApprovalDistributionMessage::PurgeBlock(hash)
+									).await;
+							}
+						},
 					},
 				};
 
@@ -492,6 +504,62 @@ async fn handle_get_approval_signatures<Context>(
 	}
 }
 
+// It sends a message to all approval workers to get the assignments they know for the requested
+// block and then merges them all together and sends them back to the requester.
+#[overseer::contextbounds(ApprovalVotingParallel, prefix = self::overseer)]
+async fn handle_get_assignments_for_block<Context>(
+	ctx: &mut Context,
+	to_approval_distribution_workers: &mut Vec<ToWorker<ApprovalDistributionMessage>>,
+	block_hash: Hash,
+	result_channel: oneshot::Sender<Vec<(ValidatorIndex, CandidateIndex)>>,
+) {
+	let mut assignments_channels = Vec::new();
+	for worker in to_approval_distribution_workers.iter_mut() {
+		let (tx, rx) = oneshot::channel();
+		worker.send_unbounded_message(ApprovalDistributionMessage::GetAssignmentsForBlock(
+			block_hash, tx,
+		));
+		assignments_channels.push(rx);
+	}
+
+	let gather_assignments = async move {
+		let Some(results) = futures::future::join_all(assignments_channels)
+			.timeout(WAIT_FOR_SIGS_GATHER_TIMEOUT)
+			.await
+		else {
+			gum::warn!(target: LOG_TARGET, "Waiting for assignments timed out - dead lock?");
+			return;
+		};
+
+		let mut assignments = Vec::new();
+		for result in results {
+			match result {
+				Ok(worker_assignments) => assignments.extend(worker_assignments),
+				Err(_) => {
+					gum::error!(
+						target: LOG_TARGET,
+						"Getting assignments failed, oneshot got closed"
+					);
+				},
+			}
+		}
+		assignments.sort();
+		assignments.dedup();
+
+		if let Err(_) = result_channel.send(assignments) {
+			gum::debug!(
+				target: LOG_TARGET,
+				"Sending back assignments failed, oneshot got closed"
+			);
+		}
+	};
+
+	if let Err(err) = ctx.spawn("approval-voting-gather-assignments", Box::pin(gather_assignments))
+	{
+		gum::warn!(target: LOG_TARGET, "Failed to spawn gather assignments task: {:?}", err);
+	}
+}
+
 // Returns the worker that should receive the message for the given validator.
 fn assigned_worker_for_validator(
 	validator: ValidatorIndex,
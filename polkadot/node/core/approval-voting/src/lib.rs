@@ -39,8 +39,8 @@ use polkadot_node_subsystem::{
 		ApprovalVotingMessage, AssignmentCheckError, AssignmentCheckResult,
 		AvailabilityRecoveryMessage, BlockDescription, CandidateValidationMessage, ChainApiMessage,
 		ChainSelectionMessage, CheckedIndirectAssignment, CheckedIndirectSignedApprovalVote,
-		DisputeCoordinatorMessage, HighestApprovedAncestorBlock, PvfExecKind, RuntimeApiMessage,
-		RuntimeApiRequest,
+		DisputeCoordinatorMessage, HighestApprovedAncestorBlock, PvfExecKind, RecoveryStrategy,
+		RuntimeApiMessage, RuntimeApiRequest,
 	},
 	overseer, FromOrchestra, OverseerSignal, SpawnedSubsystem, SubsystemError, SubsystemResult,
 	SubsystemSender,
@@ -2163,6 +2163,14 @@ async fn handle_from_overseer<
 				.await?;
 				Vec::new()
 			},
+			ApprovalVotingMessage::GetApprovedCandidates(block_hash, tx) => {
+				let approved = db
+					.load_block_entry(&block_hash)?
+					.map(|entry| entry.approved_candidates().collect())
+					.unwrap_or_default();
+				let _ = tx.send(approved);
+				Vec::new()
+			},
 		},
 	};
 
@@ -3506,6 +3514,7 @@ async fn launch_approval<
 			session_index,
 			Some(backing_group),
 			core_index,
+			RecoveryStrategy::BackingGroupFirst,
 			a_tx,
 		))
 		.await;
@@ -3618,6 +3627,8 @@ async fn launch_approval<
 				pov: available_data.pov,
 				executor_params,
 				exec_kind: PvfExecKind::Approval,
+				exec_timeout: None,
+				memory_limit_override: None,
 				response_sender: val_tx,
 			})
 			.await;
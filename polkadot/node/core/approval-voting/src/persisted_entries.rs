@@ -519,6 +519,17 @@ impl BlockEntry {
 		})
 	}
 
+	/// Iterate over all approved candidates.
+	pub fn approved_candidates(&self) -> impl Iterator<Item = CandidateHash> + '_ {
+		self.approved_bitfield.iter().enumerate().filter_map(move |(i, a)| {
+			if *a {
+				Some(self.candidates[i].1)
+			} else {
+				None
+			}
+		})
+	}
+
 	/// Get the slot of the block.
 	pub fn slot(&self) -> Slot {
 		self.slot
@@ -2840,6 +2840,104 @@ fn subsystem_approved_ancestor_missing_approval() {
 	approved_ancestor_test(|i| i == 3, 2);
 }
 
+#[test]
+fn subsystem_returns_approved_candidates_of_block() {
+	test_harness(HarnessConfig::default(), |test_harness| async move {
+		let TestHarness { mut virtual_overseer, sync_oracle_handle: _sync_oracle_handle, .. } =
+			test_harness;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::ChainApi(ChainApiMessage::FinalizedBlockNumber(rx)) => {
+				rx.send(Ok(0)).unwrap();
+			}
+		);
+
+		let block_hashes = vec![Hash::repeat_byte(0x01), Hash::repeat_byte(0x02)];
+
+		let candidate_receipts: Vec<_> = block_hashes
+			.iter()
+			.enumerate()
+			.map(|(i, hash)| {
+				let mut candidate_receipt = dummy_candidate_receipt_v2(*hash);
+				candidate_receipt.descriptor.set_para_id(i.into());
+				candidate_receipt
+			})
+			.collect();
+
+		let candidate_hashes: Vec<_> = candidate_receipts.iter().map(|r| r.hash()).collect();
+
+		let candidate_index = 0;
+		let validator = ValidatorIndex(0);
+
+		let mut builder = ChainBuilder::new();
+		for (i, (block_hash, candidate_receipt)) in
+			block_hashes.iter().zip(candidate_receipts).enumerate()
+		{
+			let parent_hash = if i == 0 { ChainBuilder::GENESIS_HASH } else { block_hashes[i - 1] };
+			builder.add_block(
+				*block_hash,
+				parent_hash,
+				i as u32 + 1,
+				BlockConfig {
+					slot: Slot::from(i as u64),
+					candidates: Some(vec![(candidate_receipt, CoreIndex(0), GroupIndex(0))]),
+					session_info: None,
+					end_syncing: false,
+				},
+			);
+		}
+		builder.build(&mut virtual_overseer).await;
+
+		for block_hash in &block_hashes {
+			let rx = import_assignment(
+				&mut virtual_overseer,
+				*block_hash,
+				candidate_index,
+				validator,
+				0,
+			)
+			.await;
+			assert_eq!(rx.await, Ok(AssignmentCheckResult::Accepted));
+		}
+
+		// Only approve the candidate of the first block.
+		let rx = import_approval(
+			&mut virtual_overseer,
+			block_hashes[0],
+			candidate_index,
+			validator,
+			candidate_hashes[0],
+			1,
+			true,
+			None,
+		)
+		.await;
+		assert_eq!(rx.await, Ok(ApprovalCheckResult::Accepted));
+
+		let expected = [
+			(block_hashes[0], vec![candidate_hashes[0]]),
+			(block_hashes[1], Vec::new()),
+			(Hash::repeat_byte(0xff), Vec::new()),
+		];
+
+		for (block_hash, approved) in expected {
+			let (tx, rx) = oneshot::channel();
+			overseer_send(
+				&mut virtual_overseer,
+				FromOrchestra::Communication {
+					msg: ApprovalVotingMessage::GetApprovedCandidates(block_hash, tx),
+				},
+			)
+			.await;
+
+			assert_eq!(rx.await.unwrap(), approved);
+		}
+
+		virtual_overseer
+	});
+}
+
 #[test]
 fn subsystem_validate_approvals_cache() {
 	let assignment_criteria = Box::new(MockAssignmentCriteria(
@@ -3199,7 +3297,7 @@ async fn recover_available_data(virtual_overseer: &mut VirtualOverseer) {
 	assert_matches!(
 		virtual_overseer.recv().await,
 		AllMessages::AvailabilityRecovery(
-			AvailabilityRecoveryMessage::RecoverAvailableData(_, _, _, _, tx)
+			AvailabilityRecoveryMessage::RecoverAvailableData(_, _, _, _, _, tx)
 		) => {
 			tx.send(Ok(available_data)).unwrap();
 		},
@@ -3213,7 +3311,7 @@ async fn recover_available_data_failure(virtual_overseer: &mut VirtualOverseer)
 	assert_matches!(
 		virtual_overseer.recv().await,
 		AllMessages::AvailabilityRecovery(
-			AvailabilityRecoveryMessage::RecoverAvailableData(_, _, _, _, tx)
+			AvailabilityRecoveryMessage::RecoverAvailableData(_, _, _, _, _, tx)
 		) => {
 			tx.send(Err(available_data)).unwrap();
 		},
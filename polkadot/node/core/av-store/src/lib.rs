@@ -42,7 +42,10 @@ use bitvec::{order::Lsb0 as BitOrderLsb0, vec::BitVec};
 use polkadot_node_primitives::{AvailableData, ErasureChunk};
 use polkadot_node_subsystem::{
 	errors::{ChainApiError, RuntimeApiError},
-	messages::{AvailabilityStoreMessage, ChainApiMessage, StoreAvailableDataError},
+	messages::{
+		AvailabilityStoreMessage, ChainApiMessage, StoreAvailableDataError,
+		StoreAvailableDataParams,
+	},
 	overseer, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, SpawnedSubsystem, SubsystemError,
 };
 use polkadot_node_subsystem_util as util;
@@ -216,6 +219,22 @@ fn load_chunk(
 	query_inner(db, config.col_data, &key)
 }
 
+// Load the size of any stored chunk of the candidate, if there is one.
+fn load_chunk_size(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	candidate_hash: &CandidateHash,
+) -> Result<Option<usize>, Error> {
+	let meta = load_meta(db, config, candidate_hash)?;
+
+	match meta.and_then(|meta| meta.chunks_stored.first_one()) {
+		Some(validator_index) =>
+			Ok(load_chunk(db, config, candidate_hash, ValidatorIndex(validator_index as u32))?
+				.map(|erasure_chunk| erasure_chunk.chunk.len())),
+		None => Ok(None),
+	}
+}
+
 fn write_chunk(
 	tx: &mut DBTransaction,
 	config: &Config,
@@ -1103,34 +1122,46 @@ fn process_message(
 		AvailabilityStoreMessage::QueryAvailableData(candidate, tx) => {
 			let _ = tx.send(load_available_data(&subsystem.db, &subsystem.config, &candidate)?);
 		},
+		AvailabilityStoreMessage::QueryAvailableDataBatch(candidates, tx) => {
+			let available_data = candidates
+				.iter()
+				.map(|candidate| load_available_data(&subsystem.db, &subsystem.config, candidate))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let _ = tx.send(available_data);
+		},
 		AvailabilityStoreMessage::QueryDataAvailability(candidate, tx) => {
 			let a = load_meta(&subsystem.db, &subsystem.config, &candidate)?
 				.map_or(false, |m| m.data_available);
 			let _ = tx.send(a);
 		},
+		AvailabilityStoreMessage::QueryDataAvailabilityBatch(candidates, tx) => {
+			let available = candidates
+				.iter()
+				.map(|candidate| {
+					load_meta(&subsystem.db, &subsystem.config, candidate)
+						.map(|meta| meta.map_or(false, |m| m.data_available))
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let _ = tx.send(available);
+		},
 		AvailabilityStoreMessage::QueryChunk(candidate, validator_index, tx) => {
 			let _timer = subsystem.metrics.time_get_chunk();
 			let _ =
 				tx.send(load_chunk(&subsystem.db, &subsystem.config, &candidate, validator_index)?);
 		},
 		AvailabilityStoreMessage::QueryChunkSize(candidate, tx) => {
-			let meta = load_meta(&subsystem.db, &subsystem.config, &candidate)?;
-
-			let validator_index = meta.map_or(None, |meta| meta.chunks_stored.first_one());
-
-			let maybe_chunk_size = if let Some(validator_index) = validator_index {
-				load_chunk(
-					&subsystem.db,
-					&subsystem.config,
-					&candidate,
-					ValidatorIndex(validator_index as u32),
-				)?
-				.map(|erasure_chunk| erasure_chunk.chunk.len())
-			} else {
-				None
-			};
-
-			let _ = tx.send(maybe_chunk_size);
+			let _ = tx.send(load_chunk_size(&subsystem.db, &subsystem.config, &candidate)?);
+		},
+		AvailabilityStoreMessage::QueryChunkSizes(candidates, tx) => {
+			// The database has no multi-key read, so every candidate is loaded on its own.
+			let sizes = candidates
+				.iter()
+				.map(|candidate| load_chunk_size(&subsystem.db, &subsystem.config, candidate))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let _ = tx.send(sizes);
 		},
 		AvailabilityStoreMessage::QueryAllChunks(candidate, tx) => {
 			match load_meta(&subsystem.db, &subsystem.config, &candidate)? {
@@ -1237,6 +1268,22 @@ fn process_message(
 				},
 			}
 		},
+		AvailabilityStoreMessage::StoreAvailableDataBatch(batch, tx) => {
+			for params in &batch {
+				subsystem.metrics.on_chunks_received(params.n_validators as _);
+			}
+
+			let _timer = subsystem.metrics.time_store_available_data();
+
+			// As for `StoreAvailableData`, internal errors drop the `tx` channel.
+			let results = store_available_data_batch(&subsystem, batch)?;
+			let invalid_erasure_root = results.iter().any(Result::is_err);
+			let _ = tx.send(results);
+
+			if invalid_erasure_root {
+				return Err(Error::InvalidErasureRoot)
+			}
+		},
 	}
 
 	Ok(())
@@ -1291,38 +1338,128 @@ fn store_available_data(
 ) -> Result<(), Error> {
 	let mut tx = DBTransaction::new();
 
-	let mut meta = match load_meta(&subsystem.db, &subsystem.config, &candidate_hash)? {
+	let written = write_available_data_and_chunks(
+		&mut tx,
+		subsystem,
+		candidate_hash,
+		n_validators,
+		&available_data,
+		expected_erasure_root,
+		core_index,
+		&node_features,
+	)?;
+
+	if written {
+		subsystem.db.write(tx)?;
+
+		gum::debug!(target: LOG_TARGET, ?candidate_hash, "Stored data and chunks");
+	}
+
+	Ok(())
+}
+
+// Stores all the items within a single DB transaction. An invalid erasure root only fails the
+// affected item, while any internal error fails the whole batch.
+fn store_available_data_batch(
+	subsystem: &AvailabilityStoreSubsystem,
+	batch: Vec<StoreAvailableDataParams>,
+) -> Result<Vec<Result<(), StoreAvailableDataError>>, Error> {
+	let mut tx = DBTransaction::new();
+	let mut results = Vec::with_capacity(batch.len());
+	let mut stored = Vec::new();
+
+	for params in batch {
+		let res = write_available_data_and_chunks(
+			&mut tx,
+			subsystem,
+			params.candidate_hash,
+			params.n_validators as _,
+			&params.available_data,
+			params.expected_erasure_root,
+			params.core_index,
+			&params.node_features,
+		);
+
+		match res {
+			Ok(written) => {
+				if written {
+					stored.push(params.candidate_hash);
+				}
+				results.push(Ok(()));
+			},
+			Err(Error::InvalidErasureRoot) => {
+				gum::debug!(
+					target: LOG_TARGET,
+					candidate_hash = ?params.candidate_hash,
+					"Invalid erasure root in batch",
+				);
+				results.push(Err(StoreAvailableDataError::InvalidErasureRoot));
+			},
+			Err(e) => return Err(e),
+		}
+	}
+
+	if !stored.is_empty() {
+		subsystem.db.write(tx)?;
+
+		gum::debug!(target: LOG_TARGET, candidates = ?stored, "Stored data and chunks");
+	}
+
+	Ok(results)
+}
+
+// Adds the available data, its chunks and the candidate meta to `tx`.
+//
+// Ok(true) if the data was written, Ok(false) if it was already stored. Nothing is written to `tx`
+// on error.
+fn write_available_data_and_chunks(
+	tx: &mut DBTransaction,
+	subsystem: &AvailabilityStoreSubsystem,
+	candidate_hash: CandidateHash,
+	n_validators: usize,
+	available_data: &AvailableData,
+	expected_erasure_root: Hash,
+	core_index: CoreIndex,
+	node_features: &NodeFeatures,
+) -> Result<bool, Error> {
+	let (mut meta, prune_at) = match load_meta(&subsystem.db, &subsystem.config, &candidate_hash)? {
 		Some(m) => {
 			if m.data_available {
-				return Ok(()) // already stored.
+				return Ok(false) // already stored.
 			}
 
-			m
+			(m, None)
 		},
 		None => {
 			let now = subsystem.clock.now()?;
-
-			// Write a pruning record.
 			let prune_at = now + subsystem.pruning_config.keep_unavailable_for;
-			write_pruning_key(&mut tx, &subsystem.config, prune_at, &candidate_hash);
 
-			CandidateMeta {
+			let meta = CandidateMeta {
 				state: State::Unavailable(now.into()),
 				data_available: false,
 				chunks_stored: BitVec::new(),
-			}
+			};
+
+			(meta, Some(prune_at))
 		},
 	};
 
 	// Important note: This check below is critical for consensus and the `backing` subsystem relies
 	// on it to ensure candidate validity.
-	let chunks = polkadot_erasure_coding::obtain_chunks_v1(n_validators, &available_data)?;
+	let chunks = polkadot_erasure_coding::obtain_chunks_v1(n_validators, available_data)?;
 	let branches = polkadot_erasure_coding::branches(chunks.as_ref());
 
 	if branches.root() != expected_erasure_root {
 		return Err(Error::InvalidErasureRoot)
 	}
 
+	let chunk_indices = availability_chunk_indices(Some(node_features), n_validators, core_index)?;
+
+	if let Some(prune_at) = prune_at {
+		// Write a pruning record.
+		write_pruning_key(tx, &subsystem.config, prune_at, &candidate_hash);
+	}
+
 	let erasure_chunks: Vec<_> = chunks
 		.iter()
 		.zip(branches.map(|(proof, _)| proof))
@@ -1334,10 +1471,9 @@ fn store_available_data(
 		})
 		.collect();
 
-	let chunk_indices = availability_chunk_indices(Some(&node_features), n_validators, core_index)?;
 	for (validator_index, chunk_index) in chunk_indices.into_iter().enumerate() {
 		write_chunk(
-			&mut tx,
+			tx,
 			&subsystem.config,
 			&candidate_hash,
 			ValidatorIndex(validator_index as u32),
@@ -1348,14 +1484,10 @@ fn store_available_data(
 	meta.data_available = true;
 	meta.chunks_stored = bitvec::bitvec![u8, BitOrderLsb0; 1; n_validators];
 
-	write_meta(&mut tx, &subsystem.config, &candidate_hash, &meta);
-	write_available_data(&mut tx, &subsystem.config, &candidate_hash, &available_data);
+	write_meta(tx, &subsystem.config, &candidate_hash, &meta);
+	write_available_data(tx, &subsystem.config, &candidate_hash, available_data);
 
-	subsystem.db.write(tx)?;
-
-	gum::debug!(target: LOG_TARGET, ?candidate_hash, "Stored data and chunks");
-
-	Ok(())
+	Ok(true)
 }
 
 fn prune_all(db: &Arc<dyn Database>, config: &Config, now: Duration) -> Result<(), Error> {
@@ -418,6 +418,58 @@ fn query_chunk_checks_meta() {
 	});
 }
 
+#[test]
+fn query_data_availability_batch_works() {
+	let store = test_store();
+
+	test_harness(TestState::default(), store.clone(), |mut virtual_overseer| async move {
+		let available_candidate_hash = CandidateHash(Hash::repeat_byte(33));
+		let unavailable_candidate_hash = CandidateHash(Hash::repeat_byte(34));
+		let unknown_candidate_hash = CandidateHash(Hash::repeat_byte(35));
+		let n_validators = 10;
+
+		with_tx(&store, |tx| {
+			for (candidate_hash, data_available) in
+				[(available_candidate_hash, true), (unavailable_candidate_hash, false)]
+			{
+				super::write_meta(
+					tx,
+					&TEST_CONFIG,
+					&candidate_hash,
+					&CandidateMeta {
+						data_available,
+						chunks_stored: bitvec::bitvec![u8, BitOrderLsb0; 0; n_validators],
+						state: State::Unavailable(BETimestamp(0)),
+					},
+				);
+			}
+		});
+
+		let (tx, rx) = oneshot::channel();
+		let query = AvailabilityStoreMessage::QueryDataAvailabilityBatch(
+			vec![
+				unknown_candidate_hash,
+				available_candidate_hash,
+				unavailable_candidate_hash,
+				available_candidate_hash,
+			],
+			tx,
+		);
+
+		overseer_send(&mut virtual_overseer, query).await;
+		assert_eq!(rx.await.unwrap(), vec![false, true, false, true]);
+
+		let (tx, rx) = oneshot::channel();
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityStoreMessage::QueryDataAvailabilityBatch(Vec::new(), tx),
+		)
+		.await;
+		assert!(rx.await.unwrap().is_empty());
+		virtual_overseer
+	});
+}
+
 #[test]
 fn store_available_data_erasure_mismatch() {
 	let store = test_store();
@@ -461,6 +513,79 @@ fn store_available_data_erasure_mismatch() {
 	});
 }
 
+#[test]
+fn store_available_data_batch_works() {
+	let store = test_store();
+	let test_state = TestState::default();
+	test_harness(test_state.clone(), store.clone(), |mut virtual_overseer| async move {
+		let n_validators = 10;
+		let candidate_hash_1 = CandidateHash(Hash::repeat_byte(1));
+		let candidate_hash_2 = CandidateHash(Hash::repeat_byte(2));
+		let candidate_hash_3 = CandidateHash(Hash::repeat_byte(3));
+
+		let available_data = |block_data: Vec<u8>| AvailableData {
+			pov: Arc::new(PoV { block_data: BlockData(block_data) }),
+			validation_data: test_state.persisted_validation_data.clone(),
+		};
+		let erasure_root = |available_data: &AvailableData| {
+			let chunks =
+				polkadot_erasure_coding::obtain_chunks_v1(n_validators as _, available_data)
+					.unwrap();
+			polkadot_erasure_coding::branches(chunks.as_ref()).root()
+		};
+		let params =
+			|candidate_hash, available_data, expected_erasure_root| StoreAvailableDataParams {
+				candidate_hash,
+				n_validators,
+				available_data,
+				expected_erasure_root,
+				core_index: CoreIndex(0),
+				node_features: NodeFeatures::EMPTY,
+			};
+
+		let available_data_1 = available_data(vec![1, 2, 3]);
+		let available_data_2 = available_data(vec![4, 5, 6]);
+		let available_data_3 = available_data(vec![7, 8, 9]);
+
+		let batch = vec![
+			params(candidate_hash_1, available_data_1.clone(), erasure_root(&available_data_1)),
+			// A dummy erasure root should only fail this item.
+			params(candidate_hash_2, available_data_2.clone(), Hash::default()),
+			params(candidate_hash_3, available_data_3.clone(), erasure_root(&available_data_3)),
+		];
+
+		let (tx, rx) = oneshot::channel();
+		let msg = AvailabilityStoreMessage::StoreAvailableDataBatch(batch, tx);
+		virtual_overseer.send(FromOrchestra::Communication { msg }).await;
+
+		assert_eq!(
+			rx.await.unwrap(),
+			vec![Ok(()), Err(StoreAvailableDataError::InvalidErasureRoot), Ok(())],
+		);
+
+		assert_eq!(
+			query_available_data(&mut virtual_overseer, candidate_hash_1).await.unwrap(),
+			available_data_1,
+		);
+		assert!(query_available_data(&mut virtual_overseer, candidate_hash_2).await.is_none());
+		assert_eq!(
+			query_available_data(&mut virtual_overseer, candidate_hash_3).await.unwrap(),
+			available_data_3,
+		);
+
+		let (tx, rx) = oneshot::channel();
+		let msg = AvailabilityStoreMessage::QueryAvailableDataBatch(
+			vec![candidate_hash_3, candidate_hash_2, candidate_hash_1],
+			tx,
+		);
+		virtual_overseer.send(FromOrchestra::Communication { msg }).await;
+
+		assert_eq!(rx.await.unwrap(), vec![Some(available_data_3), None, Some(available_data_1)]);
+
+		virtual_overseer
+	});
+}
+
 #[test]
 fn store_pov_and_queries_work() {
 	// If the AvailabilityChunkMapping feature is not enabled,
@@ -1390,3 +1515,57 @@ fn query_chunk_size_works() {
 		virtual_overseer
 	});
 }
+
+#[test]
+fn query_chunk_sizes_works() {
+	let store = test_store();
+
+	test_harness(TestState::default(), store.clone(), |mut virtual_overseer| async move {
+		let candidate_hash = CandidateHash(Hash::repeat_byte(33));
+		let unknown_candidate_hash = CandidateHash(Hash::repeat_byte(34));
+		let chunk_index = ChunkIndex(5);
+		let n_validators = 10;
+
+		let chunk = ErasureChunk {
+			chunk: vec![1, 2, 3, 4],
+			index: chunk_index,
+			proof: Proof::try_from(vec![vec![3, 4, 5]]).unwrap(),
+		};
+
+		with_tx(&store, |tx| {
+			super::write_meta(
+				tx,
+				&TEST_CONFIG,
+				&candidate_hash,
+				&CandidateMeta {
+					data_available: false,
+					chunks_stored: bitvec::bitvec![u8, BitOrderLsb0; 0; n_validators],
+					state: State::Unavailable(BETimestamp(0)),
+				},
+			);
+		});
+
+		let (tx, rx) = oneshot::channel();
+
+		let chunk_msg = AvailabilityStoreMessage::StoreChunk {
+			candidate_hash,
+			chunk: chunk.clone(),
+			tx,
+			validator_index: chunk_index.into(),
+		};
+
+		overseer_send(&mut virtual_overseer, chunk_msg).await;
+		assert_eq!(rx.await.unwrap(), Ok(()));
+
+		let (tx, rx) = oneshot::channel();
+		let query_chunk_sizes = AvailabilityStoreMessage::QueryChunkSizes(
+			vec![unknown_candidate_hash, candidate_hash, unknown_candidate_hash],
+			tx,
+		);
+
+		overseer_send(&mut virtual_overseer, query_chunk_sizes).await;
+
+		assert_eq!(rx.await.unwrap(), vec![None, Some(chunk.chunk.len()), None]);
+		virtual_overseer
+	});
+}
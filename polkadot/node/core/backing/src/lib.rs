@@ -90,7 +90,7 @@ use polkadot_node_subsystem::{
 		CandidateBackingMessage, CandidateValidationMessage, CollatorProtocolMessage,
 		HypotheticalCandidate, HypotheticalMembershipRequest, IntroduceSecondedCandidateRequest,
 		ProspectiveParachainsMessage, ProvisionableData, ProvisionerMessage, PvfExecKind,
-		RuntimeApiMessage, RuntimeApiRequest, StatementDistributionMessage,
+		RuntimeApiMessage, RuntimeApiRequest, SecondError, StatementDistributionMessage,
 		StoreAvailableDataError,
 	},
 	overseer, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, RuntimeApiError, SpawnedSubsystem,
@@ -232,6 +232,8 @@ struct PerRelayParentState {
 	awaiting_validation: HashSet<CandidateHash>,
 	/// Data needed for retrying in case of `ValidatedCandidateCommand::AttestNoPoV`.
 	fallbacks: HashMap<CandidateHash, AttestingData>,
+	/// Senders awaiting the outcome of `CandidateBackingMessage::Second` requests, by candidate.
+	second_result_senders: HashMap<CandidateHash, oneshot::Sender<Result<(), SecondError>>>,
 	/// The minimum backing votes threshold.
 	minimum_backing_votes: u32,
 	/// If true, we're appending extra bits in the BackedCandidate validator indices bitfield,
@@ -761,6 +763,8 @@ async fn request_candidate_validation(
 			} else {
 				PvfExecKind::Backing(relay_parent)
 			},
+			exec_timeout: None,
+			memory_limit_override: None,
 			response_sender: tx,
 		})
 		.await;
@@ -941,8 +945,8 @@ async fn handle_communication<Context>(
 	metrics: &Metrics,
 ) -> Result<(), Error> {
 	match message {
-		CandidateBackingMessage::Second(_relay_parent, candidate, pvd, pov) => {
-			handle_second_message(ctx, state, candidate, pvd, pov, metrics).await?;
+		CandidateBackingMessage::Second(_relay_parent, candidate, pvd, pov, tx) => {
+			handle_second_message(ctx, state, candidate, pvd, pov, tx, metrics).await?;
 		},
 		CandidateBackingMessage::Statement(relay_parent, statement) => {
 			handle_statement_message(ctx, state, relay_parent, statement, metrics).await?;
@@ -1236,6 +1240,7 @@ async fn construct_per_relay_parent_state<Context>(
 		issued_statements: HashSet::new(),
 		awaiting_validation: HashSet::new(),
 		fallbacks: HashMap::new(),
+		second_result_senders: HashMap::new(),
 		minimum_backing_votes,
 		inject_core_index,
 		n_cores: validator_groups.len() as u32,
@@ -1380,6 +1385,11 @@ async fn handle_validated_candidate_command<Context>(
 			let candidate_hash = command.candidate_hash();
 			rp_state.awaiting_validation.remove(&candidate_hash);
 
+			let second_result_tx = match command {
+				ValidatedCandidateCommand::AttestNoPoV(_) => None,
+				_ => rp_state.second_result_senders.remove(&candidate_hash),
+			};
+
 			match command {
 				ValidatedCandidateCommand::Second(res) => match res {
 					Ok(outputs) => {
@@ -1390,6 +1400,10 @@ async fn handle_validated_candidate_command<Context>(
 						} = outputs;
 
 						if rp_state.issued_statements.contains(&candidate_hash) {
+							send_second_result(
+								second_result_tx,
+								Err(SecondError::StatementAlreadyIssued),
+							);
 							return Ok(())
 						}
 
@@ -1413,6 +1427,7 @@ async fn handle_validated_candidate_command<Context>(
 						)
 						.await
 						{
+							send_second_result(second_result_tx, Err(SecondError::NotAllowed));
 							return Ok(())
 						};
 
@@ -1448,6 +1463,10 @@ async fn handle_validated_candidate_command<Context>(
 							))
 							.await;
 
+							send_second_result(
+								second_result_tx,
+								Err(SecondError::RejectedByProspectiveParachains),
+							);
 							return Ok(())
 						}
 
@@ -1471,6 +1490,8 @@ async fn handle_validated_candidate_command<Context>(
 								StatementWithPVD::drop_pvd_from_signed(stmt),
 							))
 							.await;
+
+							send_second_result(second_result_tx, Ok(()));
 						}
 					},
 					Err(candidate) => {
@@ -1479,6 +1500,8 @@ async fn handle_validated_candidate_command<Context>(
 							candidate,
 						))
 						.await;
+
+						send_second_result(second_result_tx, Err(SecondError::Invalid));
 					},
 				},
 				ValidatedCandidateCommand::Attest(res) => {
@@ -1501,6 +1524,9 @@ async fn handle_validated_candidate_command<Context>(
 						}
 						rp_state.issued_statements.insert(candidate_hash);
 					}
+
+					// A request to second the candidate raced with its attestation.
+					send_second_result(second_result_tx, Err(SecondError::StatementAlreadyIssued));
 				},
 				ValidatedCandidateCommand::AttestNoPoV(candidate_hash) => {
 					if let Some(attesting) = rp_state.fallbacks.get_mut(&candidate_hash) {
@@ -1547,6 +1573,15 @@ async fn handle_validated_candidate_command<Context>(
 	Ok(())
 }
 
+fn send_second_result(
+	tx: Option<oneshot::Sender<Result<(), SecondError>>>,
+	result: Result<(), SecondError>,
+) {
+	if let Some(tx) = tx {
+		let _ = tx.send(result);
+	}
+}
+
 fn sign_statement(
 	rp_state: &PerRelayParentState,
 	statement: StatementWithPVD,
@@ -2020,6 +2055,7 @@ async fn handle_second_message<Context>(
 	candidate: CandidateReceipt,
 	persisted_validation_data: PersistedValidationData,
 	pov: PoV,
+	tx: Option<oneshot::Sender<Result<(), SecondError>>>,
 	metrics: &Metrics,
 ) -> Result<(), Error> {
 	let _timer = metrics.time_process_second();
@@ -2034,6 +2070,7 @@ async fn handle_second_message<Context>(
 			"Candidate backing was asked to second candidate with wrong PVD",
 		);
 
+		send_second_result(tx, Err(SecondError::WrongPersistedValidationData));
 		return Ok(())
 	}
 
@@ -2046,6 +2083,7 @@ async fn handle_second_message<Context>(
 				"We were asked to second a candidate outside of our view."
 			);
 
+			send_second_result(tx, Err(SecondError::OutOfView(relay_parent)));
 			return Ok(())
 		},
 		Some(r) => r,
@@ -2055,6 +2093,7 @@ async fn handle_second_message<Context>(
 	// validator but defensively use `unwrap_or(false)` to continue processing in this case.
 	if rp_state.table_context.local_validator_is_disabled().unwrap_or(false) {
 		gum::warn!(target: LOG_TARGET, "Local validator is disabled. Don't validate and second");
+		send_second_result(tx, Err(SecondError::LocalValidatorDisabled));
 		return Ok(())
 	}
 
@@ -2069,6 +2108,7 @@ async fn handle_second_message<Context>(
 			collation = ?candidate.descriptor().para_id(),
 			"Subsystem asked to second for para outside of our assignment",
 		);
+		send_second_result(tx, Err(SecondError::NotAssigned(candidate.descriptor().para_id())));
 		return Ok(());
 	}
 
@@ -2090,6 +2130,10 @@ async fn handle_second_message<Context>(
 	if !rp_state.issued_statements.contains(&candidate_hash) {
 		let pov = Arc::new(pov);
 
+		if let Some(tx) = tx {
+			rp_state.second_result_senders.insert(candidate_hash, tx);
+		}
+
 		validate_and_second(
 			ctx,
 			rp_state,
@@ -2099,6 +2143,8 @@ async fn handle_second_message<Context>(
 			&state.background_validation_tx,
 		)
 		.await?;
+	} else {
+		send_second_result(tx, Err(SecondError::StatementAlreadyIssued));
 	}
 
 	Ok(())
@@ -719,6 +719,7 @@ fn backing_second_works() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -779,6 +780,116 @@ fn backing_second_works() {
 	});
 }
 
+// Test that the outcome of a `CandidateBackingMessage::Second` is reported to the requester.
+#[test]
+fn backing_second_acknowledges_outcome() {
+	let mut test_state = TestState::default();
+	test_harness(test_state.keystore.clone(), |mut virtual_overseer| async move {
+		let para_id = activate_initial_leaf(&mut virtual_overseer, &mut test_state).await;
+
+		let pov = PoV { block_data: BlockData(vec![42, 43, 44]) };
+		let pvd = dummy_pvd();
+		let validation_code = ValidationCode(vec![1, 2, 3]);
+
+		let expected_head_data = test_state.head_data.get(&para_id).unwrap();
+
+		let pov_hash = pov.hash();
+		let candidate = TestCandidateBuilder {
+			para_id,
+			relay_parent: test_state.relay_parent,
+			pov_hash,
+			head_data: expected_head_data.clone(),
+			erasure_root: make_erasure_root(&test_state, pov.clone(), pvd.clone()),
+			persisted_validation_data_hash: pvd.hash(),
+			validation_code: validation_code.0.clone(),
+		}
+		.build();
+
+		// A request with mismatching PVD is refused right away.
+		let mut wrong_pvd = pvd.clone();
+		wrong_pvd.max_pov_size += 1;
+		let (tx, rx) = oneshot::channel();
+		let second = CandidateBackingMessage::Second(
+			test_state.relay_parent,
+			candidate.to_plain(),
+			wrong_pvd,
+			pov.clone(),
+			Some(tx),
+		);
+
+		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
+
+		assert_eq!(rx.await.unwrap(), Err(SecondError::WrongPersistedValidationData));
+
+		let (tx, mut rx) = oneshot::channel();
+		let second = CandidateBackingMessage::Second(
+			test_state.relay_parent,
+			candidate.to_plain(),
+			pvd.clone(),
+			pov.clone(),
+			Some(tx),
+		);
+
+		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
+
+		assert_validate_seconded_candidate(
+			&mut virtual_overseer,
+			test_state.relay_parent,
+			&candidate,
+			&pov,
+			&pvd,
+			&validation_code,
+			expected_head_data,
+			false,
+		)
+		.await;
+
+		let hypothetical_candidate = HypotheticalCandidate::Complete {
+			candidate_hash: candidate.hash(),
+			receipt: Arc::new(candidate.clone()),
+			persisted_validation_data: pvd.clone(),
+		};
+		let expected_request = HypotheticalMembershipRequest {
+			candidates: vec![hypothetical_candidate.clone()],
+			fragment_chain_relay_parent: Some(test_state.relay_parent),
+		};
+		let expected_response =
+			make_hypothetical_membership_response(hypothetical_candidate, test_state.relay_parent);
+		assert_hypothetical_membership_requests(
+			&mut virtual_overseer,
+			vec![(expected_request, expected_response)],
+		)
+		.await;
+
+		// The outcome is not known before the statement is imported.
+		assert_eq!(rx.try_recv().unwrap(), None);
+
+		assert_matches!(
+			virtual_overseer.recv().await,
+			AllMessages::ProspectiveParachains(
+				ProspectiveParachainsMessage::IntroduceSecondedCandidate(
+					_,
+					tx,
+				),
+			) => {
+				tx.send(true).unwrap();
+			}
+		);
+
+		assert_candidate_is_shared_and_seconded(&mut virtual_overseer, &test_state.relay_parent)
+			.await;
+
+		assert_eq!(rx.await.unwrap(), Ok(()));
+
+		virtual_overseer
+			.send(FromOrchestra::Signal(OverseerSignal::ActiveLeaves(
+				ActiveLeavesUpdate::stop_work(test_state.relay_parent),
+			)))
+			.await;
+		virtual_overseer
+	});
+}
+
 // Test that the candidate reaches quorum successfully.
 #[rstest]
 #[case(true)]
@@ -1780,6 +1891,7 @@ fn backing_doesnt_second_invalid() {
 			candidate_a.to_plain(),
 			pvd_a.clone(),
 			pov_block_a.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -1820,6 +1932,7 @@ fn backing_doesnt_second_invalid() {
 			candidate_b.to_plain(),
 			pvd_b.clone(),
 			pov_block_b.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -2004,6 +2117,7 @@ fn backing_second_after_first_fails_works() {
 			candidate.to_plain(),
 			pvd_a.clone(),
 			pov_a.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -2034,6 +2148,7 @@ fn backing_second_after_first_fails_works() {
 			candidate_to_second.to_plain(),
 			pvd_to_second.clone(),
 			pov_to_second.clone(),
+			None,
 		);
 
 		// In order to trigger _some_ actions from subsystem ask it to second another
@@ -2613,6 +2728,7 @@ fn new_leaf_view_doesnt_clobber_old() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -2664,6 +2780,7 @@ fn disabled_validator_doesnt_distribute_statement_on_receiving_second() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -2931,6 +3048,7 @@ fn seconding_sanity_check_allowed_on_all() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -3046,6 +3164,7 @@ fn seconding_sanity_check_disallowed() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -3115,6 +3234,7 @@ fn seconding_sanity_check_disallowed() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -3218,6 +3338,7 @@ fn seconding_sanity_check_allowed_on_at_least_one_leaf() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -3323,6 +3444,7 @@ fn prospective_parachains_reject_candidate() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -3389,6 +3511,7 @@ fn prospective_parachains_reject_candidate() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -3476,6 +3599,7 @@ fn second_multiple_candidates_per_relay_parent() {
 				candidate.to_plain(),
 				pvd.clone(),
 				pov.clone(),
+				None,
 			);
 
 			virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -3855,6 +3979,7 @@ fn seconding_sanity_check_occupy_same_depth() {
 				candidate.to_plain(),
 				pvd.clone(),
 				pov.clone(),
+				None,
 			);
 
 			virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
@@ -3977,6 +4102,7 @@ fn occupied_core_assignment() {
 			candidate.to_plain(),
 			pvd.clone(),
 			pov.clone(),
+			None,
 		);
 
 		virtual_overseer.send(FromOrchestra::Communication { msg: second }).await;
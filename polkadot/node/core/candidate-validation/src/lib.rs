@@ -47,15 +47,16 @@ use polkadot_primitives::{
 	executor_params::{
 		DEFAULT_APPROVAL_EXECUTION_TIMEOUT, DEFAULT_BACKING_EXECUTION_TIMEOUT,
 		DEFAULT_LENIENT_PREPARATION_TIMEOUT, DEFAULT_PRECHECK_PREPARATION_TIMEOUT,
+		MEMORY_PAGES_MAX,
 	},
 	vstaging::{
 		transpose_claim_queue, CandidateDescriptorV2 as CandidateDescriptor, CandidateEvent,
 		CandidateReceiptV2 as CandidateReceipt,
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt,
 	},
-	AuthorityDiscoveryId, CandidateCommitments, ExecutorParams, Hash, PersistedValidationData,
-	PvfExecKind as RuntimePvfExecKind, PvfPrepKind, SessionIndex, ValidationCode,
-	ValidationCodeHash, ValidatorId,
+	AuthorityDiscoveryId, CandidateCommitments, ExecutorParam, ExecutorParams, Hash,
+	PersistedValidationData, PvfExecKind as RuntimePvfExecKind, PvfPrepKind, SessionIndex,
+	ValidationCode, ValidationCodeHash, ValidatorId,
 };
 use sp_application_crypto::{AppCrypto, ByteArray};
 use sp_keystore::KeystorePtr;
@@ -90,6 +91,14 @@ const PVF_APPROVAL_EXECUTION_RETRY_DELAY: Duration = Duration::from_secs(3);
 #[cfg(test)]
 const PVF_APPROVAL_EXECUTION_RETRY_DELAY: Duration = Duration::from_millis(200);
 
+/// The upper bound for an explicitly requested execution timeout. Anything above is clamped, so a
+/// misbehaving caller can't tie up an execution worker indefinitely.
+const MAX_EXPLICIT_EXECUTION_TIMEOUT: Duration =
+	Duration::from_secs(4 * DEFAULT_APPROVAL_EXECUTION_TIMEOUT.as_secs());
+
+/// The size of a Wasm memory page, used to convert an explicit memory limit into a page count.
+const WASM_PAGE_SIZE: u64 = 64 * 1024;
+
 // The task queue size is chosen to be somewhat bigger than the PVF host incoming queue size
 // to allow exhaustive validation messages to fall through in case the tasks are clogged
 const TASK_LIMIT: usize = 30;
@@ -188,8 +197,9 @@ where
 			pov,
 			executor_params,
 			exec_kind,
+			exec_timeout,
+			memory_limit_override,
 			response_sender,
-			..
 		} => async move {
 			let _timer = metrics.time_validate_from_exhaustive();
 			let relay_parent = candidate_receipt.descriptor.relay_parent();
@@ -205,6 +215,8 @@ where
 				pov,
 				executor_params,
 				exec_kind,
+				exec_timeout,
+				memory_limit_override,
 				&metrics,
 				maybe_claim_queue,
 			)
@@ -761,6 +773,8 @@ async fn validate_candidate_exhaustive(
 	pov: Arc<PoV>,
 	executor_params: ExecutorParams,
 	exec_kind: PvfExecKind,
+	exec_timeout: Option<Duration>,
+	memory_limit_override: Option<u64>,
 	metrics: &Metrics,
 	maybe_claim_queue: Option<ClaimQueueSnapshot>,
 ) -> Result<ValidationResult, ValidationFailed> {
@@ -807,13 +821,20 @@ async fn validate_candidate_exhaustive(
 		return Ok(ValidationResult::Invalid(e))
 	}
 
+	let exec_timeout = match exec_timeout {
+		Some(timeout) => timeout.min(MAX_EXPLICIT_EXECUTION_TIMEOUT),
+		None => pvf_exec_timeout(&executor_params, exec_kind.into()),
+	};
+	let executor_params = match memory_limit_override {
+		Some(limit) => executor_params_with_memory_limit(&executor_params, limit),
+		None => executor_params,
+	};
 	let persisted_validation_data = Arc::new(persisted_validation_data);
 	let result = match exec_kind {
 		// Retry is disabled to reduce the chance of nondeterministic blocks getting backed and
 		// honest backers getting slashed.
 		PvfExecKind::Backing(_) | PvfExecKind::BackingSystemParas(_) => {
 			let prep_timeout = pvf_prep_timeout(&executor_params, PvfPrepKind::Prepare);
-			let exec_timeout = pvf_exec_timeout(&executor_params, exec_kind.into());
 			let pvf = PvfPrepData::from_code(
 				validation_code.0,
 				executor_params,
@@ -836,7 +857,7 @@ async fn validate_candidate_exhaustive(
 			validation_backend
 				.validate_candidate_with_retry(
 					validation_code.0,
-					pvf_exec_timeout(&executor_params, exec_kind.into()),
+					exec_timeout,
 					persisted_validation_data.clone(),
 					pov,
 					executor_params,
@@ -1259,3 +1280,21 @@ fn pvf_exec_timeout(executor_params: &ExecutorParams, kind: RuntimePvfExecKind)
 		RuntimePvfExecKind::Approval => DEFAULT_APPROVAL_EXECUTION_TIMEOUT,
 	}
 }
+
+/// Returns a copy of `executor_params` with the maximum number of memory pages replaced by the
+/// explicit memory limit `limit` (in bytes).
+///
+/// The limit is rounded up to whole Wasm pages and clamped to `1..=MEMORY_PAGES_MAX`.
+fn executor_params_with_memory_limit(
+	executor_params: &ExecutorParams,
+	limit: u64,
+) -> ExecutorParams {
+	let pages = limit.div_ceil(WASM_PAGE_SIZE).clamp(1, MEMORY_PAGES_MAX as u64) as u32;
+	let params: Vec<_> = executor_params
+		.iter()
+		.filter(|param| !matches!(param, ExecutorParam::MaxMemoryPages(_)))
+		.cloned()
+		.chain(std::iter::once(ExecutorParam::MaxMemoryPages(pages)))
+		.collect();
+	ExecutorParams::from(&params[..])
+}
@@ -419,17 +419,39 @@ fn check_does_not_match() {
 struct MockValidateCandidateBackend {
 	result_list: Vec<Result<WasmValidationResult, ValidationError>>,
 	num_times_called: usize,
+	expected_timeout: Option<Duration>,
+	expected_memory_pages: Option<u32>,
 }
 
 impl MockValidateCandidateBackend {
 	fn with_hardcoded_result(result: Result<WasmValidationResult, ValidationError>) -> Self {
-		Self { result_list: vec![result], num_times_called: 0 }
+		Self {
+			result_list: vec![result],
+			num_times_called: 0,
+			expected_timeout: None,
+			expected_memory_pages: None,
+		}
 	}
 
 	fn with_hardcoded_result_list(
 		result_list: Vec<Result<WasmValidationResult, ValidationError>>,
 	) -> Self {
-		Self { result_list, num_times_called: 0 }
+		Self {
+			result_list,
+			num_times_called: 0,
+			expected_timeout: None,
+			expected_memory_pages: None,
+		}
+	}
+
+	fn with_expected_timeout(mut self, timeout: Duration) -> Self {
+		self.expected_timeout = Some(timeout);
+		self
+	}
+
+	fn with_expected_memory_pages(mut self, pages: u32) -> Self {
+		self.expected_memory_pages = Some(pages);
+		self
 	}
 }
 
@@ -437,13 +459,25 @@ impl MockValidateCandidateBackend {
 impl ValidationBackend for MockValidateCandidateBackend {
 	async fn validate_candidate(
 		&mut self,
-		_pvf: PvfPrepData,
-		_timeout: Duration,
+		pvf: PvfPrepData,
+		timeout: Duration,
 		_pvd: Arc<PersistedValidationData>,
 		_pov: Arc<PoV>,
 		_prepare_priority: polkadot_node_core_pvf::Priority,
 		_exec_kind: PvfExecKind,
 	) -> Result<WasmValidationResult, ValidationError> {
+		if let Some(expected_timeout) = self.expected_timeout {
+			assert_eq!(timeout, expected_timeout);
+		}
+
+		if let Some(expected_memory_pages) = self.expected_memory_pages {
+			let max_memory_pages = pvf.executor_params().iter().find_map(|param| match param {
+				ExecutorParam::MaxMemoryPages(pages) => Some(*pages),
+				_ => None,
+			});
+			assert_eq!(max_memory_pages, Some(expected_memory_pages));
+		}
+
 		// This is expected to panic if called more times than expected, indicating an error in the
 		// test.
 		let result = self.result_list[self.num_times_called].clone();
@@ -526,6 +560,8 @@ fn session_index_checked_only_in_backing() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -543,6 +579,8 @@ fn session_index_checked_only_in_backing() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::Approval,
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -567,6 +605,8 @@ fn session_index_checked_only_in_backing() {
 		Arc::new(pov),
 		ExecutorParams::default(),
 		PvfExecKind::Dispute,
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -665,6 +705,8 @@ fn candidate_validation_ok_is_ok(#[case] v2_descriptor: bool) {
 		Arc::new(pov),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Some(ClaimQueueSnapshot(cq)),
 	))
@@ -743,6 +785,8 @@ fn invalid_session_or_core_index() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -759,6 +803,8 @@ fn invalid_session_or_core_index() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::BackingSystemParas(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -777,6 +823,8 @@ fn invalid_session_or_core_index() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Some(Default::default()),
 	))
@@ -792,6 +840,8 @@ fn invalid_session_or_core_index() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::BackingSystemParas(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Some(Default::default()),
 	))
@@ -807,6 +857,8 @@ fn invalid_session_or_core_index() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::Approval,
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -832,6 +884,8 @@ fn invalid_session_or_core_index() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::Dispute,
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -861,6 +915,8 @@ fn invalid_session_or_core_index() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Some(ClaimQueueSnapshot(cq.clone())),
 	))
@@ -884,6 +940,8 @@ fn invalid_session_or_core_index() {
 		Arc::new(pov.clone()),
 		ExecutorParams::default(),
 		PvfExecKind::BackingSystemParas(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Some(ClaimQueueSnapshot(cq)),
 	))
@@ -928,6 +986,8 @@ fn invalid_session_or_core_index() {
 			Arc::new(pov.clone()),
 			ExecutorParams::default(),
 			exec_kind,
+			None,
+			None,
 			&Default::default(),
 			Some(Default::default()),
 		))
@@ -946,6 +1006,8 @@ fn invalid_session_or_core_index() {
 			Arc::new(pov.clone()),
 			ExecutorParams::default(),
 			exec_kind,
+			None,
+			None,
 			&Default::default(),
 			Default::default(),
 		))
@@ -1002,6 +1064,8 @@ fn candidate_validation_bad_return_is_invalid() {
 		Arc::new(pov),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -1086,6 +1150,8 @@ fn candidate_validation_one_ambiguous_error_is_valid() {
 		Arc::new(pov),
 		ExecutorParams::default(),
 		PvfExecKind::Approval,
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -1129,6 +1195,8 @@ fn candidate_validation_multiple_ambiguous_errors_is_invalid() {
 		Arc::new(pov),
 		ExecutorParams::default(),
 		PvfExecKind::Approval,
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -1246,6 +1314,8 @@ fn candidate_validation_retry_on_error_helper(
 		Arc::new(pov),
 		ExecutorParams::default(),
 		exec_kind,
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -1291,6 +1361,112 @@ fn candidate_validation_timeout_is_internal_error() {
 		Arc::new(pov),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
+		&Default::default(),
+		Default::default(),
+	));
+
+	assert_matches!(v, Ok(ValidationResult::Invalid(InvalidCandidate::Timeout)));
+}
+
+#[rstest]
+#[case(PvfExecKind::Backing(dummy_hash()), None, DEFAULT_BACKING_EXECUTION_TIMEOUT)]
+#[case(PvfExecKind::Approval, None, DEFAULT_APPROVAL_EXECUTION_TIMEOUT)]
+#[case(PvfExecKind::Backing(dummy_hash()), Some(Duration::from_secs(5)), Duration::from_secs(5))]
+#[case(PvfExecKind::Dispute, Some(Duration::from_secs(30)), Duration::from_secs(30))]
+#[case(PvfExecKind::Dispute, Some(Duration::from_secs(3600)), MAX_EXPLICIT_EXECUTION_TIMEOUT)]
+fn candidate_validation_honors_explicit_exec_timeout(
+	#[case] exec_kind: PvfExecKind,
+	#[case] exec_timeout: Option<Duration>,
+	#[case] expected_timeout: Duration,
+) {
+	let validation_data = PersistedValidationData { max_pov_size: 1024, ..Default::default() };
+
+	let pov = PoV { block_data: BlockData(vec![1; 32]) };
+	let validation_code = ValidationCode(vec![2; 16]);
+
+	let descriptor = make_valid_candidate_descriptor(
+		ParaId::from(1_u32),
+		dummy_hash(),
+		validation_data.hash(),
+		pov.hash(),
+		validation_code.hash(),
+		dummy_hash(),
+		dummy_hash(),
+		Sr25519Keyring::Alice,
+	)
+	.into();
+
+	let candidate_receipt = CandidateReceipt { descriptor, commitments_hash: Hash::zero() };
+
+	let v = executor::block_on(validate_candidate_exhaustive(
+		Some(1),
+		MockValidateCandidateBackend::with_hardcoded_result(Err(ValidationError::Invalid(
+			WasmInvalidCandidate::HardTimeout,
+		)))
+		.with_expected_timeout(expected_timeout),
+		validation_data,
+		validation_code,
+		candidate_receipt,
+		Arc::new(pov),
+		ExecutorParams::default(),
+		exec_kind,
+		exec_timeout,
+		None,
+		&Default::default(),
+		Default::default(),
+	));
+
+	assert_matches!(v, Ok(ValidationResult::Invalid(InvalidCandidate::Timeout)));
+}
+
+#[rstest]
+#[case(PvfExecKind::Backing(dummy_hash()), 1, 1)]
+#[case(PvfExecKind::Backing(dummy_hash()), 128 * 1024 * 1024, 2048)]
+#[case(PvfExecKind::Approval, 128 * 1024 * 1024 + 1, 2049)]
+#[case(PvfExecKind::Dispute, u64::MAX, MEMORY_PAGES_MAX)]
+fn candidate_validation_honors_memory_limit_override(
+	#[case] exec_kind: PvfExecKind,
+	#[case] memory_limit: u64,
+	#[case] expected_pages: u32,
+) {
+	let validation_data = PersistedValidationData { max_pov_size: 1024, ..Default::default() };
+
+	let pov = PoV { block_data: BlockData(vec![1; 32]) };
+	let validation_code = ValidationCode(vec![2; 16]);
+
+	let descriptor = make_valid_candidate_descriptor(
+		ParaId::from(1_u32),
+		dummy_hash(),
+		validation_data.hash(),
+		pov.hash(),
+		validation_code.hash(),
+		dummy_hash(),
+		dummy_hash(),
+		Sr25519Keyring::Alice,
+	)
+	.into();
+
+	let candidate_receipt = CandidateReceipt { descriptor, commitments_hash: Hash::zero() };
+
+	// The session's own limit is replaced rather than duplicated.
+	let executor_params = ExecutorParams::from(&[ExecutorParam::MaxMemoryPages(1024)][..]);
+
+	let v = executor::block_on(validate_candidate_exhaustive(
+		Some(1),
+		MockValidateCandidateBackend::with_hardcoded_result(Err(ValidationError::Invalid(
+			WasmInvalidCandidate::HardTimeout,
+		)))
+		.with_expected_memory_pages(expected_pages),
+		validation_data,
+		validation_code,
+		candidate_receipt,
+		Arc::new(pov),
+		executor_params,
+		exec_kind,
+		None,
+		Some(memory_limit),
 		&Default::default(),
 		Default::default(),
 	));
@@ -1340,6 +1516,8 @@ fn candidate_validation_commitment_hash_mismatch_is_invalid() {
 		Arc::new(pov),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -1392,6 +1570,8 @@ fn candidate_validation_code_mismatch_is_invalid() {
 		Arc::new(pov),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Default::default(),
 	))
@@ -1452,6 +1632,8 @@ fn compressed_code_works() {
 		Arc::new(pov),
 		ExecutorParams::default(),
 		PvfExecKind::Backing(dummy_hash()),
+		None,
+		None,
 		&Default::default(),
 		Some(Default::default()),
 	));
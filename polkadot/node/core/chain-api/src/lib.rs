@@ -38,10 +38,10 @@ use sc_client_api::AuxStore;
 
 use futures::stream::StreamExt;
 use polkadot_node_subsystem::{
-	messages::ChainApiMessage, overseer, FromOrchestra, OverseerSignal, SpawnedSubsystem,
-	SubsystemError, SubsystemResult,
+	errors::ChainApiError, messages::ChainApiMessage, overseer, FromOrchestra, OverseerSignal,
+	SpawnedSubsystem, SubsystemError, SubsystemResult,
 };
-use polkadot_node_subsystem_types::ChainApiBackend;
+use polkadot_node_subsystem_types::{ChainApiBackend, Hash};
 
 mod metrics;
 use self::metrics::Metrics;
@@ -51,16 +51,31 @@ mod tests;
 
 const LOG_TARGET: &str = "parachain::chain-api";
 
+/// The default upper bound on the number of ancestors returned for a single
+/// `ChainApiMessage::Ancestors` request.
+///
+/// Legitimate callers request at most a few dozen ancestors, so this is only hit by buggy or
+/// malicious requests which would otherwise walk the chain back to genesis.
+pub const DEFAULT_MAX_ANCESTORS: usize = 4096;
+
 /// The Chain API Subsystem implementation.
 pub struct ChainApiSubsystem<Client> {
 	client: Arc<Client>,
 	metrics: Metrics,
+	max_ancestors: usize,
 }
 
 impl<Client> ChainApiSubsystem<Client> {
 	/// Create a new Chain API subsystem with the given client.
 	pub fn new(client: Arc<Client>, metrics: Metrics) -> Self {
-		ChainApiSubsystem { client, metrics }
+		ChainApiSubsystem { client, metrics, max_ancestors: DEFAULT_MAX_ANCESTORS }
+	}
+
+	/// Set the upper bound on the number of ancestors returned for a single
+	/// `ChainApiMessage::Ancestors` request. Defaults to [`DEFAULT_MAX_ANCESTORS`].
+	pub fn with_max_ancestors(mut self, max_ancestors: usize) -> Self {
+		self.max_ancestors = max_ancestors;
+		self
 	}
 }
 
@@ -105,6 +120,16 @@ where
 					subsystem.metrics.on_request(result.is_ok());
 					let _ = response_channel.send(result);
 				},
+				ChainApiMessage::BlockHeaders(hashes, response_channel) => {
+					let _timer = subsystem.metrics.time_block_header();
+					let result = futures::future::try_join_all(
+						hashes.into_iter().map(|hash| subsystem.client.header(hash)),
+					)
+					.await
+					.map_err(|e| e.to_string().into());
+					subsystem.metrics.on_request(result.is_ok());
+					let _ = response_channel.send(result);
+				},
 				ChainApiMessage::BlockWeight(hash, response_channel) => {
 					let _timer = subsystem.metrics.time_block_weight();
 					let result = sc_consensus_babe::block_weight(&*subsystem.client, hash)
@@ -131,10 +156,32 @@ where
 					subsystem.metrics.on_request(result.is_ok());
 					let _ = response_channel.send(result);
 				},
+				ChainApiMessage::FinalizedBlock(response_channel) => {
+					let _timer = subsystem.metrics.time_finalized_block_number();
+					let result = subsystem
+						.client
+						.info()
+						.await
+						.map_err(|e| e.to_string().into())
+						.map(|info| (info.finalized_number, info.finalized_hash));
+					subsystem.metrics.on_request(result.is_ok());
+					let _ = response_channel.send(result);
+				},
 				ChainApiMessage::Ancestors { hash, k, response_channel } => {
 					let _timer = subsystem.metrics.time_ancestors();
 					gum::trace!(target: LOG_TARGET, hash=%hash, k=k, "ChainApiMessage::Ancestors");
 
+					let max_ancestors = subsystem.max_ancestors;
+					if k > max_ancestors {
+						gum::debug!(
+							target: LOG_TARGET,
+							hash = %hash,
+							k,
+							max_ancestors,
+							"Clamping the number of requested ancestors",
+						);
+					}
+
 					let next_parent_stream = futures::stream::unfold(
 						(hash, subsystem.client.clone()),
 						|(hash, client)| async move {
@@ -159,7 +206,20 @@ where
 						},
 					);
 
-					let result = next_parent_stream.take(k).try_collect().await;
+					let result = next_parent_stream.take(k.min(max_ancestors)).try_collect().await;
+					subsystem.metrics.on_request(result.is_ok());
+					let _ = response_channel.send(result);
+				},
+				ChainApiMessage::IsAncestor { ancestor, descendant, response_channel } => {
+					let _timer = subsystem.metrics.time_is_ancestor();
+					gum::trace!(
+						target: LOG_TARGET,
+						?ancestor,
+						?descendant,
+						"ChainApiMessage::IsAncestor",
+					);
+
+					let result = is_ancestor(&*subsystem.client, ancestor, descendant).await;
 					subsystem.metrics.on_request(result.is_ok());
 					let _ = response_channel.send(result);
 				},
@@ -167,3 +227,30 @@ where
 		}
 	}
 }
+
+/// Walks the ancestry of `descendant` back to the height of `ancestor`.
+///
+/// Returns `false` if either block is unknown.
+async fn is_ancestor<Client: ChainApiBackend>(
+	client: &Client,
+	ancestor: Hash,
+	descendant: Hash,
+) -> Result<bool, ChainApiError> {
+	let to_error = |e: sc_client_api::blockchain::Error| ChainApiError::from(e.to_string());
+
+	let Some(ancestor_number) = client.number(ancestor).await.map_err(to_error)? else {
+		return Ok(false)
+	};
+
+	let mut hash = descendant;
+	loop {
+		let Some(header) = client.header(hash).await.map_err(to_error)? else { return Ok(false) };
+		if header.number <= ancestor_number {
+			return Ok(false)
+		}
+		if header.parent_hash == ancestor {
+			return Ok(true)
+		}
+		hash = header.parent_hash;
+	}
+}
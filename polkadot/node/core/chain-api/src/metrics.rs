@@ -25,6 +25,7 @@ pub(crate) struct MetricsInner {
 	pub(crate) finalized_block_hash: prometheus::Histogram,
 	pub(crate) finalized_block_number: prometheus::Histogram,
 	pub(crate) ancestors: prometheus::Histogram,
+	pub(crate) is_ancestor: prometheus::Histogram,
 }
 
 /// Chain API metrics.
@@ -75,6 +76,11 @@ impl Metrics {
 	pub fn time_ancestors(&self) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
 		self.0.as_ref().map(|metrics| metrics.ancestors.start_timer())
 	}
+
+	/// Provide a timer for `is_ancestor` which observes on drop.
+	pub fn time_is_ancestor(&self) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
+		self.0.as_ref().map(|metrics| metrics.is_ancestor.start_timer())
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -132,6 +138,13 @@ impl metrics::Metrics for Metrics {
 				))?,
 				registry,
 			)?,
+			is_ancestor: prometheus::register(
+				prometheus::Histogram::with_opts(prometheus::HistogramOpts::new(
+					"polkadot_parachain_chain_api_is_ancestor",
+					"Time spent within `chain_api::is_ancestor`",
+				))?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
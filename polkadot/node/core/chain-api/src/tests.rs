@@ -151,11 +151,22 @@ fn test_harness(
 		Arc<TestClient>,
 		TestSubsystemContextHandle<ChainApiMessage>,
 	) -> BoxFuture<'static, ()>,
+) {
+	test_harness_with_max_ancestors(DEFAULT_MAX_ANCESTORS, test)
+}
+
+fn test_harness_with_max_ancestors(
+	max_ancestors: usize,
+	test: impl FnOnce(
+		Arc<TestClient>,
+		TestSubsystemContextHandle<ChainApiMessage>,
+	) -> BoxFuture<'static, ()>,
 ) {
 	let (ctx, ctx_handle) = make_subsystem_context(TaskExecutor::new());
 	let client = Arc::new(TestClient::default());
 
-	let subsystem = ChainApiSubsystem::new(client.clone(), Metrics(None));
+	let subsystem =
+		ChainApiSubsystem::new(client.clone(), Metrics(None)).with_max_ancestors(max_ancestors);
 	let chain_api_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = test(client, ctx_handle);
 
@@ -240,6 +251,33 @@ fn request_block_header() {
 	})
 }
 
+#[test]
+fn request_block_headers() {
+	test_harness(|client, mut sender| {
+		async move {
+			const NOT_HERE: Hash = Hash::repeat_byte(0x5);
+			let hashes = vec![TWO, NOT_HERE, FOUR];
+			let mut expected = Vec::new();
+			for hash in &hashes {
+				expected.push(client.header(*hash).await.unwrap());
+			}
+			assert!(expected[1].is_none());
+
+			let (tx, rx) = oneshot::channel();
+			sender
+				.send(FromOrchestra::Communication {
+					msg: ChainApiMessage::BlockHeaders(hashes, tx),
+				})
+				.await;
+
+			assert_eq!(rx.await.unwrap().unwrap(), expected);
+
+			sender.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+		}
+		.boxed()
+	})
+}
+
 #[test]
 fn request_block_weight() {
 	test_harness(|client, mut sender| {
@@ -315,6 +353,25 @@ fn request_last_finalized_number() {
 	})
 }
 
+#[test]
+fn request_last_finalized_block() {
+	test_harness(|client, mut sender| {
+		async move {
+			let (tx, rx) = oneshot::channel();
+
+			let info = client.info().await.unwrap();
+			sender
+				.send(FromOrchestra::Communication { msg: ChainApiMessage::FinalizedBlock(tx) })
+				.await;
+
+			assert_eq!(rx.await.unwrap().unwrap(), (info.finalized_number, info.finalized_hash));
+
+			sender.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+		}
+		.boxed()
+	})
+}
+
 #[test]
 fn request_ancestors() {
 	test_harness(|_client, mut sender| {
@@ -371,3 +428,72 @@ fn request_ancestors() {
 		.boxed()
 	})
 }
+
+#[test]
+fn request_ancestors_is_capped() {
+	test_harness_with_max_ancestors(2, |_client, mut sender| {
+		async move {
+			let (tx, rx) = oneshot::channel();
+			sender
+				.send(FromOrchestra::Communication {
+					msg: ChainApiMessage::Ancestors { hash: FOUR, k: 10, response_channel: tx },
+				})
+				.await;
+			assert_eq!(rx.await.unwrap().unwrap(), vec![THREE, TWO]);
+
+			// Requests below the cap are unaffected.
+			let (tx, rx) = oneshot::channel();
+			sender
+				.send(FromOrchestra::Communication {
+					msg: ChainApiMessage::Ancestors { hash: FOUR, k: 1, response_channel: tx },
+				})
+				.await;
+			assert_eq!(rx.await.unwrap().unwrap(), vec![THREE]);
+
+			sender.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+		}
+		.boxed()
+	})
+}
+
+#[test]
+fn request_is_ancestor() {
+	test_harness(|_client, mut sender| {
+		async move {
+			let cases = [
+				(ONE, FOUR, true),
+				(THREE, FOUR, true),
+				(GENESIS, TWO, true),
+				(FOUR, ONE, false),
+				// A block is not its own ancestor.
+				(TWO, TWO, false),
+				// Unknown blocks are never ancestors nor descendants.
+				(Hash::repeat_byte(0x05), FOUR, false),
+				(ONE, Hash::repeat_byte(0x05), false),
+			];
+
+			for (ancestor, descendant, expected) in cases {
+				let (tx, rx) = oneshot::channel();
+				sender
+					.send(FromOrchestra::Communication {
+						msg: ChainApiMessage::IsAncestor {
+							ancestor,
+							descendant,
+							response_channel: tx,
+						},
+					})
+					.await;
+				assert_eq!(
+					rx.await.unwrap().unwrap(),
+					expected,
+					"ancestor: {:?}, descendant: {:?}",
+					ancestor,
+					descendant,
+				);
+			}
+
+			sender.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+		}
+		.boxed()
+	})
+}
@@ -161,6 +161,10 @@ impl LeafEntrySet {
 	fn into_hashes_descending(self) -> impl Iterator<Item = Hash> {
 		self.inner.into_iter().map(|e| e.block_hash)
 	}
+
+	fn into_hashes_and_weights_descending(self) -> impl Iterator<Item = (Hash, BlockWeight)> {
+		self.inner.into_iter().map(|e| (e.block_hash, e.weight))
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -455,6 +459,10 @@ where
 							let leaves = load_leaves(ctx.sender(), &*backend).await?;
 							let _ = tx.send(leaves);
 						}
+						ChainSelectionMessage::LeavesWithScores(tx) => {
+							let leaves = load_leaves_with_scores(ctx.sender(), &*backend).await?;
+							let _ = tx.send(leaves);
+						}
 						ChainSelectionMessage::BestLeafContaining(required, tx) => {
 							let best_containing = backend::find_best_leaf_containing(
 								&*backend,
@@ -741,3 +749,24 @@ async fn load_leaves(
 		Ok(leaves)
 	}
 }
+
+// Load the leaves along with their weights from the backend. If there are no leaves,
+// then return the finalized block along with its weight, if known.
+async fn load_leaves_with_scores(
+	sender: &mut impl overseer::SubsystemSender<ChainApiMessage>,
+	backend: &impl Backend,
+) -> Result<Vec<(Hash, BlockWeight)>, Error> {
+	let leaves: Vec<_> = backend.load_leaves()?.into_hashes_and_weights_descending().collect();
+
+	if leaves.is_empty() {
+		let Some((finalized_hash, _)) = fetch_finalized(sender).await? else {
+			return Ok(Vec::new())
+		};
+
+		Ok(fetch_block_weight(sender, finalized_hash)
+			.await?
+			.map_or(Vec::new(), |weight| vec![(finalized_hash, weight)]))
+	} else {
+		Ok(leaves)
+	}
+}
@@ -607,6 +607,35 @@ async fn assert_finalized_leaves_query(
 	assert_eq!(rx.await.unwrap(), vec![finalized_hash]);
 }
 
+async fn assert_leaves_with_scores_query(
+	virtual_overseer: &mut VirtualOverseer,
+	leaves: Vec<(Hash, BlockWeight)>,
+) {
+	let (tx, rx) = oneshot::channel();
+	virtual_overseer
+		.send(FromOrchestra::Communication { msg: ChainSelectionMessage::LeavesWithScores(tx) })
+		.await;
+
+	assert_eq!(rx.await.unwrap(), leaves);
+}
+
+async fn assert_finalized_leaves_with_scores_query(
+	virtual_overseer: &mut VirtualOverseer,
+	finalized_number: BlockNumber,
+	finalized_hash: Hash,
+	finalized_weight: BlockWeight,
+) {
+	let (tx, rx) = oneshot::channel();
+	virtual_overseer
+		.send(FromOrchestra::Communication { msg: ChainSelectionMessage::LeavesWithScores(tx) })
+		.await;
+
+	answer_finalized_block_info(virtual_overseer, finalized_number, finalized_hash).await;
+	answer_weight_request(virtual_overseer, finalized_hash, finalized_weight).await;
+
+	assert_eq!(rx.await.unwrap(), vec![(finalized_hash, finalized_weight)]);
+}
+
 async fn best_leaf_containing(
 	virtual_overseer: &mut VirtualOverseer,
 	required: Hash,
@@ -801,6 +830,11 @@ fn leaves_ordered_by_weight_and_then_number() {
 		assert_backend_contains(&backend, chain_c.iter().map(|(h, _)| h));
 		assert_leaves(&backend, vec![c2_hash, a3_hash, b2_hash]);
 		assert_leaves_query(&mut virtual_overseer, vec![c2_hash, a3_hash, b2_hash]).await;
+		assert_leaves_with_scores_query(
+			&mut virtual_overseer,
+			vec![(c2_hash, 3), (a3_hash, 2), (b2_hash, 2)],
+		)
+		.await;
 		virtual_overseer
 	});
 }
@@ -879,6 +913,13 @@ fn reversion_removes_viability_of_chain() {
 		assert_leaves(&backend, vec![]);
 		assert_finalized_leaves_query(&mut virtual_overseer, finalized_number, finalized_hash)
 			.await;
+		assert_finalized_leaves_with_scores_query(
+			&mut virtual_overseer,
+			finalized_number,
+			finalized_hash,
+			0,
+		)
+		.await;
 
 		virtual_overseer
 	});
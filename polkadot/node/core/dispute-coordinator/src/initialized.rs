@@ -36,7 +36,7 @@ use polkadot_node_subsystem::{
 	messages::{
 		ApprovalVotingMessage, ApprovalVotingParallelMessage, BlockDescription,
 		ChainSelectionMessage, DisputeCoordinatorMessage, DisputeDistributionMessage,
-		ImportStatementsResult,
+		ImportStatementsItem, ImportStatementsResult,
 	},
 	overseer, ActivatedLeaf, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, RuntimeApiError,
 };
@@ -54,7 +54,7 @@ use schnellru::{LruMap, UnlimitedCompact};
 use crate::{
 	db,
 	error::{log_error, FatalError, FatalResult, JfyiError, JfyiResult, Result},
-	import::{CandidateEnvironment, CandidateVoteState},
+	import::{find_controlled_validator_indices, CandidateEnvironment, CandidateVoteState},
 	is_potential_spam,
 	metrics::Metrics,
 	scraping::ScrapedUpdates,
@@ -847,6 +847,40 @@ impl Initialized {
 					ImportStatementsResult::ValidImport => return Ok(Box::new(report)),
 				}
 			},
+			DisputeCoordinatorMessage::ImportStatementsBatch { items, pending_confirmation } => {
+				gum::trace!(
+					target: LOG_TARGET,
+					count = items.len(),
+					"DisputeCoordinatorMessage::ImportStatementsBatch"
+				);
+				let mut outcomes = Vec::with_capacity(items.len());
+				for ImportStatementsItem { candidate_receipt, session, statements } in items {
+					let outcome = self
+						.handle_import_statements(
+							ctx,
+							overlay_db,
+							MaybeCandidateReceipt::Provides(candidate_receipt),
+							session,
+							statements,
+							now,
+						)
+						.await?;
+					outcomes.push(outcome);
+				}
+				let any_valid = outcomes.contains(&ImportStatementsResult::ValidImport);
+				let report = move || match pending_confirmation {
+					Some(pending_confirmation) => pending_confirmation
+						.send(outcomes)
+						.map_err(|_| JfyiError::DisputeImportOneshotSend),
+					None => Ok(()),
+				};
+
+				// In case of any valid import, delay confirmation until actual disk write:
+				if any_valid {
+					return Ok(Box::new(report))
+				}
+				report()?;
+			},
 			DisputeCoordinatorMessage::RecentDisputes(tx) => {
 				gum::trace!(target: LOG_TARGET, "Loading recent disputes from db");
 				let recent_disputes = if let Some(disputes) = overlay_db.load_recent_disputes()? {
@@ -912,9 +946,19 @@ impl Initialized {
 				)
 				.await?;
 			},
+			DisputeCoordinatorMessage::GetLocalStatementStatus(session, candidate_hash, tx) => {
+				gum::trace!(
+					target: LOG_TARGET,
+					"DisputeCoordinatorMessage::GetLocalStatementStatus"
+				);
+				let status =
+					self.local_statement_status(ctx, overlay_db, session, candidate_hash).await?;
+				let _ = tx.send(status);
+			},
 			DisputeCoordinatorMessage::DetermineUndisputedChain {
 				base: (base_number, base_hash),
 				block_descriptions,
+				max_depth,
 				tx,
 			} => {
 				gum::trace!(
@@ -927,15 +971,73 @@ impl Initialized {
 					base_number,
 					base_hash,
 					block_descriptions,
+					max_depth,
 				)?;
 
 				let _ = tx.send(undisputed_chain);
 			},
+			DisputeCoordinatorMessage::PruneFinalized(finalized, tx) => {
+				gum::trace!(
+					target: LOG_TARGET,
+					?finalized,
+					"DisputeCoordinatorMessage::PruneFinalized"
+				);
+				let pruned = self.prune_finalized_disputes(overlay_db, finalized)?;
+				// Only confirm once the pruning is written to disk:
+				return Ok(Box::new(move || {
+					let _ = tx.send(pruned);
+					Ok(())
+				}))
+			},
 		}
 
 		Ok(Box::new(|| Ok(())))
 	}
 
+	/// Prunes the concluded disputes of candidates only included below the `finalized` block
+	/// number, together with their votes.
+	///
+	/// Candidates without any known inclusion (e.g. never included or with inclusions already
+	/// removed from the scraper) are pruned once their session is older than the highest session
+	/// seen. Unconcluded disputes are always kept.
+	///
+	/// Returns the number of pruned disputes.
+	fn prune_finalized_disputes(
+		&self,
+		overlay_db: &mut OverlayedBackend<'_, impl Backend>,
+		finalized: BlockNumber,
+	) -> FatalResult<usize> {
+		let Some(mut recent_disputes) = overlay_db.load_recent_disputes()? else { return Ok(0) };
+
+		let prunable = recent_disputes
+			.iter()
+			.filter(|(_, status)| status.concluded_at().is_some())
+			.filter(|((session, candidate_hash), _)| {
+				let inclusions = self.scraper.get_blocks_including_candidate(candidate_hash);
+				if inclusions.is_empty() {
+					*session < self.highest_session_seen
+				} else {
+					inclusions.iter().all(|(number, _)| *number < finalized)
+				}
+			})
+			.map(|(key, _)| *key)
+			.collect::<Vec<_>>();
+
+		if prunable.is_empty() {
+			return Ok(0)
+		}
+
+		for (session, candidate_hash) in &prunable {
+			recent_disputes.remove(&(*session, *candidate_hash));
+			overlay_db.delete_candidate_votes(*session, *candidate_hash);
+		}
+		overlay_db.write_recent_disputes(recent_disputes);
+
+		gum::debug!(target: LOG_TARGET, ?finalized, count = prunable.len(), "Pruned finalized disputes");
+
+		Ok(prunable.len())
+	}
+
 	// We use fatal result rather than result here. Reason being, We for example increase
 	// spam slots in this function. If then the import fails for some non fatal and
 	// unrelated reason, we should likely actually decrement previously incremented spam
@@ -1427,6 +1529,53 @@ impl Initialized {
 		Ok(ImportStatementsResult::ValidImport)
 	}
 
+	/// Check whether any of our validators already voted on the given candidate.
+	///
+	/// Returns `Some(false)` if we voted against the candidate, `Some(true)` if we only voted for
+	/// it and `None` if we did not vote (or the session info is not available).
+	async fn local_statement_status<Context>(
+		&mut self,
+		ctx: &mut Context,
+		overlay_db: &mut OverlayedBackend<'_, impl Backend>,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+	) -> Result<Option<bool>> {
+		let Some(votes) = overlay_db.load_candidate_votes(session, &candidate_hash)? else {
+			return Ok(None)
+		};
+
+		let relay_parent = votes.candidate_receipt.descriptor.relay_parent();
+		let validators = match self
+			.runtime_info
+			.get_session_info_by_index(ctx.sender(), relay_parent, session)
+			.await
+		{
+			Ok(extended_session_info) => &extended_session_info.session_info.validators,
+			Err(err) => {
+				gum::debug!(
+					target: LOG_TARGET,
+					?session,
+					?candidate_hash,
+					?err,
+					"Could not retrieve session info for local statement status",
+				);
+				return Ok(None)
+			},
+		};
+
+		let controlled_indices = find_controlled_validator_indices(&self.keystore, validators);
+		let status = if votes.invalid.iter().any(|(_, index, _)| controlled_indices.contains(index))
+		{
+			Some(false)
+		} else if votes.valid.iter().any(|(_, index, _)| controlled_indices.contains(index)) {
+			Some(true)
+		} else {
+			None
+		};
+
+		Ok(status)
+	}
+
 	async fn issue_local_statement<Context>(
 		&mut self,
 		ctx: &mut Context,
@@ -1620,8 +1769,13 @@ fn determine_undisputed_chain(
 	overlay_db: &mut OverlayedBackend<'_, impl Backend>,
 	base_number: BlockNumber,
 	base_hash: Hash,
-	block_descriptions: Vec<BlockDescription>,
+	mut block_descriptions: Vec<BlockDescription>,
+	max_depth: Option<BlockNumber>,
 ) -> Result<(BlockNumber, Hash)> {
+	if let Some(max_depth) = max_depth {
+		block_descriptions.truncate(max_depth as usize);
+	}
+
 	let last = block_descriptions
 		.last()
 		.map(|e| (base_number + block_descriptions.len() as BlockNumber, e.block_hash))
@@ -14,9 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::collections::HashSet;
-#[cfg(test)]
-use std::time::Duration;
+use std::{collections::HashSet, time::Duration};
 
 use futures::{
 	channel::{mpsc, oneshot},
@@ -27,13 +25,16 @@ use futures_timer::Delay;
 
 use polkadot_node_primitives::ValidationResult;
 use polkadot_node_subsystem::{
-	messages::{AvailabilityRecoveryMessage, CandidateValidationMessage, PvfExecKind},
+	messages::{
+		AvailabilityRecoveryMessage, CandidateValidationMessage, PvfExecKind, RecoveryStrategy,
+	},
 	overseer, ActiveLeavesUpdate, RecoveryError,
 };
 use polkadot_node_subsystem_util::runtime::get_validation_code_by_hash;
 use polkadot_primitives::{
-	vstaging::CandidateReceiptV2 as CandidateReceipt, BlockNumber, CandidateHash, Hash,
-	SessionIndex,
+	executor_params::DEFAULT_APPROVAL_EXECUTION_TIMEOUT,
+	vstaging::CandidateReceiptV2 as CandidateReceipt, BlockNumber, CandidateHash, ExecutorParams,
+	Hash, PvfExecKind as RuntimePvfExecKind, SessionIndex,
 };
 
 use crate::LOG_TARGET;
@@ -62,6 +63,12 @@ const MAX_PARALLEL_PARTICIPATIONS: usize = 3;
 #[cfg(test)]
 pub(crate) const MAX_PARALLEL_PARTICIPATIONS: usize = 1;
 
+/// By how much the approval execution timeout is extended when participating in a dispute.
+///
+/// A dispute is the last line of defence, so a slow but honest validator should rather take its
+/// time than cast an invalid vote because of a timeout.
+const DISPUTE_EXECUTION_TIMEOUT_FACTOR: u32 = 2;
+
 /// Keep track of disputes we need to participate in.
 ///
 /// - Prioritize and queue participations
@@ -307,6 +314,7 @@ async fn participate(
 			req.session(),
 			None,
 			None,
+			RecoveryStrategy::FullNetwork,
 			recover_available_data_tx,
 		))
 		.await;
@@ -377,9 +385,11 @@ async fn participate(
 	// Issue a request to validate the candidate with the provided exhaustive
 	// parameters
 	//
-	// We use the approval execution timeout because this is intended to
-	// be run outside of backing and therefore should be subject to the
+	// We use an extended approval execution timeout because this is intended to
+	// be run outside of backing and therefore should be subject to at least the
 	// same level of leeway.
+	let executor_params = req.executor_params();
+	let exec_timeout = dispute_execution_timeout(&executor_params);
 	let (validation_tx, validation_rx) = oneshot::channel();
 	sender
 		.send_message(CandidateValidationMessage::ValidateFromExhaustive {
@@ -387,8 +397,10 @@ async fn participate(
 			validation_code,
 			candidate_receipt: req.candidate_receipt().clone(),
 			pov: available_data.pov,
-			executor_params: req.executor_params(),
+			executor_params,
 			exec_kind: PvfExecKind::Dispute,
+			exec_timeout: Some(exec_timeout),
+			memory_limit_override: None,
 			response_sender: validation_tx,
 		})
 		.await;
@@ -446,3 +458,11 @@ async fn send_result(
 		);
 	}
 }
+
+/// The execution timeout used when participating in a dispute.
+fn dispute_execution_timeout(executor_params: &ExecutorParams) -> Duration {
+	executor_params
+		.pvf_exec_timeout(RuntimePvfExecKind::Approval)
+		.unwrap_or(DEFAULT_APPROVAL_EXECUTION_TIMEOUT) *
+		DISPUTE_EXECUTION_TIMEOUT_FACTOR
+}
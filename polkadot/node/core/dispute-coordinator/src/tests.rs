@@ -38,7 +38,7 @@ use polkadot_node_primitives::{
 use polkadot_node_subsystem::{
 	messages::{
 		ApprovalVotingMessage, ChainApiMessage, ChainSelectionMessage, DisputeCoordinatorMessage,
-		DisputeDistributionMessage, ImportStatementsResult,
+		DisputeDistributionMessage, ImportStatementsItem, ImportStatementsResult,
 	},
 	overseer::FromOrchestra,
 	OverseerSignal,
@@ -1367,6 +1367,94 @@ fn backing_statements_import_works_and_no_spam() {
 	});
 }
 
+#[test]
+fn batch_statements_import_works() {
+	test_harness(|mut test_state, mut virtual_overseer| {
+		Box::pin(async move {
+			let session = 1;
+
+			test_state.handle_resume_sync(&mut virtual_overseer, session).await;
+
+			let candidate_receipt1 = make_valid_candidate_receipt();
+			let candidate_hash1 = candidate_receipt1.hash();
+			let candidate_receipt2 = make_invalid_candidate_receipt();
+			let candidate_hash2 = candidate_receipt2.hash();
+
+			test_state
+				.activate_leaf_at_session(&mut virtual_overseer, session, 1, Vec::new())
+				.await;
+
+			let items =
+				[(&candidate_receipt1, candidate_hash1), (&candidate_receipt2, candidate_hash2)]
+					.into_iter()
+					.map(|(candidate_receipt, candidate_hash)| ImportStatementsItem {
+						candidate_receipt: candidate_receipt.clone(),
+						session,
+						statements: vec![
+							(
+								test_state.issue_backing_statement_with_index(
+									ValidatorIndex(3),
+									candidate_hash,
+									session,
+								),
+								ValidatorIndex(3),
+							),
+							(
+								test_state.issue_backing_statement_with_index(
+									ValidatorIndex(4),
+									candidate_hash,
+									session,
+								),
+								ValidatorIndex(4),
+							),
+						],
+					})
+					.collect();
+
+			let (pending_confirmation, confirmation_rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::ImportStatementsBatch {
+						items,
+						pending_confirmation: Some(pending_confirmation),
+					},
+				})
+				.await;
+			handle_disabled_validators_queries(&mut virtual_overseer, Vec::new()).await;
+			assert_eq!(
+				confirmation_rx.await.unwrap(),
+				vec![ImportStatementsResult::ValidImport, ImportStatementsResult::ValidImport]
+			);
+
+			{
+				let (tx, rx) = oneshot::channel();
+				virtual_overseer
+					.send(FromOrchestra::Communication {
+						msg: DisputeCoordinatorMessage::QueryCandidateVotes(
+							vec![(session, candidate_hash1), (session, candidate_hash2)],
+							tx,
+						),
+					})
+					.await;
+
+				let votes = rx.await.unwrap();
+				assert_eq!(votes.len(), 2);
+				for (_, _, votes) in votes {
+					assert_eq!(votes.valid.raw().len(), 2);
+					assert_eq!(votes.invalid.len(), 0);
+				}
+			}
+
+			virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+
+			// No more messages expected:
+			assert!(virtual_overseer.try_recv().await.is_none());
+
+			test_state
+		})
+	});
+}
+
 #[test]
 fn conflicting_votes_lead_to_dispute_participation() {
 	test_harness(|mut test_state, mut virtual_overseer| {
@@ -1753,6 +1841,7 @@ fn finality_votes_ignore_disputed_candidates() {
 								session,
 								candidates: vec![candidate_hash],
 							}],
+							max_depth: None,
 							tx,
 						},
 					})
@@ -1777,6 +1866,7 @@ fn finality_votes_ignore_disputed_candidates() {
 									candidates: vec![candidate_hash],
 								},
 							],
+							max_depth: None,
 							tx,
 						},
 					})
@@ -1793,6 +1883,103 @@ fn finality_votes_ignore_disputed_candidates() {
 	});
 }
 
+#[test]
+fn undisputed_chain_respects_max_depth() {
+	test_harness(|mut test_state, mut virtual_overseer| {
+		Box::pin(async move {
+			let session = 1;
+
+			test_state.handle_resume_sync(&mut virtual_overseer, session).await;
+
+			let candidate_receipt = make_valid_candidate_receipt();
+			let candidate_hash = candidate_receipt.hash();
+
+			test_state
+				.activate_leaf_at_session(
+					&mut virtual_overseer,
+					session,
+					1,
+					vec![make_candidate_backed_event(candidate_receipt.clone())],
+				)
+				.await;
+
+			let (valid_vote, invalid_vote) = generate_opposing_votes_pair(
+				&test_state,
+				ValidatorIndex(2),
+				ValidatorIndex(1),
+				candidate_hash,
+				session,
+				VoteType::Explicit,
+			)
+			.await;
+
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::ImportStatements {
+						candidate_receipt: candidate_receipt.clone(),
+						session,
+						statements: vec![
+							(valid_vote, ValidatorIndex(2)),
+							(invalid_vote, ValidatorIndex(1)),
+						],
+						pending_confirmation: None,
+					},
+				})
+				.await;
+			handle_disabled_validators_queries(&mut virtual_overseer, Vec::new()).await;
+			handle_approval_vote_request(&mut virtual_overseer, &candidate_hash, HashMap::new())
+				.await;
+
+			participation_with_distribution(
+				&mut virtual_overseer,
+				&candidate_hash,
+				candidate_receipt.commitments_hash,
+			)
+			.await;
+
+			{
+				let base_block = Hash::repeat_byte(0x0f);
+				// 200 blocks above the base, the disputed candidate is included in block 161.
+				let block_descriptions = (0..200u64)
+					.map(|i| BlockDescription {
+						block_hash: Hash::from_low_u64_be(i),
+						session,
+						candidates: if i == 150 { vec![candidate_hash] } else { vec![] },
+					})
+					.collect::<Vec<_>>();
+
+				let cases = [
+					(None, (160, Hash::from_low_u64_be(149))),
+					// The disputed block is beyond the window.
+					(Some(5), (15, Hash::from_low_u64_be(4))),
+					// No blocks are considered at all.
+					(Some(0), (10, base_block)),
+				];
+				for (max_depth, expected) in cases {
+					let (tx, rx) = oneshot::channel();
+					virtual_overseer
+						.send(FromOrchestra::Communication {
+							msg: DisputeCoordinatorMessage::DetermineUndisputedChain {
+								base: (10, base_block),
+								block_descriptions: block_descriptions.clone(),
+								max_depth,
+								tx,
+							},
+						})
+						.await;
+
+					assert_eq!(rx.await.unwrap(), expected);
+				}
+			}
+
+			virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+			assert!(virtual_overseer.try_recv().await.is_none());
+
+			test_state
+		})
+	});
+}
+
 #[test]
 fn supermajority_valid_dispute_may_be_finalized() {
 	test_harness(|mut test_state, mut virtual_overseer| {
@@ -1887,6 +2074,7 @@ fn supermajority_valid_dispute_may_be_finalized() {
 								session,
 								candidates: vec![candidate_hash],
 							}],
+							max_depth: None,
 							tx,
 						},
 					})
@@ -1911,6 +2099,7 @@ fn supermajority_valid_dispute_may_be_finalized() {
 									candidates: vec![candidate_hash],
 								},
 							],
+							max_depth: None,
 							tx,
 						},
 					})
@@ -2042,6 +2231,142 @@ fn concluded_supermajority_for_non_active_after_time() {
 	});
 }
 
+#[test]
+fn concluded_disputes_get_pruned_below_finalized_block() {
+	test_harness(|mut test_state, mut virtual_overseer| {
+		Box::pin(async move {
+			let session = 1;
+
+			test_state.handle_resume_sync(&mut virtual_overseer, session).await;
+
+			let candidate_receipt = make_valid_candidate_receipt();
+			let candidate_hash = candidate_receipt.hash();
+
+			test_state
+				.activate_leaf_at_session(
+					&mut virtual_overseer,
+					session,
+					1,
+					vec![make_candidate_included_event(candidate_receipt.clone())],
+				)
+				.await;
+
+			let supermajority_threshold =
+				polkadot_primitives::supermajority_threshold(test_state.validators.len());
+
+			let (valid_vote, invalid_vote) = generate_opposing_votes_pair(
+				&test_state,
+				ValidatorIndex(2),
+				ValidatorIndex(1),
+				candidate_hash,
+				session,
+				VoteType::Explicit,
+			)
+			.await;
+
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::ImportStatements {
+						candidate_receipt: candidate_receipt.clone(),
+						session,
+						statements: vec![
+							(valid_vote, ValidatorIndex(2)),
+							(invalid_vote, ValidatorIndex(1)),
+						],
+						pending_confirmation: None,
+					},
+				})
+				.await;
+			handle_disabled_validators_queries(&mut virtual_overseer, Vec::new()).await;
+			handle_approval_vote_request(&mut virtual_overseer, &candidate_hash, HashMap::new())
+				.await;
+
+			participation_with_distribution(
+				&mut virtual_overseer,
+				&candidate_hash,
+				candidate_receipt.commitments_hash,
+			)
+			.await;
+
+			// The dispute is still active, nothing gets pruned.
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::PruneFinalized(2, tx),
+				})
+				.await;
+			assert_eq!(rx.await.unwrap(), 0);
+
+			let mut statements = Vec::new();
+			// -2: 1 for already imported vote and one for local vote (which is valid).
+			for i in (0_u32..supermajority_threshold as u32 - 2).map(|i| i + 3) {
+				let vote = test_state.issue_explicit_statement_with_index(
+					ValidatorIndex(i),
+					candidate_hash,
+					session,
+					true,
+				);
+
+				statements.push((vote, ValidatorIndex(i as _)));
+			}
+
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::ImportStatements {
+						candidate_receipt: candidate_receipt.clone(),
+						session,
+						statements,
+						pending_confirmation: None,
+					},
+				})
+				.await;
+			handle_approval_vote_request(&mut virtual_overseer, &candidate_hash, HashMap::new())
+				.await;
+
+			// The candidate is included at the finalized block, the dispute is kept.
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::PruneFinalized(1, tx),
+				})
+				.await;
+			assert_eq!(rx.await.unwrap(), 0);
+
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::PruneFinalized(2, tx),
+				})
+				.await;
+			assert_eq!(rx.await.unwrap(), 1);
+
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::RecentDisputes(tx),
+				})
+				.await;
+			assert!(rx.await.unwrap().is_empty());
+
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::QueryCandidateVotes(
+						vec![(session, candidate_hash)],
+						tx,
+					),
+				})
+				.await;
+			assert!(rx.await.unwrap().is_empty());
+
+			virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+			assert!(virtual_overseer.try_recv().await.is_none());
+
+			test_state
+		})
+	});
+}
+
 #[test]
 fn concluded_supermajority_against_non_active_after_time() {
 	test_harness(|mut test_state, mut virtual_overseer| {
@@ -3410,6 +3735,70 @@ fn negative_issue_local_statement_only_triggers_import() {
 	});
 }
 
+#[test]
+fn local_statement_status_reflects_own_votes() {
+	test_harness(|mut test_state, mut virtual_overseer| {
+		Box::pin(async move {
+			let session = 1;
+
+			test_state.handle_resume_sync(&mut virtual_overseer, session).await;
+
+			let candidate_receipt = make_invalid_candidate_receipt();
+			let candidate_hash = candidate_receipt.hash();
+
+			test_state
+				.activate_leaf_at_session(&mut virtual_overseer, session, 1, Vec::new())
+				.await;
+
+			// We have not voted yet:
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::GetLocalStatementStatus(
+						session,
+						candidate_hash,
+						tx,
+					),
+				})
+				.await;
+			assert_eq!(rx.await.unwrap(), None);
+
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::IssueLocalStatement(
+						session,
+						candidate_hash,
+						candidate_receipt.clone(),
+						false,
+					),
+				})
+				.await;
+
+			handle_disabled_validators_queries(&mut virtual_overseer, Vec::new()).await;
+
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::GetLocalStatementStatus(
+						session,
+						candidate_hash,
+						tx,
+					),
+				})
+				.await;
+			assert_eq!(rx.await.unwrap(), Some(false));
+
+			// Status is read-only, nothing else happens:
+			assert!(virtual_overseer.recv().timeout(TEST_TIMEOUT).await.is_none());
+
+			virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+			assert!(virtual_overseer.try_recv().await.is_none());
+
+			test_state
+		})
+	});
+}
+
 #[test]
 fn redundant_votes_ignored() {
 	test_harness(|mut test_state, mut virtual_overseer| {
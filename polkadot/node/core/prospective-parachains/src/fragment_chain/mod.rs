@@ -127,7 +127,7 @@ use std::{
 };
 
 use super::LOG_TARGET;
-use polkadot_node_subsystem::messages::Ancestors;
+use polkadot_node_subsystem::messages::{Ancestors, HypotheticalCandidate};
 use polkadot_node_subsystem_util::inclusion_emulator::{
 	self, validate_commitments, ConstraintModifications, Constraints, Fragment,
 	HypotheticalOrConcreteCandidate, ProspectiveCandidate, RelayChainBlockInfo,
@@ -427,6 +427,73 @@ impl HypotheticalOrConcreteCandidate for CandidateEntry {
 	}
 }
 
+/// A complete hypothetical candidate, with the values needed by the potential checks computed
+/// once up-front.
+///
+/// Checking a [`HypotheticalCandidate`] directly hashes its parent head data on every check, which
+/// adds up when it is checked against the fragment chains of many leaves.
+#[derive(Debug, Clone)]
+pub(crate) struct CompleteHypotheticalCandidate<'a> {
+	candidate_hash: CandidateHash,
+	parent_head_data_hash: Hash,
+	output_head_data_hash: Hash,
+	relay_parent: Hash,
+	commitments: &'a CandidateCommitments,
+	persisted_validation_data: &'a PersistedValidationData,
+	validation_code_hash: ValidationCodeHash,
+}
+
+impl<'a> CompleteHypotheticalCandidate<'a> {
+	/// Returns `None` if the candidate is incomplete.
+	pub fn new(candidate: &'a HypotheticalCandidate) -> Option<Self> {
+		let HypotheticalCandidate::Complete { candidate_hash, receipt, persisted_validation_data } =
+			candidate
+		else {
+			return None
+		};
+
+		Some(Self {
+			candidate_hash: *candidate_hash,
+			parent_head_data_hash: persisted_validation_data.parent_head.hash(),
+			output_head_data_hash: receipt.descriptor.para_head(),
+			relay_parent: receipt.descriptor.relay_parent(),
+			commitments: &receipt.commitments,
+			persisted_validation_data,
+			validation_code_hash: receipt.descriptor.validation_code_hash(),
+		})
+	}
+}
+
+impl HypotheticalOrConcreteCandidate for CompleteHypotheticalCandidate<'_> {
+	fn commitments(&self) -> Option<&CandidateCommitments> {
+		Some(self.commitments)
+	}
+
+	fn persisted_validation_data(&self) -> Option<&PersistedValidationData> {
+		Some(self.persisted_validation_data)
+	}
+
+	fn validation_code_hash(&self) -> Option<ValidationCodeHash> {
+		Some(self.validation_code_hash)
+	}
+
+	fn parent_head_data_hash(&self) -> Hash {
+		self.parent_head_data_hash
+	}
+
+	fn output_head_data_hash(&self) -> Option<Hash> {
+		Some(self.output_head_data_hash)
+	}
+
+	fn relay_parent(&self) -> Hash {
+		self.relay_parent
+	}
+
+	fn candidate_hash(&self) -> CandidateHash {
+		self.candidate_hash
+	}
+}
+
 /// A candidate existing on-chain but pending availability, for special treatment
 /// in the [`Scope`].
 #[derive(Debug, Clone)]
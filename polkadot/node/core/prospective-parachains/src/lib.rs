@@ -35,16 +35,16 @@ use futures::{channel::oneshot, prelude::*};
 
 use polkadot_node_subsystem::{
 	messages::{
-		Ancestors, ChainApiMessage, HypotheticalCandidate, HypotheticalMembership,
-		HypotheticalMembershipRequest, IntroduceSecondedCandidateRequest, ParentHeadData,
-		ProspectiveParachainsMessage, ProspectiveValidationDataRequest, RuntimeApiMessage,
-		RuntimeApiRequest,
+		Ancestors, ChainApiMessage, FragmentTreeStats, HypotheticalCandidate,
+		HypotheticalMembership, HypotheticalMembershipRequest, IntroduceSecondedCandidateRequest,
+		ParentHeadData, ProspectiveParachainsMessage, ProspectiveValidationDataRequest,
+		RuntimeApiMessage, RuntimeApiRequest,
 	},
 	overseer, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, SpawnedSubsystem, SubsystemError,
 };
 use polkadot_node_subsystem_util::{
 	backing_implicit_view::{BlockInfoProspectiveParachains as BlockInfo, View as ImplicitView},
-	inclusion_emulator::{Constraints, RelayChainBlockInfo},
+	inclusion_emulator::{Constraints, HypotheticalOrConcreteCandidate, RelayChainBlockInfo},
 	request_backing_constraints, request_candidates_pending_availability,
 	request_session_index_for_child,
 	runtime::{fetch_claim_queue, fetch_scheduling_lookahead},
@@ -57,7 +57,8 @@ use polkadot_primitives::{
 use crate::{
 	error::{FatalError, FatalResult, JfyiError, JfyiErrorResult, Result},
 	fragment_chain::{
-		CandidateEntry, Error as FragmentChainError, FragmentChain, Scope as FragmentChainScope,
+		CandidateEntry, CompleteHypotheticalCandidate, Error as FragmentChainError, FragmentChain,
+		Scope as FragmentChainScope,
 	},
 };
 
@@ -172,6 +173,10 @@ async fn run_iteration<Context>(
 					answer_minimum_relay_parents_request(&view, relay_parent, tx),
 				ProspectiveParachainsMessage::GetProspectiveValidationData(request, tx) =>
 					answer_prospective_validation_data_request(&view, request, tx),
+				ProspectiveParachainsMessage::GetProspectiveValidationDataBatch(requests, tx) =>
+					answer_prospective_validation_data_batch_request(&view, requests, tx),
+				ProspectiveParachainsMessage::GetFragmentTreeStats(tx) =>
+					answer_fragment_tree_stats_request(&view, tx),
 			},
 		}
 	}
@@ -754,23 +759,72 @@ fn answer_hypothetical_membership_request(
 ) {
 	let _timer = metrics.time_hypothetical_membership_request();
 
-	let mut response = Vec::with_capacity(request.candidates.len());
-	for candidate in request.candidates {
-		response.push((candidate, vec![]));
+	let required_active_leaf = request.fragment_chain_relay_parent;
+	// Complete candidates are the common case, they are checked with their hashes computed once.
+	let complete_candidates = request
+		.candidates
+		.iter()
+		.map(|candidate| {
+			CompleteHypotheticalCandidate::new(candidate)
+				.map(|complete| (candidate.candidate_para(), complete))
+		})
+		.collect::<Option<Vec<_>>>();
+	let memberships = match complete_candidates {
+		Some(candidates) => hypothetical_memberships(
+			view,
+			required_active_leaf,
+			&candidates
+				.iter()
+				.map(|(para, candidate)| (*para, candidate))
+				.collect::<Vec<_>>(),
+		),
+		None => hypothetical_memberships(
+			view,
+			required_active_leaf,
+			&request
+				.candidates
+				.iter()
+				.map(|candidate| (candidate.candidate_para(), candidate))
+				.collect::<Vec<_>>(),
+		),
+	};
+	let response = request.candidates.into_iter().zip(memberships).collect::<Vec<_>>();
+
+	for (candidate, membership) in &response {
+		if membership.is_empty() {
+			gum::debug!(
+				target: LOG_TARGET,
+				para = ?candidate.candidate_para(),
+				active_leaves = ?view.active_leaves,
+				?required_active_leaf,
+				candidate = ?candidate.candidate_hash(),
+				"Candidate is not a hypothetical member on any of the active leaves",
+			)
+		}
 	}
 
-	let required_active_leaf = request.fragment_chain_relay_parent;
+	let _ = tx.send(response);
+}
+
+/// Returns the active leaves on which the given candidates are hypothetical members, in the order
+/// of the candidates.
+fn hypothetical_memberships<C: HypotheticalOrConcreteCandidate>(
+	view: &View,
+	required_active_leaf: Option<Hash>,
+	candidates: &[(ParaId, &C)],
+) -> Vec<HypotheticalMembership> {
+	let mut memberships = vec![HypotheticalMembership::new(); candidates.len()];
+
 	for active_leaf in view
 		.active_leaves
 		.iter()
 		.filter(|h| required_active_leaf.as_ref().map_or(true, |x| h == &x))
 	{
 		let Some(leaf_view) = view.per_relay_parent.get(&active_leaf) else { continue };
-		for &mut (ref candidate, ref mut membership) in &mut response {
-			let para_id = &candidate.candidate_para();
+		for ((para_id, candidate), membership) in candidates.iter().zip(&mut memberships) {
 			let Some(fragment_chain) = leaf_view.fragment_chains.get(para_id) else { continue };
 
-			let res = fragment_chain.can_add_candidate_as_potential(candidate);
+			let res = fragment_chain.can_add_candidate_as_potential(*candidate);
 			match res {
 				Err(FragmentChainError::CandidateAlreadyKnown) | Ok(()) => {
 					membership.push(*active_leaf);
@@ -789,20 +843,7 @@ fn answer_hypothetical_membership_request(
 		}
 	}
 
-	for (candidate, membership) in &response {
-		if membership.is_empty() {
-			gum::debug!(
-				target: LOG_TARGET,
-				para = ?candidate.candidate_para(),
-				active_leaves = ?view.active_leaves,
-				?required_active_leaf,
-				candidate = ?candidate.candidate_hash(),
-				"Candidate is not a hypothetical member on any of the active leaves",
-			)
-		}
-	}
-
-	let _ = tx.send(response);
+	memberships
 }
 
 fn answer_minimum_relay_parents_request(
@@ -822,11 +863,50 @@ fn answer_minimum_relay_parents_request(
 	let _ = tx.send(v);
 }
 
+fn answer_fragment_tree_stats_request(view: &View, tx: oneshot::Sender<Vec<FragmentTreeStats>>) {
+	let mut v = Vec::new();
+	for active_leaf in &view.active_leaves {
+		if let Some(leaf_data) = view.per_relay_parent.get(active_leaf) {
+			for (para_id, fragment_chain) in &leaf_data.fragment_chains {
+				v.push(FragmentTreeStats {
+					relay_parent: *active_leaf,
+					para_id: *para_id,
+					node_count: fragment_chain.best_chain_len() + fragment_chain.unconnected_len(),
+					max_depth: fragment_chain.best_chain_len(),
+				});
+			}
+		}
+	}
+	v.sort_by_key(|stats| (stats.relay_parent, stats.para_id));
+
+	let _ = tx.send(v);
+}
+
 fn answer_prospective_validation_data_request(
 	view: &View,
 	request: ProspectiveValidationDataRequest,
 	tx: oneshot::Sender<Option<PersistedValidationData>>,
 ) {
+	let _ = tx.send(prospective_validation_data(view, request));
+}
+
+fn answer_prospective_validation_data_batch_request(
+	view: &View,
+	requests: Vec<ProspectiveValidationDataRequest>,
+	tx: oneshot::Sender<Vec<Option<PersistedValidationData>>>,
+) {
+	let _ = tx.send(
+		requests
+			.into_iter()
+			.map(|request| prospective_validation_data(view, request))
+			.collect(),
+	);
+}
+
+fn prospective_validation_data(
+	view: &View,
+	request: ProspectiveValidationDataRequest,
+) -> Option<PersistedValidationData> {
 	// Try getting the needed data from any fragment chain.
 
 	let (mut head_data, parent_head_data_hash) = match request.parent_head_data {
@@ -864,7 +944,7 @@ fn answer_prospective_validation_data_request(
 		}
 	}
 
-	let _ = tx.send(match (head_data, relay_parent_info, max_pov_size) {
+	match (head_data, relay_parent_info, max_pov_size) {
 		(Some(h), Some(i), Some(m)) => Some(PersistedValidationData {
 			parent_head: h,
 			relay_parent_number: i.number,
@@ -872,7 +952,7 @@ fn answer_prospective_validation_data_request(
 			max_pov_size: m as _,
 		}),
 		_ => None,
-	});
+	}
 }
 
 #[overseer::contextbounds(ProspectiveParachains, prefix = self::overseer)]
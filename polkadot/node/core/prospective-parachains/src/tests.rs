@@ -563,6 +563,39 @@ async fn get_hypothetical_membership(
 	);
 }
 
+async fn get_hypothetical_memberships(
+	virtual_overseer: &mut VirtualOverseer,
+	candidates: Vec<HypotheticalCandidate>,
+) -> Vec<(HypotheticalCandidate, HypotheticalMembership)> {
+	let request = HypotheticalMembershipRequest { candidates, fragment_chain_relay_parent: None };
+	let (tx, rx) = oneshot::channel();
+	virtual_overseer
+		.send(overseer::FromOrchestra::Communication {
+			msg: ProspectiveParachainsMessage::GetHypotheticalMembership(request, tx),
+		})
+		.await;
+	rx.await.unwrap()
+}
+
+async fn get_fragment_tree_stats(
+	virtual_overseer: &mut VirtualOverseer,
+	expected: Vec<(Hash, ParaId, usize, usize)>,
+) {
+	let (tx, rx) = oneshot::channel();
+	virtual_overseer
+		.send(overseer::FromOrchestra::Communication {
+			msg: ProspectiveParachainsMessage::GetFragmentTreeStats(tx),
+		})
+		.await;
+	let stats = rx
+		.await
+		.unwrap()
+		.into_iter()
+		.map(|stats| (stats.relay_parent, stats.para_id, stats.node_count, stats.max_depth))
+		.collect::<Vec<_>>();
+	assert_eq!(stats, expected);
+}
+
 async fn get_pvd(
 	virtual_overseer: &mut VirtualOverseer,
 	para_id: ParaId,
@@ -1442,6 +1475,12 @@ fn unconnected_candidates_become_connected(#[case] runtime_api_version: u32) {
 			vec![(candidate_a.hash(), leaf_a.hash)],
 		)
 		.await;
+		// A is in the best chain, C and D are unconnected.
+		get_fragment_tree_stats(
+			&mut virtual_overseer,
+			vec![(leaf_a.hash, 1.into(), 3, 1), (leaf_a.hash, 2.into(), 0, 0)],
+		)
+		.await;
 
 		// Introduce C and check membership. Full chain should be returned.
 		introduce_seconded_candidate(&mut virtual_overseer, candidate_b.clone(), pvd_b.clone())
@@ -1461,6 +1500,11 @@ fn unconnected_candidates_become_connected(#[case] runtime_api_version: u32) {
 			],
 		)
 		.await;
+		get_fragment_tree_stats(
+			&mut virtual_overseer,
+			vec![(leaf_a.hash, 1.into(), 4, 4), (leaf_a.hash, 2.into(), 0, 0)],
+		)
+		.await;
 
 		virtual_overseer
 	});
@@ -2068,6 +2112,135 @@ fn check_hypothetical_membership_query(#[case] runtime_api_version: u32) {
 	assert_eq!(view.active_leaves.len(), 2);
 }
 
+// Requests made of complete candidates only are evaluated separately, check they get the same
+// answer as when evaluated along with incomplete candidates.
+#[test]
+fn complete_only_hypothetical_membership_matches_general_path() {
+	let test_state = TestState::default();
+
+	let view = test_harness(|mut virtual_overseer| async move {
+		let leaf_b = TestLeaf {
+			number: 101,
+			hash: Hash::from_low_u64_be(131),
+			para_data: vec![
+				(1.into(), PerParaData::new(97, HeadData(vec![1, 2, 3]))),
+				(2.into(), PerParaData::new(100, HeadData(vec![2, 3, 4]))),
+			],
+		};
+		let leaf_a = TestLeaf {
+			number: 100,
+			hash: get_parent_hash(leaf_b.hash),
+			para_data: vec![
+				(1.into(), PerParaData::new(98, HeadData(vec![1, 2, 3]))),
+				(2.into(), PerParaData::new(100, HeadData(vec![2, 3, 4]))),
+			],
+		};
+		activate_leaf(&mut virtual_overseer, &leaf_a, &test_state).await;
+		activate_leaf(&mut virtual_overseer, &leaf_b, &test_state).await;
+
+		// A chain of candidates on para 1, followed by candidates which aren't members: a
+		// candidate with an unknown relay parent, one with too large head data and one of an
+		// unknown para.
+		let mut candidates = (0..16u8)
+			.map(|i| {
+				let parent_head = if i == 0 { HeadData(vec![1, 2, 3]) } else { HeadData(vec![i]) };
+				make_candidate(
+					leaf_a.hash,
+					leaf_a.number,
+					1.into(),
+					parent_head,
+					HeadData(vec![i + 1]),
+					test_state.validation_code_hash,
+				)
+			})
+			.collect::<Vec<_>>();
+		candidates.push(make_candidate(
+			Hash::from_low_u64_be(200),
+			leaf_a.number,
+			1.into(),
+			HeadData(vec![1]),
+			HeadData(vec![2]),
+			test_state.validation_code_hash,
+		));
+		candidates.push(make_candidate(
+			leaf_a.hash,
+			leaf_a.number,
+			1.into(),
+			HeadData(vec![2]),
+			HeadData(vec![0; 20481]),
+			test_state.validation_code_hash,
+		));
+		candidates.push(make_candidate(
+			leaf_a.hash,
+			leaf_a.number,
+			3.into(),
+			HeadData(vec![1]),
+			HeadData(vec![2]),
+			test_state.validation_code_hash,
+		));
+		let complete = candidates
+			.iter()
+			.map(|(candidate, pvd)| HypotheticalCandidate::Complete {
+				candidate_hash: candidate.hash(),
+				receipt: Arc::new(candidate.clone()),
+				persisted_validation_data: pvd.clone(),
+			})
+			.collect::<Vec<_>>();
+		let incomplete = HypotheticalCandidate::Incomplete {
+			candidate_hash: CandidateHash(Hash::from_low_u64_be(300)),
+			candidate_para: 1.into(),
+			parent_head_data_hash: HeadData(vec![1, 2, 3]).hash(),
+			candidate_relay_parent: leaf_a.hash,
+		};
+
+		let (candidate_a, pvd_a) = candidates[0].clone();
+		let (candidate_b, pvd_b) = candidates[1].clone();
+		for step in 0..3 {
+			match step {
+				0 => {},
+				1 => {
+					introduce_seconded_candidate(
+						&mut virtual_overseer,
+						candidate_a.clone(),
+						pvd_a.clone(),
+					)
+					.await;
+					back_candidate(&mut virtual_overseer, &candidate_a, candidate_a.hash()).await;
+				},
+				_ => {
+					introduce_seconded_candidate(
+						&mut virtual_overseer,
+						candidate_b.clone(),
+						pvd_b.clone(),
+					)
+					.await;
+				},
+			}
+
+			let complete_only =
+				get_hypothetical_memberships(&mut virtual_overseer, complete.clone()).await;
+
+			let mut mixed = complete.clone();
+			mixed.push(incomplete.clone());
+			let mut general = get_hypothetical_memberships(&mut virtual_overseer, mixed).await;
+			assert_eq!(general.pop().map(|(candidate, _)| candidate), Some(incomplete.clone()));
+
+			assert_eq!(complete_only, general);
+			// Some candidates are members, some aren't.
+			assert!(complete_only.iter().any(|(_, membership)| !membership.is_empty()));
+			assert!(complete_only
+				.iter()
+				.rev()
+				.take(3)
+				.all(|(_, membership)| membership.is_empty()));
+		}
+
+		virtual_overseer
+	});
+
+	assert_eq!(view.active_leaves.len(), 2);
+}
+
 #[rstest]
 #[case(RuntimeApiRequest::CONSTRAINTS_RUNTIME_REQUIREMENT)]
 #[case(RuntimeApiRequest::CLAIM_QUEUE_RUNTIME_REQUIREMENT)]
@@ -2200,7 +2373,31 @@ fn check_pvd_query(#[case] runtime_api_version: u32) {
 		// Add candidate E and check again. Should succeed this time.
 		introduce_seconded_candidate(&mut virtual_overseer, candidate_e, pvd_e.clone()).await;
 
-		get_pvd(&mut virtual_overseer, 1.into(), leaf_a.hash, HeadData(vec![5]), Some(pvd_e)).await;
+		get_pvd(
+			&mut virtual_overseer,
+			1.into(),
+			leaf_a.hash,
+			HeadData(vec![5]),
+			Some(pvd_e.clone()),
+		)
+		.await;
+
+		// Query a batch, including an unknown parent head-data.
+		let requests = [HeadData(vec![1, 2, 3]), HeadData(vec![7]), HeadData(vec![5])]
+			.into_iter()
+			.map(|parent_head_data| ProspectiveValidationDataRequest {
+				para_id: 1.into(),
+				candidate_relay_parent: leaf_a.hash,
+				parent_head_data: ParentHeadData::OnlyHash(parent_head_data.hash()),
+			})
+			.collect();
+		let (tx, rx) = oneshot::channel();
+		virtual_overseer
+			.send(overseer::FromOrchestra::Communication {
+				msg: ProspectiveParachainsMessage::GetProspectiveValidationDataBatch(requests, tx),
+			})
+			.await;
+		assert_eq!(rx.await.unwrap(), vec![Some(pvd_a), None, Some(pvd_e)]);
 
 		virtual_overseer
 	});
@@ -42,19 +42,29 @@ pub(crate) struct RequestResultCache {
 	authorities: LruMap<Hash, Vec<AuthorityDiscoveryId>>,
 	validators: LruMap<Hash, Vec<ValidatorId>>,
 	validator_groups: LruMap<Hash, (Vec<Vec<ValidatorIndex>>, GroupRotationInfo)>,
+	/// Keyed by the relay parent, so every block gets its own entry and the entries of the other
+	/// blocks are never served for it. The entries are evicted by the LRU policy only.
 	availability_cores: LruMap<Hash, Vec<CoreState>>,
 	persisted_validation_data:
 		LruMap<(Hash, ParaId, OccupiedCoreAssumption), Option<PersistedValidationData>>,
 	assumed_validation_data:
 		LruMap<(ParaId, Hash), Option<(PersistedValidationData, ValidationCodeHash)>>,
 	check_validation_outputs: LruMap<(Hash, ParaId, CandidateCommitments), bool>,
+	/// Keyed by the relay parent, as the session of its child is fixed. Holds up to
+	/// `DEFAULT_CACHE_CAP` entries evicted by the LRU policy, and the entries of the leaves which
+	/// are no longer active are evicted as soon as the subsystem learns about it.
 	session_index_for_child: LruMap<Hash, SessionIndex>,
 	validation_code: LruMap<(Hash, ParaId, OccupiedCoreAssumption), Option<ValidationCode>>,
+	validation_code_len: LruMap<(Hash, ParaId, OccupiedCoreAssumption), Option<u32>>,
 	validation_code_by_hash: LruMap<ValidationCodeHash, Option<ValidationCode>>,
 	candidate_pending_availability: LruMap<(Hash, ParaId), Option<CommittedCandidateReceipt>>,
 	candidates_pending_availability: LruMap<(Hash, ParaId), Vec<CommittedCandidateReceipt>>,
 	candidate_events: LruMap<Hash, Vec<CandidateEvent>>,
-	session_executor_params: LruMap<SessionIndex, Option<ExecutorParams>>,
+	/// The executor params are fixed for the whole session, so they are keyed by session index
+	/// only and never invalidated: a session change just moves the lookups to a new key. The
+	/// sessions which were not yet known to the runtime (`None`) are not cached, as their params
+	/// may appear once the session is set up.
+	session_executor_params: LruMap<SessionIndex, ExecutorParams>,
 	session_info: LruMap<SessionIndex, SessionInfo>,
 	dmq_contents: LruMap<(Hash, ParaId), Vec<InboundDownwardMessage<BlockNumber>>>,
 	inbound_hrmp_channels_contents:
@@ -91,6 +101,7 @@ impl Default for RequestResultCache {
 			check_validation_outputs: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			session_index_for_child: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			validation_code: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
+			validation_code_len: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			validation_code_by_hash: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			candidate_pending_availability: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			candidates_pending_availability: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
@@ -224,6 +235,12 @@ impl RequestResultCache {
 		self.session_index_for_child.insert(relay_parent, index);
 	}
 
+	pub(crate) fn evict_session_index_for_child(&mut self, relay_parents: &[Hash]) {
+		for relay_parent in relay_parents {
+			self.session_index_for_child.remove(relay_parent);
+		}
+	}
+
 	pub(crate) fn validation_code(
 		&mut self,
 		key: (Hash, ParaId, OccupiedCoreAssumption),
@@ -239,6 +256,21 @@ impl RequestResultCache {
 		self.validation_code.insert(key, value);
 	}
 
+	pub(crate) fn validation_code_len(
+		&mut self,
+		key: (Hash, ParaId, OccupiedCoreAssumption),
+	) -> Option<&Option<u32>> {
+		self.validation_code_len.get(&key).map(|v| &*v)
+	}
+
+	pub(crate) fn cache_validation_code_len(
+		&mut self,
+		key: (Hash, ParaId, OccupiedCoreAssumption),
+		value: Option<u32>,
+	) {
+		self.validation_code_len.insert(key, value);
+	}
+
 	// the actual key is `ValidationCodeHash` (`Hash` is ignored),
 	// but we keep the interface that way to keep the macro simple
 	pub(crate) fn validation_code_by_hash(
@@ -309,14 +341,14 @@ impl RequestResultCache {
 	pub(crate) fn session_executor_params(
 		&mut self,
 		session_index: SessionIndex,
-	) -> Option<&Option<ExecutorParams>> {
+	) -> Option<&ExecutorParams> {
 		self.session_executor_params.get(&session_index).map(|v| &*v)
 	}
 
 	pub(crate) fn cache_session_executor_params(
 		&mut self,
 		session_index: SessionIndex,
-		value: Option<ExecutorParams>,
+		value: ExecutorParams,
 	) {
 		self.session_executor_params.insert(session_index, value);
 	}
@@ -608,6 +640,7 @@ pub(crate) enum RequestResult {
 	CheckValidationOutputs(Hash, ParaId, CandidateCommitments, bool),
 	SessionIndexForChild(Hash, SessionIndex),
 	ValidationCode(Hash, ParaId, OccupiedCoreAssumption, Option<ValidationCode>),
+	ValidationCodeLen(Hash, ParaId, OccupiedCoreAssumption, Option<u32>),
 	ValidationCodeByHash(Hash, ValidationCodeHash, Option<ValidationCode>),
 	CandidatePendingAvailability(Hash, ParaId, Option<CommittedCandidateReceipt>),
 	CandidateEvents(Hash, Vec<CandidateEvent>),
@@ -619,6 +652,10 @@ pub(crate) enum RequestResult {
 		ParaId,
 		BTreeMap<ParaId, Vec<InboundHrmpMessage<BlockNumber>>>,
 	),
+	InboundHrmpChannelsContentsAll(
+		Hash,
+		BTreeMap<ParaId, BTreeMap<ParaId, Vec<InboundHrmpMessage<BlockNumber>>>>,
+	),
 	CurrentBabeEpoch(Hash, Epoch),
 	FetchOnChainVotes(Hash, Option<ScrapedOnChainVotes>),
 	PvfsRequirePrecheck(Hash, Vec<ValidationCodeHash>),
@@ -636,10 +673,18 @@ pub(crate) enum RequestResult {
 	ApprovalVotingParams(Hash, SessionIndex, ApprovalVotingParams),
 	DisabledValidators(Hash, Vec<ValidatorIndex>),
 	ParaBackingState(Hash, ParaId, Option<vstaging::async_backing::BackingState>),
+	ParaBackingStateWithCores(
+		Hash,
+		ParaId,
+		Option<vstaging::async_backing::BackingState>,
+		Vec<CoreState>,
+	),
 	AsyncBackingParams(Hash, async_backing::AsyncBackingParams),
 	NodeFeatures(SessionIndex, NodeFeatures),
 	ClaimQueue(Hash, BTreeMap<CoreIndex, VecDeque<ParaId>>),
 	CandidatesPendingAvailability(Hash, ParaId, Vec<CommittedCandidateReceipt>),
 	BackingConstraints(Hash, ParaId, Option<Constraints>),
 	SchedulingLookahead(SessionIndex, u32),
+	// The results of the individual requests a batch request is made of, cached one by one.
+	Batch(Vec<RequestResult>),
 }
@@ -24,15 +24,27 @@
 
 use polkadot_node_subsystem::{
 	errors::RuntimeApiError,
-	messages::{RuntimeApiMessage, RuntimeApiRequest as Request},
-	overseer, FromOrchestra, OverseerSignal, SpawnedSubsystem, SubsystemError, SubsystemResult,
+	messages::{
+		BackingStateWithCores, RuntimeApiMessage, RuntimeApiRequest as Request, ValidatorSetDiff,
+	},
+	overseer, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, SpawnedSubsystem, SubsystemError,
+	SubsystemResult,
 };
-use polkadot_node_subsystem_types::RuntimeApiSubsystemClient;
-use polkadot_primitives::Hash;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_primitives::{
+	slashing,
+	vstaging::{CandidateEvent, CoreState, ScrapedOnChainVotes},
+	AuthorityDiscoveryId, BlockNumber, CandidateHash, DisputeState, Hash, Id as ParaId,
+	InboundHrmpMessage, SessionIndex, ValidatorId,
+};
+use sp_api::ApiError;
 
 use cache::{RequestResult, RequestResultCache};
 use futures::{channel::oneshot, prelude::*, select, stream::FuturesUnordered};
-use std::sync::Arc;
+use std::{
+	collections::{BTreeMap, BTreeSet, HashSet},
+	sync::Arc,
+};
 
 mod cache;
 
@@ -60,6 +72,9 @@ pub struct RuntimeApiSubsystem<Client> {
 	active_requests: FuturesUnordered<oneshot::Receiver<Option<RequestResult>>>,
 	/// Requests results cache
 	requests_cache: RequestResultCache,
+	/// The `AsyncBackingParams` returned instead of querying the runtime.
+	#[cfg(feature = "testing")]
+	async_backing_params_override: Option<polkadot_primitives::async_backing::AsyncBackingParams>,
 }
 
 impl<Client> RuntimeApiSubsystem<Client> {
@@ -75,14 +90,30 @@ impl<Client> RuntimeApiSubsystem<Client> {
 			spawn_handle: Box::new(spawner),
 			active_requests: Default::default(),
 			requests_cache: RequestResultCache::default(),
+			#[cfg(feature = "testing")]
+			async_backing_params_override: None,
 		}
 	}
+
+	/// Answer all the `AsyncBackingParams` requests with the given `params`, without querying
+	/// the runtime.
+	///
+	/// Allows integration tests to enable async backing without a runtime supporting it. Only
+	/// available with the `testing` feature.
+	#[cfg(feature = "testing")]
+	pub fn with_async_backing_params(
+		mut self,
+		params: polkadot_primitives::async_backing::AsyncBackingParams,
+	) -> Self {
+		self.async_backing_params_override = Some(params);
+		self
+	}
 }
 
 #[overseer::subsystem(RuntimeApi, error = SubsystemError, prefix = self::overseer)]
 impl<Client, Context> RuntimeApiSubsystem<Client>
 where
-	Client: RuntimeApiSubsystemClient + Send + Sync + 'static,
+	Client: RuntimeApiSubsystemClient + ChainApiBackend + Send + Sync + 'static,
 {
 	fn start(self, ctx: Context) -> SpawnedSubsystem {
 		SpawnedSubsystem { future: run(ctx, self).boxed(), name: "runtime-api-subsystem" }
@@ -91,8 +122,13 @@ where
 
 impl<Client> RuntimeApiSubsystem<Client>
 where
-	Client: RuntimeApiSubsystemClient + Send + 'static + Sync,
+	Client: RuntimeApiSubsystemClient + ChainApiBackend + Send + 'static + Sync,
 {
+	/// Evicts the cached entries of the leaves which are no longer active.
+	fn on_active_leaves_update(&mut self, update: &ActiveLeavesUpdate) {
+		self.requests_cache.evict_session_index_for_child(&update.deactivated);
+	}
+
 	fn store_cache(&mut self, result: RequestResult) {
 		use RequestResult::*;
 
@@ -128,6 +164,9 @@ where
 			ValidationCode(relay_parent, para_id, assumption, code) => self
 				.requests_cache
 				.cache_validation_code((relay_parent, para_id, assumption), code),
+			ValidationCodeLen(relay_parent, para_id, assumption, len) => self
+				.requests_cache
+				.cache_validation_code_len((relay_parent, para_id, assumption), len),
 			ValidationCodeByHash(_relay_parent, validation_code_hash, code) =>
 				self.requests_cache.cache_validation_code_by_hash(validation_code_hash, code),
 			CandidatePendingAvailability(relay_parent, para_id, candidate) => self
@@ -138,8 +177,11 @@ where
 				.cache_candidates_pending_availability((relay_parent, para_id), candidates),
 			CandidateEvents(relay_parent, events) =>
 				self.requests_cache.cache_candidate_events(relay_parent, events),
-			SessionExecutorParams(_relay_parent, session_index, index) =>
-				self.requests_cache.cache_session_executor_params(session_index, index),
+			SessionExecutorParams(_relay_parent, session_index, executor_params) =>
+				if let Some(executor_params) = executor_params {
+					self.requests_cache
+						.cache_session_executor_params(session_index, executor_params);
+				},
 			SessionInfo(_relay_parent, session_index, info) =>
 				if let Some(info) = info {
 					self.requests_cache.cache_session_info(session_index, info);
@@ -149,6 +191,11 @@ where
 			InboundHrmpChannelsContents(relay_parent, para_id, contents) => self
 				.requests_cache
 				.cache_inbound_hrmp_channel_contents((relay_parent, para_id), contents),
+			InboundHrmpChannelsContentsAll(relay_parent, contents) =>
+				for (para_id, contents) in contents {
+					self.requests_cache
+						.cache_inbound_hrmp_channel_contents((relay_parent, para_id), contents);
+				},
 			CurrentBabeEpoch(relay_parent, epoch) =>
 				self.requests_cache.cache_current_babe_epoch(relay_parent, epoch),
 			FetchOnChainVotes(relay_parent, scraped) =>
@@ -176,6 +223,11 @@ where
 			ParaBackingState(relay_parent, para_id, constraints) => self
 				.requests_cache
 				.cache_para_backing_state((relay_parent, para_id), constraints),
+			ParaBackingStateWithCores(relay_parent, para_id, constraints, cores) => {
+				self.requests_cache
+					.cache_para_backing_state((relay_parent, para_id), constraints);
+				self.requests_cache.cache_availability_cores(relay_parent, cores);
+			},
 			AsyncBackingParams(relay_parent, params) =>
 				self.requests_cache.cache_async_backing_params(relay_parent, params),
 			NodeFeatures(session_index, params) =>
@@ -189,11 +241,28 @@ where
 			SchedulingLookahead(session_index, scheduling_lookahead) => self
 				.requests_cache
 				.cache_scheduling_lookahead(session_index, scheduling_lookahead),
+			Batch(results) =>
+				for result in results {
+					self.store_cache(result);
+				},
 		}
 	}
 
 	fn query_cache(&mut self, relay_parent: Hash, request: Request) -> Option<Request> {
 		macro_rules! query {
+			// Query every item of a batch. Only answered if all of them are cached, otherwise all
+			// the items are fetched from the runtime, so that they are read from the same state.
+			(for $item:pat_param in $items:expr => $cached:expr, $sender:expr) => {{
+				let sender = $sender;
+				let cached = $items.iter().map(|$item| $cached).collect::<Option<Vec<_>>>();
+				if let Some(values) = cached {
+					self.metrics.on_cached_request();
+					let _ = sender.send(Ok(values));
+					None
+				} else {
+					Some(sender)
+				}
+			}};
 			// Just query by relay parent
 			($cache_api_name:ident (), $sender:expr) => {{
 				let sender = $sender;
@@ -223,8 +292,31 @@ where
 				query!(version(), sender).map(|sender| Request::Version(sender)),
 			Request::Authorities(sender) =>
 				query!(authorities(), sender).map(|sender| Request::Authorities(sender)),
+			Request::AuthoritiesForSession(session_index, sender) => {
+				if let Some(info) = self.requests_cache.session_info(session_index) {
+					self.metrics.on_cached_request();
+					let _ = sender.send(Ok(dedup_authorities(&info.discovery_keys)));
+					None
+				} else {
+					Some(Request::AuthoritiesForSession(session_index, sender))
+				}
+			},
 			Request::Validators(sender) =>
 				query!(validators(), sender).map(|sender| Request::Validators(sender)),
+			Request::ValidatorsDiff(from, to, sender) => {
+				let from_info = self.requests_cache.session_info(from).cloned();
+				let to_info = self.requests_cache.session_info(to);
+				if let (Some(from_info), Some(to_info)) = (from_info, to_info) {
+					self.metrics.on_cached_request();
+					let _ = sender.send(Ok(ValidatorSetDiff::new(
+						&from_info.validators.to_vec(),
+						&to_info.validators.to_vec(),
+					)));
+					None
+				} else {
+					Some(Request::ValidatorsDiff(from, to, sender))
+				}
+			},
 			Request::ValidatorGroups(sender) =>
 				query!(validator_groups(), sender).map(|sender| Request::ValidatorGroups(sender)),
 			Request::AvailabilityCores(sender) => query!(availability_cores(), sender)
@@ -232,6 +324,14 @@ where
 			Request::PersistedValidationData(para, assumption, sender) =>
 				query!(persisted_validation_data(para, assumption), sender)
 					.map(|sender| Request::PersistedValidationData(para, assumption, sender)),
+			Request::PersistedValidationDataBatch(paras, sender) => query!(
+				for (para, assumption) in paras => self
+					.requests_cache
+					.persisted_validation_data((relay_parent, *para, *assumption))
+					.cloned(),
+				sender
+			)
+			.map(|sender| Request::PersistedValidationDataBatch(paras, sender)),
 			Request::AssumedValidationData(
 				para,
 				expected_persisted_validation_data_hash,
@@ -250,14 +350,47 @@ where
 			Request::CheckValidationOutputs(para, commitments, sender) =>
 				query!(check_validation_outputs(para, commitments), sender)
 					.map(|sender| Request::CheckValidationOutputs(para, commitments, sender)),
+			Request::CheckValidationOutputsBatch(outputs, sender) => query!(
+				for (para, commitments) in outputs => self
+					.requests_cache
+					.check_validation_outputs((relay_parent, *para, commitments.clone()))
+					.copied(),
+				sender
+			)
+			.map(|sender| Request::CheckValidationOutputsBatch(outputs, sender)),
 			Request::SessionIndexForChild(sender) => query!(session_index_for_child(), sender)
 				.map(|sender| Request::SessionIndexForChild(sender)),
 			Request::ValidationCode(para, assumption, sender) =>
 				query!(validation_code(para, assumption), sender)
 					.map(|sender| Request::ValidationCode(para, assumption, sender)),
+			Request::ValidationCodeLen(para, assumption, sender) => {
+				let key = (relay_parent, para, assumption);
+				// The length is known as well if the whole code is cached.
+				let cached = self.requests_cache.validation_code_len(key).copied().or_else(|| {
+					self.requests_cache
+						.validation_code(key)
+						.map(|code| code.as_ref().map(|code| code.0.len() as u32))
+				});
+
+				if let Some(len) = cached {
+					self.metrics.on_cached_request();
+					let _ = sender.send(Ok(len));
+					None
+				} else {
+					Some(Request::ValidationCodeLen(para, assumption, sender))
+				}
+			},
 			Request::ValidationCodeByHash(validation_code_hash, sender) =>
 				query!(validation_code_by_hash(validation_code_hash), sender)
 					.map(|sender| Request::ValidationCodeByHash(validation_code_hash, sender)),
+			Request::ValidationCodeByHashes(hashes, sender) => query!(
+				for hash in hashes => self
+					.requests_cache
+					.validation_code_by_hash((relay_parent, *hash))
+					.cloned(),
+				sender
+			)
+			.map(|sender| Request::ValidationCodeByHashes(hashes, sender)),
 			Request::CandidatePendingAvailability(para, sender) =>
 				query!(candidate_pending_availability(para), sender)
 					.map(|sender| Request::CandidatePendingAvailability(para, sender)),
@@ -266,12 +399,13 @@ where
 					.map(|sender| Request::CandidatesPendingAvailability(para, sender)),
 			Request::CandidateEvents(sender) =>
 				query!(candidate_events(), sender).map(|sender| Request::CandidateEvents(sender)),
+			request @ Request::CandidateEventsInRange { .. } => Some(request),
 			Request::SessionExecutorParams(session_index, sender) => {
 				if let Some(executor_params) =
 					self.requests_cache.session_executor_params(session_index)
 				{
 					self.metrics.on_cached_request();
-					let _ = sender.send(Ok(executor_params.clone()));
+					let _ = sender.send(Ok(Some(executor_params.clone())));
 					None
 				} else {
 					Some(Request::SessionExecutorParams(session_index, sender))
@@ -286,15 +420,35 @@ where
 					Some(Request::SessionInfo(index, sender))
 				}
 			},
+			Request::SessionInfos(indices, sender) => query!(
+				for index in indices => self.requests_cache.session_info(*index).cloned().map(Some),
+				sender
+			)
+			.map(|sender| Request::SessionInfos(indices, sender)),
 			Request::DmqContents(id, sender) =>
 				query!(dmq_contents(id), sender).map(|sender| Request::DmqContents(id, sender)),
+			Request::DmqContentsBounded(id, limit, sender) => match self
+				.requests_cache
+				.dmq_contents((relay_parent, id))
+			{
+				Some(messages) => {
+					self.metrics.on_cached_request();
+					let _ =
+						sender.send(Ok(messages.iter().take(limit as usize).cloned().collect()));
+					None
+				},
+				None => Some(Request::DmqContentsBounded(id, limit, sender)),
+			},
 			Request::InboundHrmpChannelsContents(id, sender) =>
 				query!(inbound_hrmp_channels_contents(id), sender)
 					.map(|sender| Request::InboundHrmpChannelsContents(id, sender)),
+			Request::InboundHrmpChannelsContentsAll(sender) =>
+				Some(Request::InboundHrmpChannelsContentsAll(sender)),
 			Request::CurrentBabeEpoch(sender) =>
 				query!(current_babe_epoch(), sender).map(|sender| Request::CurrentBabeEpoch(sender)),
 			Request::FetchOnChainVotes(sender) =>
 				query!(on_chain_votes(), sender).map(|sender| Request::FetchOnChainVotes(sender)),
+			request @ Request::FetchOnChainVotesInRange { .. } => Some(request),
 			Request::PvfsRequirePrecheck(sender) => query!(pvfs_require_precheck(), sender)
 				.map(|sender| Request::PvfsRequirePrecheck(sender)),
 			request @ Request::SubmitPvfCheckStatement(_, _, _) => {
@@ -306,11 +460,50 @@ where
 					.map(|sender| Request::ValidationCodeHash(para, assumption, sender)),
 			Request::Disputes(sender) =>
 				query!(disputes(), sender).map(|sender| Request::Disputes(sender)),
+			Request::DisputesInSessions(sessions, sender) => {
+				if let Some(disputes) = self.requests_cache.disputes(&relay_parent) {
+					self.metrics.on_cached_request();
+					let _ = sender.send(Ok(filter_disputes(disputes, &sessions)));
+					None
+				} else {
+					Some(Request::DisputesInSessions(sessions, sender))
+				}
+			},
 			Request::UnappliedSlashes(sender) =>
 				query!(unapplied_slashes(), sender).map(|sender| Request::UnappliedSlashes(sender)),
+			Request::UnappliedSlashesForSession(session_index, sender) => {
+				if let Some(slashes) = self.requests_cache.unapplied_slashes(&relay_parent) {
+					self.metrics.on_cached_request();
+					let _ = sender.send(Ok(filter_unapplied_slashes(slashes, session_index)));
+					None
+				} else {
+					Some(Request::UnappliedSlashesForSession(session_index, sender))
+				}
+			},
 			Request::KeyOwnershipProof(validator_id, sender) =>
 				query!(key_ownership_proof(validator_id), sender)
 					.map(|sender| Request::KeyOwnershipProof(validator_id, sender)),
+			Request::KeyOwnershipProofForSession(session_index, validator_id, sender) => {
+				let is_current_session = self
+					.requests_cache
+					.session_index_for_child(&relay_parent)
+					.map_or(false, |current_session| *current_session == session_index);
+				let cached = is_current_session
+					.then(|| {
+						self.requests_cache
+							.key_ownership_proof((relay_parent, validator_id.clone()))
+							.cloned()
+					})
+					.flatten();
+
+				if let Some(proof) = cached {
+					self.metrics.on_cached_request();
+					let _ = sender.send(Ok(proof));
+					None
+				} else {
+					Some(Request::KeyOwnershipProofForSession(session_index, validator_id, sender))
+				}
+			},
 			Request::SubmitReportDisputeLost(dispute_proof, key_ownership_proof, sender) =>
 				query!(submit_report_dispute_lost(dispute_proof, key_ownership_proof), sender).map(
 					|sender| {
@@ -324,8 +517,31 @@ where
 				.map(|sender| Request::DisabledValidators(sender)),
 			Request::ParaBackingState(para, sender) => query!(para_backing_state(para), sender)
 				.map(|sender| Request::ParaBackingState(para, sender)),
-			Request::AsyncBackingParams(sender) => query!(async_backing_params(), sender)
-				.map(|sender| Request::AsyncBackingParams(sender)),
+			Request::ParaBackingStateWithCores(para, sender) => {
+				let cached = self.requests_cache.para_backing_state((relay_parent, para)).cloned();
+				let cached = cached.and_then(|backing_state| {
+					let cores = self.requests_cache.availability_cores(&relay_parent)?;
+					Some(backing_state.map(|state| BackingStateWithCores::new(state, cores)))
+				});
+
+				if let Some(state) = cached {
+					self.metrics.on_cached_request();
+					let _ = sender.send(Ok(state));
+					None
+				} else {
+					Some(Request::ParaBackingStateWithCores(para, sender))
+				}
+			},
+			Request::AsyncBackingParams(sender) => {
+				#[cfg(feature = "testing")]
+				if let Some(params) = self.async_backing_params_override {
+					let _ = sender.send(Ok(params));
+					return None
+				}
+
+				query!(async_backing_params(), sender)
+					.map(|sender| Request::AsyncBackingParams(sender))
+			},
 			Request::MinimumBackingVotes(index, sender) => {
 				if let Some(value) = self.requests_cache.minimum_backing_votes(index) {
 					self.metrics.on_cached_request();
@@ -335,6 +551,8 @@ where
 					Some(Request::MinimumBackingVotes(index, sender))
 				}
 			},
+			Request::MinimumBackingVotesForParas(index, paras, sender) =>
+				Some(Request::MinimumBackingVotesForParas(index, paras, sender)),
 			Request::NodeFeatures(index, sender) => {
 				if let Some(value) = self.requests_cache.node_features(index) {
 					self.metrics.on_cached_request();
@@ -409,7 +627,7 @@ async fn run<Client, Context>(
 	mut subsystem: RuntimeApiSubsystem<Client>,
 ) -> SubsystemResult<()>
 where
-	Client: RuntimeApiSubsystemClient + Send + Sync + 'static,
+	Client: RuntimeApiSubsystemClient + ChainApiBackend + Send + Sync + 'static,
 {
 	loop {
 		// Let's add some back pressure when the subsystem is running at `MAX_PARALLEL_REQUESTS`.
@@ -426,7 +644,8 @@ where
 		select! {
 			req = ctx.recv().fuse() => match req? {
 				FromOrchestra::Signal(OverseerSignal::Conclude) => return Ok(()),
-				FromOrchestra::Signal(OverseerSignal::ActiveLeaves(_)) => {},
+				FromOrchestra::Signal(OverseerSignal::ActiveLeaves(update)) =>
+					subsystem.on_active_leaves_update(&update),
 				FromOrchestra::Signal(OverseerSignal::BlockFinalized(..)) => {},
 				FromOrchestra::Communication { msg } => match msg {
 					RuntimeApiMessage::Request(relay_parent, request) => {
@@ -439,6 +658,442 @@ where
 	}
 }
 
+/// Keep only the unapplied slashes of the given session.
+fn filter_unapplied_slashes(
+	slashes: &[(SessionIndex, CandidateHash, slashing::PendingSlashes)],
+	session_index: SessionIndex,
+) -> Vec<(CandidateHash, slashing::PendingSlashes)> {
+	slashes
+		.iter()
+		.filter(|(session, _, _)| *session == session_index)
+		.map(|(_, candidate_hash, pending)| (*candidate_hash, pending.clone()))
+		.collect()
+}
+
+/// Keep only the disputes raised in one of the given sessions.
+fn filter_disputes(
+	disputes: &[(SessionIndex, CandidateHash, DisputeState<BlockNumber>)],
+	sessions: &[SessionIndex],
+) -> Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumber>)> {
+	disputes
+		.iter()
+		.filter(|(session, _, _)| sessions.contains(session))
+		.cloned()
+		.collect()
+}
+
+/// Deduplicate the given authority discovery keys, keeping the order of first occurrence.
+fn dedup_authorities(keys: &[AuthorityDiscoveryId]) -> Vec<AuthorityDiscoveryId> {
+	let mut seen = HashSet::new();
+	keys.iter().filter(|key| seen.insert(*key)).cloned().collect()
+}
+
+/// Fetch the `ParachainHost` runtime API version at the given relay parent.
+///
+/// Falls back to `0` if the version cannot be determined, so that any versioned request is
+/// reported as not supported.
+async fn parachain_host_version<Client>(
+	client: &Client,
+	relay_parent: Hash,
+	api_name: &'static str,
+) -> u32
+where
+	Client: RuntimeApiSubsystemClient,
+{
+	client
+		.api_version_parachain_host(relay_parent)
+		.await
+		.unwrap_or_else(|e| {
+			gum::warn!(
+				target: LOG_TARGET,
+				api = ?api_name,
+				"cannot query the runtime API version: {}",
+				e,
+			);
+			Some(0)
+		})
+		.unwrap_or_else(|| {
+			gum::warn!(target: LOG_TARGET, "no runtime version is reported");
+			0
+		})
+}
+
+/// Assemble the contents of all inbound HRMP channels on runtimes which don't support
+/// `inbound_hrmp_channels_contents_all`.
+///
+/// Only the paras assigned to an availability core at `relay_parent` are queried.
+async fn inbound_hrmp_channels_contents_per_para<Client>(
+	client: &Client,
+	relay_parent: Hash,
+) -> Result<BTreeMap<ParaId, BTreeMap<ParaId, Vec<InboundHrmpMessage<BlockNumber>>>>, RuntimeApiError>
+where
+	Client: RuntimeApiSubsystemClient,
+{
+	let cores =
+		client
+			.availability_cores(relay_parent)
+			.await
+			.map_err(|e| RuntimeApiError::Execution {
+				runtime_api_name: "availability_cores",
+				source: std::sync::Arc::new(e),
+			})?;
+
+	let paras = cores
+		.iter()
+		.filter_map(|core| match core {
+			CoreState::Occupied(occupied) => Some(occupied.para_id()),
+			CoreState::Scheduled(scheduled) => Some(scheduled.para_id),
+			CoreState::Free => None,
+		})
+		.collect::<BTreeSet<_>>();
+
+	let mut all_contents = BTreeMap::new();
+	for para_id in paras {
+		let contents =
+			client
+				.inbound_hrmp_channels_contents(relay_parent, para_id)
+				.await
+				.map_err(|e| RuntimeApiError::Execution {
+					runtime_api_name: "inbound_hrmp_channels_contents",
+					source: std::sync::Arc::new(e),
+				})?;
+
+		if !contents.is_empty() {
+			all_contents.insert(para_id, contents);
+		}
+	}
+
+	Ok(all_contents)
+}
+
+/// Fetch the deduplicated authority discovery set of the given session.
+///
+/// The set is read from the session info stored at `relay_parent`. If the session info is not
+/// available, the live authorities, deduplicated the same way, are used for the session of the
+/// child of `relay_parent`. An empty set is returned for sessions which are too old, while the
+/// request fails for sessions which are not started yet.
+///
+/// Along with the set, the result to be cached is returned, if any.
+async fn authorities_for_session<Client>(
+	client: &Client,
+	relay_parent: Hash,
+	session_index: SessionIndex,
+) -> Result<(Vec<AuthorityDiscoveryId>, Option<RequestResult>), RuntimeApiError>
+where
+	Client: RuntimeApiSubsystemClient,
+{
+	let info = if parachain_host_version(client, relay_parent, "session_info").await >=
+		Request::AUTHORITIES_FOR_SESSION_RUNTIME_REQUIREMENT
+	{
+		client.session_info(relay_parent, session_index).await.map_err(|e| {
+			RuntimeApiError::Execution {
+				runtime_api_name: "session_info",
+				source: std::sync::Arc::new(e),
+			}
+		})?
+	} else {
+		None
+	};
+
+	if let Some(info) = info {
+		let authorities = dedup_authorities(&info.discovery_keys);
+		return Ok((
+			authorities,
+			Some(RequestResult::SessionInfo(relay_parent, session_index, Some(info))),
+		))
+	}
+
+	let current_session = client.session_index_for_child(relay_parent).await.map_err(|e| {
+		RuntimeApiError::Execution {
+			runtime_api_name: "session_index_for_child",
+			source: std::sync::Arc::new(e),
+		}
+	})?;
+
+	if session_index < current_session {
+		gum::debug!(
+			target: LOG_TARGET,
+			?relay_parent,
+			session_index,
+			current_session,
+			"session info is not available anymore, no authorities known for session",
+		);
+		return Ok((Vec::new(), None))
+	}
+
+	if session_index > current_session {
+		return Err(RuntimeApiError::Execution {
+			runtime_api_name: "authorities",
+			source: std::sync::Arc::new(ApiError::Application(
+				format!("the authorities of session {} are not known yet", session_index).into(),
+			)),
+		})
+	}
+
+	let authorities =
+		client.authorities(relay_parent).await.map_err(|e| RuntimeApiError::Execution {
+			runtime_api_name: "authorities",
+			source: std::sync::Arc::new(e),
+		})?;
+
+	Ok((
+		dedup_authorities(&authorities),
+		Some(RequestResult::Authorities(relay_parent, authorities)),
+	))
+}
+
+/// Fetch the validator set of the given session.
+///
+/// The set is read from the session info stored at `relay_parent`. If the session info is not
+/// available, the current validator set is used for the session of the child of `relay_parent`,
+/// while the request fails for other sessions.
+async fn validators_for_session<Client>(
+	client: &Client,
+	relay_parent: Hash,
+	session_index: SessionIndex,
+) -> Result<Vec<ValidatorId>, RuntimeApiError>
+where
+	Client: RuntimeApiSubsystemClient,
+{
+	let info = if parachain_host_version(client, relay_parent, "session_info").await >= 2 {
+		client.session_info(relay_parent, session_index).await.map_err(|e| {
+			RuntimeApiError::Execution {
+				runtime_api_name: "session_info",
+				source: std::sync::Arc::new(e),
+			}
+		})?
+	} else {
+		None
+	};
+
+	if let Some(info) = info {
+		return Ok(info.validators.to_vec())
+	}
+
+	let current_session = client.session_index_for_child(relay_parent).await.map_err(|e| {
+		RuntimeApiError::Execution {
+			runtime_api_name: "session_index_for_child",
+			source: std::sync::Arc::new(e),
+		}
+	})?;
+
+	if session_index != current_session {
+		return Err(RuntimeApiError::Execution {
+			runtime_api_name: "validators_diff",
+			source: std::sync::Arc::new(ApiError::Application(
+				format!("the validators of session {} are not available", session_index).into(),
+			)),
+		})
+	}
+
+	client.validators(relay_parent).await.map_err(|e| RuntimeApiError::Execution {
+		runtime_api_name: "validators",
+		source: std::sync::Arc::new(e),
+	})
+}
+
+/// Compute the validators added and removed going from session `from` to session `to`.
+async fn validators_diff<Client>(
+	client: &Client,
+	relay_parent: Hash,
+	from: SessionIndex,
+	to: SessionIndex,
+) -> Result<ValidatorSetDiff, RuntimeApiError>
+where
+	Client: RuntimeApiSubsystemClient,
+{
+	let from_validators = validators_for_session(client, relay_parent, from).await?;
+	let to_validators = validators_for_session(client, relay_parent, to).await?;
+
+	Ok(ValidatorSetDiff::new(&from_validators, &to_validators))
+}
+
+/// Fetch the key ownership proof of the validator in the given session.
+///
+/// If the session is the one of the relay parent's child, the proof is fetched with
+/// `key_ownership_proof`, and the result is returned for caching.
+async fn key_ownership_proof_for_session<Client>(
+	client: &Client,
+	relay_parent: Hash,
+	session_index: SessionIndex,
+	validator_id: ValidatorId,
+) -> Result<(Option<slashing::OpaqueKeyOwnershipProof>, Option<RequestResult>), RuntimeApiError>
+where
+	Client: RuntimeApiSubsystemClient,
+{
+	let runtime_version =
+		parachain_host_version(client, relay_parent, "key_ownership_proof_for_session").await;
+
+	let current_session = client.session_index_for_child(relay_parent).await.map_err(|e| {
+		RuntimeApiError::Execution {
+			runtime_api_name: "session_index_for_child",
+			source: std::sync::Arc::new(e),
+		}
+	})?;
+
+	if session_index == current_session {
+		if runtime_version < Request::KEY_OWNERSHIP_PROOF_RUNTIME_REQUIREMENT {
+			return Err(RuntimeApiError::NotSupported { runtime_api_name: "key_ownership_proof" })
+		}
+
+		let proof =
+			client
+				.key_ownership_proof(relay_parent, validator_id.clone())
+				.await
+				.map_err(|e| RuntimeApiError::Execution {
+					runtime_api_name: "key_ownership_proof",
+					source: std::sync::Arc::new(e),
+				})?;
+
+		return Ok((
+			proof.clone(),
+			Some(RequestResult::KeyOwnershipProof(relay_parent, validator_id, proof)),
+		))
+	}
+
+	if runtime_version < Request::KEY_OWNERSHIP_PROOF_FOR_SESSION_RUNTIME_REQUIREMENT {
+		return Err(RuntimeApiError::NotSupported {
+			runtime_api_name: "key_ownership_proof_for_session",
+		})
+	}
+
+	let proof = client
+		.key_ownership_proof_for_session(relay_parent, session_index, validator_id)
+		.await
+		.map_err(|e| RuntimeApiError::Execution {
+			runtime_api_name: "key_ownership_proof_for_session",
+			source: std::sync::Arc::new(e),
+		})?;
+
+	Ok((proof, None))
+}
+
+/// Collect the hashes of the blocks from `from` to `to`, both inclusive, in that order.
+///
+/// The blocks are found by walking the parent hashes back from `to`, which is only done for
+/// ranges of up to `max_range` blocks.
+async fn hashes_in_range<Client>(
+	client: &Client,
+	from: Hash,
+	to: Hash,
+	max_range: BlockNumber,
+	api_name: &'static str,
+) -> Result<Vec<Hash>, RuntimeApiError>
+where
+	Client: ChainApiBackend,
+{
+	let error = |e: ApiError| RuntimeApiError::Execution {
+		runtime_api_name: api_name,
+		source: std::sync::Arc::new(e),
+	};
+	let unknown_block = |hash: Hash| error(ApiError::UnknownBlock(format!("{:?}", hash)));
+	let invalid_range = |reason: &str| {
+		error(ApiError::Application(format!("{:?}..{:?}: {}", from, to, reason).into()))
+	};
+
+	let from_number = client
+		.number(from)
+		.await
+		.map_err(|e| error(e.into()))?
+		.ok_or_else(|| unknown_block(from))?;
+	let mut header = client
+		.header(to)
+		.await
+		.map_err(|e| error(e.into()))?
+		.ok_or_else(|| unknown_block(to))?;
+
+	if header.number < from_number {
+		return Err(invalid_range("`from` is higher than `to`"))
+	}
+	if header.number - from_number >= max_range {
+		return Err(invalid_range(&format!("the range exceeds {} blocks", max_range)))
+	}
+
+	let mut hashes = vec![to];
+	while header.number > from_number {
+		let parent_hash = header.parent_hash;
+		header = client
+			.header(parent_hash)
+			.await
+			.map_err(|e| error(e.into()))?
+			.ok_or_else(|| unknown_block(parent_hash))?;
+		hashes.push(parent_hash);
+	}
+
+	if hashes.last() != Some(&from) {
+		return Err(invalid_range("`from` is not an ancestor of `to`"))
+	}
+
+	hashes.reverse();
+	Ok(hashes)
+}
+
+/// Collect the candidate events of every block from `from` to `to`, both inclusive.
+///
+/// Only ranges of up to `Request::MAX_CANDIDATE_EVENTS_RANGE` blocks are supported.
+async fn candidate_events_in_range<Client>(
+	client: &Client,
+	from: Hash,
+	to: Hash,
+) -> Result<Vec<(Hash, Vec<CandidateEvent>)>, RuntimeApiError>
+where
+	Client: RuntimeApiSubsystemClient + ChainApiBackend,
+{
+	let hashes = hashes_in_range(
+		client,
+		from,
+		to,
+		Request::MAX_CANDIDATE_EVENTS_RANGE,
+		"candidate_events_in_range",
+	)
+	.await?;
+
+	let mut events = Vec::with_capacity(hashes.len());
+	for hash in hashes {
+		let block_events =
+			client.candidate_events(hash).await.map_err(|e| RuntimeApiError::Execution {
+				runtime_api_name: "candidate_events",
+				source: std::sync::Arc::new(e),
+			})?;
+		events.push((hash, block_events));
+	}
+
+	Ok(events)
+}
+
+/// Collect the scraped on-chain votes of every block from `from` to `to`, both inclusive.
+///
+/// Only ranges of up to `Request::MAX_ON_CHAIN_VOTES_RANGE` blocks are supported.
+async fn on_chain_votes_in_range<Client>(
+	client: &Client,
+	from: Hash,
+	to: Hash,
+) -> Result<Vec<(Hash, Option<ScrapedOnChainVotes>)>, RuntimeApiError>
+where
+	Client: RuntimeApiSubsystemClient + ChainApiBackend,
+{
+	let hashes = hashes_in_range(
+		client,
+		from,
+		to,
+		Request::MAX_ON_CHAIN_VOTES_RANGE,
+		"on_chain_votes_in_range",
+	)
+	.await?;
+
+	let mut votes = Vec::with_capacity(hashes.len());
+	for hash in hashes {
+		let block_votes =
+			client.on_chain_votes(hash).await.map_err(|e| RuntimeApiError::Execution {
+				runtime_api_name: "on_chain_votes",
+				source: std::sync::Arc::new(e),
+			})?;
+		votes.push((hash, block_votes));
+	}
+
+	Ok(votes)
+}
+
 async fn make_runtime_api_request<Client>(
 	client: Arc<Client>,
 	metrics: Metrics,
@@ -446,34 +1101,60 @@ async fn make_runtime_api_request<Client>(
 	request: Request,
 ) -> Option<RequestResult>
 where
-	Client: RuntimeApiSubsystemClient + 'static,
+	Client: RuntimeApiSubsystemClient + ChainApiBackend + 'static,
 {
 	let _timer = metrics.time_make_runtime_api_request();
 
 	macro_rules! query {
+		// Batch query, calling the API for every item. The first failure fails the whole batch.
+		// The result of every item is cached as the one of the single request `$req_variant`.
+		($req_variant:ident, $api_name:ident ($($param:ident),*), for $item:pat_param in $items:expr, ver = $version:expr, $sender:expr) => {{
+			let sender = $sender;
+			let version: u32 = $version; // enforce type for the version expression
+			let runtime_version =
+				parachain_host_version(&*client, relay_parent, stringify!($api_name)).await;
+
+			let res = if runtime_version >= version {
+				async {
+					let mut values = Vec::new();
+					let mut results = Vec::new();
+					for $item in $items {
+						let value = client.$api_name(relay_parent $(, $param.clone() )*).await
+							.map_err(|e| RuntimeApiError::Execution {
+								runtime_api_name: stringify!($api_name),
+								source: std::sync::Arc::new(e),
+							})?;
+						values.push(value.clone());
+						results.push(RequestResult::$req_variant(relay_parent $(, $param )*, value));
+					}
+					Ok::<_, RuntimeApiError>((values, results))
+				}
+				.await
+			} else {
+				Err(RuntimeApiError::NotSupported {
+					runtime_api_name: stringify!($api_name),
+				})
+			};
+			metrics.on_request(res.is_ok());
+			match res {
+				Ok((values, results)) => {
+					let _ = sender.send(Ok(values));
+					Some(RequestResult::Batch(results))
+				},
+				Err(e) => {
+					let _ = sender.send(Err(e));
+					None
+				},
+			}
+		}};
 		($req_variant:ident, $api_name:ident ($($param:expr),*), ver = $version:expr, $sender:expr) => {{
 			query!($req_variant, $api_name($($param),*), ver = $version, $sender, result = ( relay_parent $(, $param )* ) )
 		}};
 		($req_variant:ident, $api_name:ident ($($param:expr),*), ver = $version:expr, $sender:expr, result = ( $($results:expr),* ) ) => {{
 			let sender = $sender;
 			let version: u32 = $version; // enforce type for the version expression
-			let runtime_version = client.api_version_parachain_host(relay_parent).await
-				.unwrap_or_else(|e| {
-					gum::warn!(
-						target: LOG_TARGET,
-						api = ?stringify!($api_name),
-						"cannot query the runtime API version: {}",
-						e,
-					);
-					Some(0)
-				})
-				.unwrap_or_else(|| {
-					gum::warn!(
-						target: LOG_TARGET,
-						"no runtime version is reported"
-					);
-					0
-				});
+			let runtime_version =
+				parachain_host_version(&*client, relay_parent, stringify!($api_name)).await;
 
 			let res = if runtime_version >= version {
 				client.$api_name(relay_parent $(, $param.clone() )*).await
@@ -509,7 +1190,28 @@ where
 		},
 
 		Request::Authorities(sender) => query!(Authorities, authorities(), ver = 1, sender),
+		Request::AuthoritiesForSession(session_index, sender) => {
+			let res = authorities_for_session(&*client, relay_parent, session_index).await;
+			metrics.on_request(res.is_ok());
+			match res {
+				Ok((authorities, result)) => {
+					let _ = sender.send(Ok(authorities));
+					result
+				},
+				Err(e) => {
+					let _ = sender.send(Err(e));
+					None
+				},
+			}
+		},
 		Request::Validators(sender) => query!(Validators, validators(), ver = 1, sender),
+		Request::ValidatorsDiff(from, to, sender) => {
+			let res = validators_diff(&*client, relay_parent, from, to).await;
+			metrics.on_request(res.is_ok());
+			let _ = sender.send(res);
+
+			None
+		},
 		Request::ValidatorGroups(sender) => {
 			query!(ValidatorGroups, validator_groups(), ver = 1, sender)
 		},
@@ -522,6 +1224,13 @@ where
 			ver = 1,
 			sender
 		),
+		Request::PersistedValidationDataBatch(paras, sender) => query!(
+			PersistedValidationData,
+			persisted_validation_data(para, assumption),
+			for (para, assumption) in paras,
+			ver = 1,
+			sender
+		),
 		Request::AssumedValidationData(para, expected_persisted_validation_data_hash, sender) => {
 			query!(
 				AssumedValidationData,
@@ -536,18 +1245,48 @@ where
 			ver = 1,
 			sender
 		),
+		Request::CheckValidationOutputsBatch(outputs, sender) => query!(
+			CheckValidationOutputs,
+			check_validation_outputs(para, commitments),
+			for (para, commitments) in outputs,
+			ver = 1,
+			sender
+		),
 		Request::SessionIndexForChild(sender) => {
 			query!(SessionIndexForChild, session_index_for_child(), ver = 1, sender)
 		},
 		Request::ValidationCode(para, assumption, sender) => {
 			query!(ValidationCode, validation_code(para, assumption), ver = 1, sender)
 		},
+		Request::ValidationCodeLen(para, assumption, sender) => {
+			// The runtime only provides the whole code, the length is taken here.
+			let res = client
+				.validation_code(relay_parent, para, assumption)
+				.await
+				.map(|code| code.map(|code| code.0.len() as u32))
+				.map_err(|e| RuntimeApiError::Execution {
+					runtime_api_name: "validation_code",
+					source: std::sync::Arc::new(e),
+				});
+			metrics.on_request(res.is_ok());
+			let _ = sender.send(res.clone());
+
+			res.ok()
+				.map(|len| RequestResult::ValidationCodeLen(relay_parent, para, assumption, len))
+		},
 		Request::ValidationCodeByHash(validation_code_hash, sender) => query!(
 			ValidationCodeByHash,
 			validation_code_by_hash(validation_code_hash),
 			ver = 1,
 			sender
 		),
+		Request::ValidationCodeByHashes(hashes, sender) => query!(
+			ValidationCodeByHash,
+			validation_code_by_hash(hash),
+			for hash in hashes,
+			ver = 1,
+			sender
+		),
 		Request::CandidatePendingAvailability(para, sender) => query!(
 			CandidatePendingAvailability,
 			candidate_pending_availability(para),
@@ -563,9 +1302,23 @@ where
 		Request::CandidateEvents(sender) => {
 			query!(CandidateEvents, candidate_events(), ver = 1, sender)
 		},
+		Request::CandidateEventsInRange { from, to, response } => {
+			let res = candidate_events_in_range(&*client, from, to).await;
+			metrics.on_request(res.is_ok());
+			let _ = response.send(res);
+
+			None
+		},
 		Request::SessionInfo(index, sender) => {
 			query!(SessionInfo, session_info(index), ver = 2, sender)
 		},
+		Request::SessionInfos(indices, sender) => query!(
+			SessionInfo,
+			session_info(index),
+			for index in indices,
+			ver = 2,
+			sender
+		),
 		Request::SessionExecutorParams(session_index, sender) => query!(
 			SessionExecutorParams,
 			session_executor_params(session_index),
@@ -573,15 +1326,66 @@ where
 			sender
 		),
 		Request::DmqContents(id, sender) => query!(DmqContents, dmq_contents(id), ver = 1, sender),
+		Request::DmqContentsBounded(id, limit, sender) => {
+			let res = client.dmq_contents(relay_parent, id).await.map_err(|e| {
+				RuntimeApiError::Execution {
+					runtime_api_name: "dmq_contents",
+					source: std::sync::Arc::new(e),
+				}
+			});
+			metrics.on_request(res.is_ok());
+			let _ = sender.send(
+				res.as_ref()
+					.map(|messages| messages.iter().take(limit as usize).cloned().collect())
+					.map_err(Clone::clone),
+			);
+
+			// The whole queue is cached, so it serves the unbounded requests as well.
+			res.ok().map(|messages| RequestResult::DmqContents(relay_parent, id, messages))
+		},
 		Request::InboundHrmpChannelsContents(id, sender) => {
 			query!(InboundHrmpChannelsContents, inbound_hrmp_channels_contents(id), ver = 1, sender)
 		},
+		Request::InboundHrmpChannelsContentsAll(sender) => {
+			let runtime_version = parachain_host_version(
+				&*client,
+				relay_parent,
+				"inbound_hrmp_channels_contents_all",
+			)
+			.await;
+
+			let res = if runtime_version >=
+				Request::INBOUND_HRMP_CHANNELS_CONTENTS_ALL_RUNTIME_REQUIREMENT
+			{
+				client.inbound_hrmp_channels_contents_all(relay_parent).await.map_err(|e| {
+					RuntimeApiError::Execution {
+						runtime_api_name: "inbound_hrmp_channels_contents_all",
+						source: std::sync::Arc::new(e),
+					}
+				})
+			} else {
+				inbound_hrmp_channels_contents_per_para(&*client, relay_parent).await
+			};
+			metrics.on_request(res.is_ok());
+			let _ = sender.send(res.clone());
+
+			res.ok().map(|contents| {
+				RequestResult::InboundHrmpChannelsContentsAll(relay_parent, contents)
+			})
+		},
 		Request::CurrentBabeEpoch(sender) => {
 			query!(CurrentBabeEpoch, current_epoch(), ver = 1, sender)
 		},
 		Request::FetchOnChainVotes(sender) => {
 			query!(FetchOnChainVotes, on_chain_votes(), ver = 1, sender)
 		},
+		Request::FetchOnChainVotesInRange { from, to, response } => {
+			let res = on_chain_votes_in_range(&*client, from, to).await;
+			metrics.on_request(res.is_ok());
+			let _ = response.send(res);
+
+			None
+		},
 		Request::SubmitPvfCheckStatement(stmt, signature, sender) => {
 			query!(
 				SubmitPvfCheckStatement,
@@ -600,18 +1404,97 @@ where
 		Request::Disputes(sender) => {
 			query!(Disputes, disputes(), ver = Request::DISPUTES_RUNTIME_REQUIREMENT, sender)
 		},
+		Request::DisputesInSessions(sessions, sender) => {
+			let runtime_version = parachain_host_version(&*client, relay_parent, "disputes").await;
+
+			let res = if runtime_version >= Request::DISPUTES_RUNTIME_REQUIREMENT {
+				client.disputes(relay_parent).await.map_err(|e| RuntimeApiError::Execution {
+					runtime_api_name: "disputes",
+					source: std::sync::Arc::new(e),
+				})
+			} else {
+				Err(RuntimeApiError::NotSupported { runtime_api_name: "disputes" })
+			};
+			metrics.on_request(res.is_ok());
+			let _ = sender.send(
+				res.as_ref()
+					.map(|disputes| filter_disputes(disputes, &sessions))
+					.map_err(Clone::clone),
+			);
+
+			res.ok().map(|disputes| RequestResult::Disputes(relay_parent, disputes))
+		},
 		Request::UnappliedSlashes(sender) => query!(
 			UnappliedSlashes,
 			unapplied_slashes(),
 			ver = Request::UNAPPLIED_SLASHES_RUNTIME_REQUIREMENT,
 			sender
 		),
+		Request::UnappliedSlashesForSession(session_index, sender) => {
+			let runtime_version =
+				parachain_host_version(&*client, relay_parent, "unapplied_slashes_for_session")
+					.await;
+
+			if runtime_version >= Request::UNAPPLIED_SLASHES_FOR_SESSION_RUNTIME_REQUIREMENT {
+				let res = client
+					.unapplied_slashes_for_session(relay_parent, session_index)
+					.await
+					.map_err(|e| RuntimeApiError::Execution {
+						runtime_api_name: "unapplied_slashes_for_session",
+						source: std::sync::Arc::new(e),
+					});
+				metrics.on_request(res.is_ok());
+				let _ = sender.send(res);
+
+				None
+			} else {
+				// Older runtimes can only return the slashes of all sessions, filter them here.
+				let res = if runtime_version >= Request::UNAPPLIED_SLASHES_RUNTIME_REQUIREMENT {
+					client.unapplied_slashes(relay_parent).await.map_err(|e| {
+						RuntimeApiError::Execution {
+							runtime_api_name: "unapplied_slashes",
+							source: std::sync::Arc::new(e),
+						}
+					})
+				} else {
+					Err(RuntimeApiError::NotSupported { runtime_api_name: "unapplied_slashes" })
+				};
+				metrics.on_request(res.is_ok());
+				let _ = sender.send(
+					res.as_ref()
+						.map(|slashes| filter_unapplied_slashes(slashes, session_index))
+						.map_err(Clone::clone),
+				);
+
+				res.ok().map(|slashes| RequestResult::UnappliedSlashes(relay_parent, slashes))
+			}
+		},
 		Request::KeyOwnershipProof(validator_id, sender) => query!(
 			KeyOwnershipProof,
 			key_ownership_proof(validator_id),
 			ver = Request::KEY_OWNERSHIP_PROOF_RUNTIME_REQUIREMENT,
 			sender
 		),
+		Request::KeyOwnershipProofForSession(session_index, validator_id, sender) => {
+			let res = key_ownership_proof_for_session(
+				&*client,
+				relay_parent,
+				session_index,
+				validator_id,
+			)
+			.await;
+			metrics.on_request(res.is_ok());
+			match res {
+				Ok((proof, result)) => {
+					let _ = sender.send(Ok(proof));
+					result
+				},
+				Err(e) => {
+					let _ = sender.send(Err(e));
+					None
+				},
+			}
+		},
 		Request::ApprovalVotingParams(session_index, sender) => {
 			query!(
 				ApprovalVotingParams,
@@ -627,6 +1510,41 @@ where
 			sender,
 			result = ()
 		),
+		Request::MinimumBackingVotesForParas(index, paras, sender) => {
+			let runtime_version =
+				parachain_host_version(&*client, relay_parent, "minimum_backing_votes_for_paras")
+					.await;
+
+			if runtime_version >= Request::MINIMUM_BACKING_VOTES_FOR_PARAS_RUNTIME_REQUIREMENT {
+				let res = client
+					.minimum_backing_votes_for_paras(relay_parent, paras)
+					.await
+					.map_err(|e| RuntimeApiError::Execution {
+						runtime_api_name: "minimum_backing_votes_for_paras",
+						source: std::sync::Arc::new(e),
+					});
+				metrics.on_request(res.is_ok());
+				let _ = sender.send(res);
+
+				None
+			} else {
+				// Older runtimes only know the session-wide threshold, apply it to every para.
+				let res = if runtime_version >= Request::MINIMUM_BACKING_VOTES_RUNTIME_REQUIREMENT {
+					client.minimum_backing_votes(relay_parent, index).await.map_err(|e| {
+						RuntimeApiError::Execution {
+							runtime_api_name: "minimum_backing_votes",
+							source: std::sync::Arc::new(e),
+						}
+					})
+				} else {
+					Err(RuntimeApiError::NotSupported { runtime_api_name: "minimum_backing_votes" })
+				};
+				metrics.on_request(res.is_ok());
+				let _ = sender.send(res.clone().map(|votes| vec![votes; paras.len()]));
+
+				res.ok().map(|votes| RequestResult::MinimumBackingVotes(index, votes))
+			}
+		},
 		Request::MinimumBackingVotes(index, sender) => query!(
 			MinimumBackingVotes,
 			minimum_backing_votes(index),
@@ -648,6 +1566,36 @@ where
 				sender
 			)
 		},
+		Request::ParaBackingStateWithCores(para, sender) => {
+			let res = if parachain_host_version(&*client, relay_parent, "para_backing_state").await >=
+				Request::ASYNC_BACKING_STATE_RUNTIME_REQUIREMENT
+			{
+				match client.para_backing_state(relay_parent, para).await {
+					Ok(state) => client
+						.availability_cores(relay_parent)
+						.await
+						.map(|cores| (state, cores))
+						.map_err(|e| RuntimeApiError::Execution {
+							runtime_api_name: "availability_cores",
+							source: std::sync::Arc::new(e),
+						}),
+					Err(e) => Err(RuntimeApiError::Execution {
+						runtime_api_name: "para_backing_state",
+						source: std::sync::Arc::new(e),
+					}),
+				}
+			} else {
+				Err(RuntimeApiError::NotSupported { runtime_api_name: "para_backing_state" })
+			};
+			metrics.on_request(res.is_ok());
+			let _ = sender.send(res.clone().map(|(state, cores)| {
+				state.map(|state| BackingStateWithCores::new(state, &cores))
+			}));
+
+			res.ok().map(|(state, cores)| {
+				RequestResult::ParaBackingStateWithCores(relay_parent, para, state, cores)
+			})
+		},
 		Request::AsyncBackingParams(sender) => {
 			query!(
 				AsyncBackingParams,
@@ -25,11 +25,11 @@ use polkadot_primitives::{
 		async_backing::Constraints, CandidateEvent,
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState, ScrapedOnChainVotes,
 	},
-	ApprovalVotingParams, AuthorityDiscoveryId, BlockNumber, CandidateCommitments, CandidateHash,
-	CoreIndex, DisputeState, ExecutorParams, GroupRotationInfo, Id as ParaId,
-	InboundDownwardMessage, InboundHrmpMessage, NodeFeatures, OccupiedCoreAssumption,
-	PersistedValidationData, PvfCheckStatement, SessionIndex, SessionInfo, Slot, ValidationCode,
-	ValidationCodeHash, ValidatorId, ValidatorIndex, ValidatorSignature,
+	ApprovalVotingParams, AuthorityDiscoveryId, Block, BlockNumber, CandidateCommitments,
+	CandidateHash, CoreIndex, DisputeState, ExecutorParams, GroupRotationInfo, Header,
+	Id as ParaId, InboundDownwardMessage, InboundHrmpMessage, NodeFeatures, OccupiedCoreAssumption,
+	PersistedValidationData, PvfCheckStatement, ScheduledCore, SessionIndex, SessionInfo, Slot,
+	ValidationCode, ValidationCodeHash, ValidatorId, ValidatorIndex, ValidatorSignature,
 };
 use polkadot_primitives_test_helpers::{
 	dummy_committed_candidate_receipt_v2, dummy_validation_code,
@@ -38,7 +38,10 @@ use sp_api::ApiError;
 use sp_core::testing::TaskExecutor;
 use std::{
 	collections::{BTreeMap, HashMap, VecDeque},
-	sync::{Arc, Mutex},
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc, Mutex,
+	},
 };
 
 #[derive(Default)]
@@ -54,21 +57,52 @@ struct MockSubsystemClient {
 	session_index_for_child: SessionIndex,
 	candidate_pending_availability: HashMap<ParaId, CommittedCandidateReceipt>,
 	candidates_pending_availability: HashMap<ParaId, Vec<CommittedCandidateReceipt>>,
+	para_backing_state: HashMap<ParaId, vstaging::async_backing::BackingState>,
 	dmq_contents: HashMap<ParaId, Vec<InboundDownwardMessage>>,
 	hrmp_channels: HashMap<ParaId, BTreeMap<ParaId, Vec<InboundHrmpMessage>>>,
 	validation_code_by_hash: HashMap<ValidationCodeHash, ValidationCode>,
 	availability_cores_wait: Arc<Mutex<()>>,
+	availability_cores_calls: Arc<AtomicUsize>,
 	babe_epoch: Option<BabeEpoch>,
 	pvfs_require_precheck: Vec<ValidationCodeHash>,
 	validation_code_hash: HashMap<ParaId, ValidationCodeHash>,
 	session_info: HashMap<SessionIndex, SessionInfo>,
+	session_executor_params: Arc<Mutex<HashMap<SessionIndex, ExecutorParams>>>,
 	candidate_events: Vec<CandidateEvent>,
+	on_chain_votes: HashMap<Hash, ScrapedOnChainVotes>,
+	minimum_backing_votes: u32,
+	minimum_backing_votes_for_paras: HashMap<ParaId, u32>,
+	unapplied_slashes: Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>,
+	disputes: Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumber>)>,
+	key_ownership_proofs: HashMap<(SessionIndex, ValidatorId), slashing::OpaqueKeyOwnershipProof>,
+	/// The reported `ParachainHost` version, `5` if not set.
+	api_version: Option<u32>,
+	headers: HashMap<Hash, Header>,
+}
+
+#[async_trait::async_trait]
+impl ChainApiBackend for MockSubsystemClient {
+	async fn header(&self, hash: Hash) -> sp_blockchain::Result<Option<Header>> {
+		Ok(self.headers.get(&hash).cloned())
+	}
+
+	async fn info(&self) -> sp_blockchain::Result<sp_blockchain::Info<Block>> {
+		todo!("Not required for tests")
+	}
+
+	async fn number(&self, hash: Hash) -> sp_blockchain::Result<Option<BlockNumber>> {
+		Ok(self.headers.get(&hash).map(|header| header.number))
+	}
+
+	async fn hash(&self, _: BlockNumber) -> sp_blockchain::Result<Option<Hash>> {
+		todo!("Not required for tests")
+	}
 }
 
 #[async_trait::async_trait]
 impl RuntimeApiSubsystemClient for MockSubsystemClient {
 	async fn api_version_parachain_host(&self, _: Hash) -> Result<Option<u32>, ApiError> {
-		Ok(Some(5))
+		Ok(Some(self.api_version.unwrap_or(5)))
 	}
 
 	async fn validators(&self, _: Hash) -> Result<Vec<ValidatorId>, ApiError> {
@@ -90,6 +124,7 @@ impl RuntimeApiSubsystemClient for MockSubsystemClient {
 		_: Hash,
 	) -> Result<Vec<CoreState<Hash, BlockNumber>>, ApiError> {
 		let _lock = self.availability_cores_wait.lock().unwrap();
+		self.availability_cores_calls.fetch_add(1, Ordering::SeqCst);
 		Ok(self.availability_cores.clone())
 	}
 
@@ -183,8 +218,11 @@ impl RuntimeApiSubsystemClient for MockSubsystemClient {
 		Ok(self.validation_code_by_hash.get(&hash).cloned())
 	}
 
-	async fn on_chain_votes(&self, _: Hash) -> Result<Option<ScrapedOnChainVotes<Hash>>, ApiError> {
-		todo!("Not required for tests")
+	async fn on_chain_votes(
+		&self,
+		at: Hash,
+	) -> Result<Option<ScrapedOnChainVotes<Hash>>, ApiError> {
+		Ok(self.on_chain_votes.get(&at).cloned())
 	}
 
 	async fn session_info(
@@ -222,22 +260,25 @@ impl RuntimeApiSubsystemClient for MockSubsystemClient {
 		&self,
 		_: Hash,
 	) -> Result<Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumber>)>, ApiError> {
-		todo!("Not required for tests")
+		Ok(self.disputes.clone())
 	}
 
 	async fn unapplied_slashes(
 		&self,
 		_: Hash,
 	) -> Result<Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>, ApiError> {
-		todo!("Not required for tests")
+		Ok(self.unapplied_slashes.clone())
 	}
 
 	async fn key_ownership_proof(
 		&self,
 		_: Hash,
-		_: ValidatorId,
+		validator_id: ValidatorId,
 	) -> Result<Option<slashing::OpaqueKeyOwnershipProof>, ApiError> {
-		todo!("Not required for tests")
+		Ok(self
+			.key_ownership_proofs
+			.get(&(self.session_index_for_child, validator_id))
+			.cloned())
 	}
 
 	async fn submit_report_dispute_lost(
@@ -252,9 +293,9 @@ impl RuntimeApiSubsystemClient for MockSubsystemClient {
 	async fn session_executor_params(
 		&self,
 		_: Hash,
-		_: SessionIndex,
+		session_index: SessionIndex,
 	) -> Result<Option<ExecutorParams>, ApiError> {
-		todo!("Not required for tests")
+		Ok(self.session_executor_params.lock().unwrap().get(&session_index).cloned())
 	}
 
 	/// Approval voting configuration parameters
@@ -284,13 +325,13 @@ impl RuntimeApiSubsystemClient for MockSubsystemClient {
 	async fn para_backing_state(
 		&self,
 		_: Hash,
-		_: ParaId,
+		para_id: ParaId,
 	) -> Result<Option<vstaging::async_backing::BackingState>, ApiError> {
-		todo!("Not required for tests")
+		Ok(self.para_backing_state.get(&para_id).cloned())
 	}
 
 	async fn minimum_backing_votes(&self, _: Hash, _: SessionIndex) -> Result<u32, ApiError> {
-		todo!("Not required for tests")
+		Ok(self.minimum_backing_votes)
 	}
 
 	async fn node_features(&self, _: Hash) -> Result<NodeFeatures, ApiError> {
@@ -319,6 +360,46 @@ impl RuntimeApiSubsystemClient for MockSubsystemClient {
 	) -> Result<Option<Constraints>, ApiError> {
 		todo!("Not required for tests")
 	}
+
+	async fn inbound_hrmp_channels_contents_all(
+		&self,
+		_: Hash,
+	) -> Result<BTreeMap<ParaId, BTreeMap<ParaId, Vec<InboundHrmpMessage>>>, ApiError> {
+		todo!("Not required for tests")
+	}
+
+	async fn minimum_backing_votes_for_paras(
+		&self,
+		_: Hash,
+		para_ids: Vec<ParaId>,
+	) -> Result<Vec<u32>, ApiError> {
+		Ok(para_ids
+			.iter()
+			.map(|para_id| self.minimum_backing_votes_for_paras.get(para_id).copied().unwrap())
+			.collect())
+	}
+
+	async fn unapplied_slashes_for_session(
+		&self,
+		_: Hash,
+		session_index: SessionIndex,
+	) -> Result<Vec<(CandidateHash, slashing::PendingSlashes)>, ApiError> {
+		Ok(self
+			.unapplied_slashes
+			.iter()
+			.filter(|(session, _, _)| *session == session_index)
+			.map(|(_, candidate_hash, pending)| (*candidate_hash, pending.clone()))
+			.collect())
+	}
+
+	async fn key_ownership_proof_for_session(
+		&self,
+		_: Hash,
+		session_index: SessionIndex,
+		validator_id: ValidatorId,
+	) -> Result<Option<slashing::OpaqueKeyOwnershipProof>, ApiError> {
+		Ok(self.key_ownership_proofs.get(&(session_index, validator_id)).cloned())
+	}
 }
 
 #[test]
@@ -326,7 +407,7 @@ fn requests_authorities() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
 	let subsystem_client = Arc::new(MockSubsystemClient::default());
 	let relay_parent = [1; 32].into();
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let subsystem =
 		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
@@ -348,12 +429,193 @@ fn requests_authorities() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn requests_authorities_for_session() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let mut subsystem_client = MockSubsystemClient::default();
+	let alice: AuthorityDiscoveryId = sp_keyring::Sr25519Keyring::Alice.public().into();
+	let bob: AuthorityDiscoveryId = sp_keyring::Sr25519Keyring::Bob.public().into();
+	let charlie: AuthorityDiscoveryId = sp_keyring::Sr25519Keyring::Charlie.public().into();
+	let pruned_session_index = 0;
+	let stored_session_index = 1;
+	let current_session_index = 2;
+	let mut session_info = dummy_session_info();
+	session_info.discovery_keys = vec![alice.clone(), bob.clone(), alice.clone()];
+	subsystem_client.session_info.insert(stored_session_index, session_info);
+	subsystem_client.session_index_for_child = current_session_index;
+	subsystem_client.authorities = vec![charlie.clone()];
+	let subsystem_client = Arc::new(subsystem_client);
+	let relay_parent = [1; 32].into();
+	let spawner = TaskExecutor::new();
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		for (session_index, expected) in [
+			(stored_session_index, vec![alice.clone(), bob.clone()]),
+			// Served from the cached session info.
+			(stored_session_index, vec![alice, bob]),
+			(current_session_index, vec![charlie]),
+			(pruned_session_index, vec![]),
+		] {
+			let (tx, rx) = oneshot::channel();
+
+			ctx_handle
+				.send(FromOrchestra::Communication {
+					msg: RuntimeApiMessage::Request(
+						relay_parent,
+						Request::AuthoritiesForSession(session_index, tx),
+					),
+				})
+				.await;
+
+			assert_eq!(rx.await.unwrap().unwrap(), expected);
+		}
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
+#[test]
+fn requests_authorities_for_current_session_deduplicated() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let mut subsystem_client = MockSubsystemClient::default();
+	let alice: AuthorityDiscoveryId = sp_keyring::Sr25519Keyring::Alice.public().into();
+	let bob: AuthorityDiscoveryId = sp_keyring::Sr25519Keyring::Bob.public().into();
+	let current_session_index = 2;
+	subsystem_client.session_index_for_child = current_session_index;
+	subsystem_client.authorities = vec![bob.clone(), alice.clone(), bob.clone()];
+	let subsystem_client = Arc::new(subsystem_client);
+	let relay_parent = [1; 32].into();
+	let spawner = TaskExecutor::new();
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::AuthoritiesForSession(current_session_index, tx),
+				),
+			})
+			.await;
+
+		// Deduplicated keeping the first occurrence, as the keys of the session info.
+		assert_eq!(rx.await.unwrap().unwrap(), vec![bob, alice]);
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
+#[test]
+fn requests_authorities_for_future_session_fail() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let mut subsystem_client = MockSubsystemClient::default();
+	let alice: AuthorityDiscoveryId = sp_keyring::Sr25519Keyring::Alice.public().into();
+	let current_session_index = 2;
+	subsystem_client.session_index_for_child = current_session_index;
+	subsystem_client.authorities = vec![alice];
+	let subsystem_client = Arc::new(subsystem_client);
+	let relay_parent = [1; 32].into();
+	let spawner = TaskExecutor::new();
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::AuthoritiesForSession(current_session_index + 1, tx),
+				),
+			})
+			.await;
+
+		assert!(matches!(rx.await.unwrap(), Err(RuntimeApiError::Execution { .. })));
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
+#[test]
+fn requests_validators_diff() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let mut subsystem_client = MockSubsystemClient::default();
+	let alice: ValidatorId = sp_keyring::Sr25519Keyring::Alice.public().into();
+	let bob: ValidatorId = sp_keyring::Sr25519Keyring::Bob.public().into();
+	let charlie: ValidatorId = sp_keyring::Sr25519Keyring::Charlie.public().into();
+	let pruned_session_index = 0;
+	let stored_session_index = 1;
+	let current_session_index = 2;
+	let mut session_info = dummy_session_info();
+	session_info.validators = vec![alice.clone(), bob.clone()].into();
+	subsystem_client.session_info.insert(stored_session_index, session_info);
+	subsystem_client.session_index_for_child = current_session_index;
+	subsystem_client.validators = vec![bob.clone(), charlie.clone()];
+	let subsystem_client = Arc::new(subsystem_client);
+	let relay_parent = [1; 32].into();
+	let spawner = TaskExecutor::new();
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let request_diff = |from, to| {
+			let (tx, rx) = oneshot::channel();
+			let msg =
+				RuntimeApiMessage::Request(relay_parent, Request::ValidatorsDiff(from, to, tx));
+			(FromOrchestra::Communication { msg }, rx)
+		};
+
+		let (msg, rx) = request_diff(stored_session_index, current_session_index);
+		ctx_handle.send(msg).await;
+		assert_eq!(
+			rx.await.unwrap().unwrap(),
+			ValidatorSetDiff { added: vec![charlie.clone()], removed: vec![alice.clone()] },
+		);
+
+		let (msg, rx) = request_diff(current_session_index, stored_session_index);
+		ctx_handle.send(msg).await;
+		assert_eq!(
+			rx.await.unwrap().unwrap(),
+			ValidatorSetDiff { added: vec![alice], removed: vec![charlie] },
+		);
+
+		let (msg, rx) = request_diff(stored_session_index, stored_session_index);
+		ctx_handle.send(msg).await;
+		assert_eq!(rx.await.unwrap().unwrap(), ValidatorSetDiff::default());
+
+		// The validators of a pruned session are not known.
+		let (msg, rx) = request_diff(pruned_session_index, current_session_index);
+		ctx_handle.send(msg).await;
+		assert!(rx.await.unwrap().is_err());
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
 #[test]
 fn requests_validators() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
 	let subsystem_client = Arc::new(MockSubsystemClient::default());
 	let relay_parent = [1; 32].into();
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let subsystem =
 		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
@@ -380,7 +642,7 @@ fn requests_validator_groups() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
 	let subsystem_client = Arc::new(MockSubsystemClient::default());
 	let relay_parent = [1; 32].into();
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let subsystem =
 		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
@@ -407,7 +669,7 @@ fn requests_availability_cores() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
 	let subsystem_client = Arc::new(MockSubsystemClient::default());
 	let relay_parent = [1; 32].into();
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let subsystem =
 		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
@@ -435,7 +697,7 @@ fn requests_persisted_validation_data() {
 	let relay_parent = [1; 32].into();
 	let para_a = ParaId::from(5_u32);
 	let para_b = ParaId::from(6_u32);
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let mut subsystem_client = MockSubsystemClient::default();
 	subsystem_client.validation_data.insert(para_a, Default::default());
@@ -476,13 +738,70 @@ fn requests_persisted_validation_data() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn requests_persisted_validation_data_batch() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let relay_parent = [1; 32].into();
+	let para_a = ParaId::from(5_u32);
+	let para_b = ParaId::from(6_u32);
+	let spawner = TaskExecutor::new();
+
+	let mut subsystem_client = MockSubsystemClient::default();
+	subsystem_client.validation_data.insert(para_a, Default::default());
+	let subsystem_client = Arc::new(subsystem_client);
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::PersistedValidationDataBatch(
+						vec![
+							(para_b, OccupiedCoreAssumption::Included),
+							(para_a, OccupiedCoreAssumption::Included),
+						],
+						tx,
+					),
+				),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), vec![None, Some(Default::default())]);
+
+		// Served from the cache populated by the batch request.
+		let (tx, rx) = oneshot::channel();
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::PersistedValidationDataBatch(
+						vec![(para_a, OccupiedCoreAssumption::Included)],
+						tx,
+					),
+				),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), vec![Some(Default::default())]);
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
 #[test]
 fn requests_assumed_validation_data() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
 	let relay_parent = [1; 32].into();
 	let para_a = ParaId::from(5_u32);
 	let para_b = ParaId::from(6_u32);
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let validation_code = ValidationCode(vec![1, 2, 3]);
 	let expected_data_hash = <PersistedValidationData as Default>::default().hash();
@@ -537,7 +856,7 @@ fn requests_check_validation_outputs() {
 	let para_a = ParaId::from(5_u32);
 	let para_b = ParaId::from(6_u32);
 	let commitments = polkadot_primitives::CandidateCommitments::default();
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	subsystem_client.validation_outputs_results.insert(para_a, false);
 	subsystem_client.validation_outputs_results.insert(para_b, true);
@@ -584,11 +903,19 @@ fn requests_check_validation_outputs() {
 }
 
 #[test]
-fn requests_session_index_for_child() {
+fn requests_check_validation_outputs_batch() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
-	let subsystem_client = Arc::new(MockSubsystemClient::default());
+	let mut subsystem_client = MockSubsystemClient::default();
 	let relay_parent = [1; 32].into();
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let para_a = ParaId::from(5_u32);
+	let para_b = ParaId::from(6_u32);
+	let commitments = polkadot_primitives::CandidateCommitments::default();
+	let spawner = TaskExecutor::new();
+
+	subsystem_client.validation_outputs_results.insert(para_a, false);
+	subsystem_client.validation_outputs_results.insert(para_b, true);
+
+	let subsystem_client = Arc::new(subsystem_client);
 
 	let subsystem =
 		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
@@ -598,11 +925,32 @@ fn requests_session_index_for_child() {
 
 		ctx_handle
 			.send(FromOrchestra::Communication {
-				msg: RuntimeApiMessage::Request(relay_parent, Request::SessionIndexForChild(tx)),
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::CheckValidationOutputsBatch(
+						vec![
+							(para_b, commitments.clone()),
+							(para_a, commitments.clone()),
+							(para_b, commitments.clone()),
+						],
+						tx,
+					),
+				),
 			})
 			.await;
+		assert_eq!(rx.await.unwrap().unwrap(), vec![true, false, true]);
 
-		assert_eq!(rx.await.unwrap().unwrap(), subsystem_client.session_index_for_child);
+		// Served from the cache populated by the batch request.
+		let (tx, rx) = oneshot::channel();
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::CheckValidationOutputs(para_a, commitments, tx),
+				),
+			})
+			.await;
+		assert!(!rx.await.unwrap().unwrap());
 
 		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
 	};
@@ -610,51 +958,324 @@ fn requests_session_index_for_child() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
-fn dummy_session_info() -> SessionInfo {
-	SessionInfo {
-		validators: Default::default(),
-		discovery_keys: vec![],
-		assignment_keys: vec![],
-		validator_groups: Default::default(),
-		n_cores: 4u32,
-		zeroth_delay_tranche_width: 0u32,
-		relay_vrf_modulo_samples: 0u32,
-		n_delay_tranches: 2u32,
-		no_show_slots: 0u32,
-		needed_approvals: 1u32,
-		active_validator_indices: vec![],
-		dispute_period: 6,
-		random_seed: [0u8; 32],
-	}
-}
 #[test]
-fn requests_session_info() {
+fn requests_para_backing_state_with_cores() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let relay_parent = Hash::repeat_byte(1);
+	let para_a = ParaId::from(5_u32);
+	let para_b = ParaId::from(6_u32);
+	let spawner = TaskExecutor::new();
+
+	let pending_availability = [1u8, 2]
+		.into_iter()
+		.map(|n| {
+			let candidate = dummy_committed_candidate_receipt_v2(relay_parent);
+			vstaging::async_backing::CandidatePendingAvailability {
+				candidate_hash: CandidateHash(Hash::repeat_byte(n)),
+				descriptor: candidate.descriptor,
+				commitments: candidate.commitments,
+				relay_parent_number: 1,
+				max_pov_size: 1024,
+			}
+		})
+		.collect::<Vec<_>>();
+	let backing_state = vstaging::async_backing::BackingState {
+		constraints: async_backing::Constraints {
+			min_relay_parent_number: 0,
+			max_pov_size: 1024,
+			max_code_size: 1024,
+			ump_remaining: 0,
+			ump_remaining_bytes: 0,
+			max_ump_num_per_candidate: 0,
+			dmp_remaining_messages: vec![],
+			hrmp_inbound: async_backing::InboundHrmpLimitations { valid_watermarks: vec![] },
+			hrmp_channels_out: vec![],
+			max_hrmp_num_per_candidate: 0,
+			required_parent: Default::default(),
+			validation_code_hash: dummy_validation_code().hash(),
+			upgrade_restriction: None,
+			future_validation_code: None,
+		},
+		pending_availability: pending_availability.clone(),
+	};
+
+	// Only the first candidate is still occupying a core.
+	let occupied = vstaging::OccupiedCore {
+		next_up_on_available: None,
+		occupied_since: 1,
+		time_out_at: 10,
+		next_up_on_time_out: None,
+		availability: Default::default(),
+		group_responsible: Default::default(),
+		candidate_hash: pending_availability[0].candidate_hash,
+		candidate_descriptor: pending_availability[0].descriptor.clone(),
+	};
+
 	let mut subsystem_client = MockSubsystemClient::default();
-	let session_index = 1;
-	subsystem_client.session_info.insert(session_index, dummy_session_info());
+	subsystem_client.api_version = Some(Request::ASYNC_BACKING_STATE_RUNTIME_REQUIREMENT);
+	subsystem_client.para_backing_state.insert(para_a, backing_state.clone());
+	subsystem_client.availability_cores = vec![CoreState::Free, CoreState::Occupied(occupied)];
 	let subsystem_client = Arc::new(subsystem_client);
-	let spawner = sp_core::testing::TaskExecutor::new();
-
-	let relay_parent = [1; 32].into();
 
 	let subsystem =
 		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
-		let (tx, rx) = oneshot::channel();
+		let expected = BackingStateWithCores {
+			backing_state,
+			pending_availability_cores: vec![Some(CoreIndex(1)), None],
+		};
 
+		let (tx, rx) = oneshot::channel();
 		ctx_handle
 			.send(FromOrchestra::Communication {
 				msg: RuntimeApiMessage::Request(
 					relay_parent,
-					Request::SessionInfo(session_index, tx),
+					Request::ParaBackingStateWithCores(para_a, tx),
 				),
 			})
 			.await;
+		assert_eq!(rx.await.unwrap().unwrap(), Some(expected.clone()));
+
+		let (tx, rx) = oneshot::channel();
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::ParaBackingStateWithCores(para_b, tx),
+				),
+			})
+			.await;
+		assert_eq!(rx.await.unwrap().unwrap(), None);
+
+		// Answered the same way once the first request populated the cache.
+		let (tx, rx) = oneshot::channel();
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::ParaBackingStateWithCores(para_a, tx),
+				),
+			})
+			.await;
+		assert_eq!(rx.await.unwrap().unwrap(), Some(expected));
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
+#[test]
+fn requests_session_index_for_child() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let subsystem_client = Arc::new(MockSubsystemClient::default());
+	let relay_parent = [1; 32].into();
+	let spawner = TaskExecutor::new();
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(relay_parent, Request::SessionIndexForChild(tx)),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), subsystem_client.session_index_for_child);
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
+#[test]
+fn session_index_for_child_is_evicted_for_deactivated_leaves() {
+	let spawner = TaskExecutor::new();
+	let mut subsystem = RuntimeApiSubsystem::new(
+		Arc::new(MockSubsystemClient::default()),
+		Metrics(None),
+		SpawnGlue(spawner),
+	);
+	let leaf_a = Hash::repeat_byte(1);
+	let leaf_b = Hash::repeat_byte(2);
+	subsystem.requests_cache.cache_session_index_for_child(leaf_a, 1);
+	subsystem.requests_cache.cache_session_index_for_child(leaf_b, 1);
+
+	subsystem.on_active_leaves_update(&ActiveLeavesUpdate::stop_work(leaf_a));
+
+	assert!(subsystem.requests_cache.session_index_for_child(&leaf_a).is_none());
+	assert_eq!(subsystem.requests_cache.session_index_for_child(&leaf_b), Some(&1));
+}
+
+fn dummy_session_info() -> SessionInfo {
+	SessionInfo {
+		validators: Default::default(),
+		discovery_keys: vec![],
+		assignment_keys: vec![],
+		validator_groups: Default::default(),
+		n_cores: 4u32,
+		zeroth_delay_tranche_width: 0u32,
+		relay_vrf_modulo_samples: 0u32,
+		n_delay_tranches: 2u32,
+		no_show_slots: 0u32,
+		needed_approvals: 1u32,
+		active_validator_indices: vec![],
+		dispute_period: 6,
+		random_seed: [0u8; 32],
+	}
+}
+#[test]
+fn requests_session_info() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let mut subsystem_client = MockSubsystemClient::default();
+	let session_index = 1;
+	subsystem_client.session_info.insert(session_index, dummy_session_info());
+	let subsystem_client = Arc::new(subsystem_client);
+	let spawner = TaskExecutor::new();
+
+	let relay_parent = [1; 32].into();
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::SessionInfo(session_index, tx),
+				),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), Some(dummy_session_info()));
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
+#[test]
+fn requests_session_executor_params() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let subsystem_client = Arc::new(MockSubsystemClient::default());
+	let session_index = 1;
+	let spawner = TaskExecutor::new();
+
+	let relay_parent = [1; 32].into();
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let mut request = || {
+			let (tx, rx) = oneshot::channel();
+			let msg = FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::SessionExecutorParams(session_index, tx),
+				),
+			};
+			(msg, rx)
+		};
+
+		// The session is not known yet.
+		let (msg, rx) = request();
+		ctx_handle.send(msg).await;
+		assert_eq!(rx.await.unwrap().unwrap(), None);
+
+		// Unknown sessions are not cached, so the params are returned once set.
+		subsystem_client
+			.session_executor_params
+			.lock()
+			.unwrap()
+			.insert(session_index, ExecutorParams::default());
+		let (msg, rx) = request();
+		ctx_handle.send(msg).await;
+		assert_eq!(rx.await.unwrap().unwrap(), Some(ExecutorParams::default()));
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
+#[test]
+fn caches_only_known_session_executor_params() {
+	let subsystem_client = Arc::new(MockSubsystemClient::default());
+	let mut subsystem =
+		RuntimeApiSubsystem::new(subsystem_client, Metrics(None), SpawnGlue(TaskExecutor::new()));
+	let relay_parent = [1; 32].into();
+	let session_index = 1;
+
+	subsystem.store_cache(RequestResult::SessionExecutorParams(relay_parent, session_index, None));
+	let (tx, _rx) = oneshot::channel();
+	assert!(subsystem
+		.query_cache(relay_parent, Request::SessionExecutorParams(session_index, tx))
+		.is_some());
+
+	subsystem.store_cache(RequestResult::SessionExecutorParams(
+		relay_parent,
+		session_index,
+		Some(ExecutorParams::default()),
+	));
+	let (tx, rx) = oneshot::channel();
+	assert!(subsystem
+		.query_cache(relay_parent, Request::SessionExecutorParams(session_index, tx))
+		.is_none());
+	assert_eq!(futures::executor::block_on(rx).unwrap().unwrap(), Some(ExecutorParams::default()));
+}
+
+#[test]
+fn requests_session_infos() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let mut subsystem_client = MockSubsystemClient::default();
+	let session_index = 1;
+	let missing_session_index = 2;
+	subsystem_client.session_info.insert(session_index, dummy_session_info());
+	let subsystem_client = Arc::new(subsystem_client);
+	let spawner = TaskExecutor::new();
+
+	let relay_parent = [1; 32].into();
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::SessionInfos(vec![missing_session_index, session_index], tx),
+				),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), vec![None, Some(dummy_session_info())]);
+
+		let (tx, rx) = oneshot::channel();
+
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::SessionInfos(vec![session_index], tx),
+				),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), vec![Some(dummy_session_info())]);
 
-		assert_eq!(rx.await.unwrap().unwrap(), Some(dummy_session_info()));
-
 		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
 	};
 
@@ -668,7 +1289,7 @@ fn requests_validation_code() {
 	let relay_parent = [1; 32].into();
 	let para_a = ParaId::from(5_u32);
 	let para_b = ParaId::from(6_u32);
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 	let validation_code = dummy_validation_code();
 
 	let mut subsystem_client = MockSubsystemClient::default();
@@ -710,13 +1331,62 @@ fn requests_validation_code() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn requests_validation_code_len() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+
+	let relay_parent = [1; 32].into();
+	let para_a = ParaId::from(5_u32);
+	let para_b = ParaId::from(6_u32);
+	let spawner = TaskExecutor::new();
+	let validation_code = dummy_validation_code();
+
+	let mut subsystem_client = MockSubsystemClient::default();
+	subsystem_client.validation_code.insert(para_a, validation_code.clone());
+	let subsystem_client = Arc::new(subsystem_client);
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::ValidationCodeLen(para_a, OccupiedCoreAssumption::Included, tx),
+				),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), Some(validation_code.0.len() as u32));
+
+		let (tx, rx) = oneshot::channel();
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::ValidationCodeLen(para_b, OccupiedCoreAssumption::Included, tx),
+				),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), None);
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
 #[test]
 fn requests_candidate_pending_availability() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
 	let relay_parent = [1; 32].into();
 	let para_a = ParaId::from(5_u32);
 	let para_b = ParaId::from(6_u32);
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 	let candidate_receipt = dummy_committed_candidate_receipt_v2(relay_parent);
 
 	let mut subsystem_client = MockSubsystemClient::default();
@@ -761,12 +1431,163 @@ fn requests_candidate_pending_availability() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn requests_candidate_events_in_range() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let spawner = TaskExecutor::new();
+
+	// Block `n` has hash `[n; 32]`, its fork sibling at height 3 has hash `[0xf3; 32]`.
+	let mut subsystem_client = MockSubsystemClient::default();
+	for n in 1..=100u8 {
+		let header = Header::new(
+			n.into(),
+			Default::default(),
+			Default::default(),
+			[n - 1; 32].into(),
+			Default::default(),
+		);
+		subsystem_client.headers.insert([n; 32].into(), header);
+	}
+	let fork =
+		Header::new(3, Default::default(), Default::default(), [2; 32].into(), Default::default());
+	subsystem_client.headers.insert([0xf3; 32].into(), fork);
+	let subsystem_client = Arc::new(subsystem_client);
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let request_range = |from: u8, to: u8| {
+			let (tx, rx) = oneshot::channel();
+			let msg = RuntimeApiMessage::Request(
+				[to; 32].into(),
+				Request::CandidateEventsInRange {
+					from: [from; 32].into(),
+					to: [to; 32].into(),
+					response: tx,
+				},
+			);
+			(FromOrchestra::Communication { msg }, rx)
+		};
+
+		let (msg, rx) = request_range(2, 5);
+		ctx_handle.send(msg).await;
+		let expected = (2..=5u8)
+			.map(|n| (Hash::from([n; 32]), subsystem_client.candidate_events.clone()))
+			.collect::<Vec<_>>();
+		assert_eq!(rx.await.unwrap().unwrap(), expected);
+
+		// A single block range.
+		let (msg, rx) = request_range(4, 4);
+		ctx_handle.send(msg).await;
+		assert_eq!(rx.await.unwrap().unwrap().len(), 1);
+
+		// `from` is on another fork.
+		let (msg, rx) = request_range(0xf3, 5);
+		ctx_handle.send(msg).await;
+		assert!(rx.await.unwrap().is_err());
+
+		// `from` is higher than `to`.
+		let (msg, rx) = request_range(5, 2);
+		ctx_handle.send(msg).await;
+		assert!(rx.await.unwrap().is_err());
+
+		// The range is too long.
+		let (msg, rx) = request_range(1, 1 + Request::MAX_CANDIDATE_EVENTS_RANGE as u8);
+		ctx_handle.send(msg).await;
+		assert!(rx.await.unwrap().is_err());
+
+		// The longest range allowed.
+		let (msg, rx) = request_range(1, Request::MAX_CANDIDATE_EVENTS_RANGE as u8);
+		ctx_handle.send(msg).await;
+		assert_eq!(rx.await.unwrap().unwrap().len(), Request::MAX_CANDIDATE_EVENTS_RANGE as usize);
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
+#[test]
+fn requests_on_chain_votes_in_range() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let spawner = TaskExecutor::new();
+
+	// Block `n` has hash `[n; 32]`, only block 3 has on-chain votes.
+	let mut subsystem_client = MockSubsystemClient::default();
+	for n in 1..=100u8 {
+		let header = Header::new(
+			n.into(),
+			Default::default(),
+			Default::default(),
+			[n - 1; 32].into(),
+			Default::default(),
+		);
+		subsystem_client.headers.insert([n; 32].into(), header);
+	}
+	let votes = ScrapedOnChainVotes {
+		session: 1,
+		backing_validators_per_candidate: Vec::new(),
+		disputes: Vec::new(),
+	};
+	subsystem_client.on_chain_votes.insert([3; 32].into(), votes.clone());
+	let subsystem_client = Arc::new(subsystem_client);
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let request_range = |from: u8, to: u8| {
+			let (tx, rx) = oneshot::channel();
+			let msg = RuntimeApiMessage::Request(
+				[to; 32].into(),
+				Request::FetchOnChainVotesInRange {
+					from: [from; 32].into(),
+					to: [to; 32].into(),
+					response: tx,
+				},
+			);
+			(FromOrchestra::Communication { msg }, rx)
+		};
+
+		let (msg, rx) = request_range(2, 4);
+		ctx_handle.send(msg).await;
+		assert_eq!(
+			rx.await.unwrap().unwrap(),
+			vec![
+				(Hash::from([2; 32]), None),
+				(Hash::from([3; 32]), Some(votes)),
+				(Hash::from([4; 32]), None),
+			],
+		);
+
+		// `from` is higher than `to`.
+		let (msg, rx) = request_range(4, 2);
+		ctx_handle.send(msg).await;
+		assert!(rx.await.unwrap().is_err());
+
+		// The range is too long.
+		let (msg, rx) = request_range(1, 1 + Request::MAX_ON_CHAIN_VOTES_RANGE as u8);
+		ctx_handle.send(msg).await;
+		assert!(rx.await.unwrap().is_err());
+
+		// The longest range allowed.
+		let (msg, rx) = request_range(1, Request::MAX_ON_CHAIN_VOTES_RANGE as u8);
+		ctx_handle.send(msg).await;
+		assert_eq!(rx.await.unwrap().unwrap().len(), Request::MAX_ON_CHAIN_VOTES_RANGE as usize);
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
 #[test]
 fn requests_candidate_events() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
 	let subsystem_client = Arc::new(MockSubsystemClient::default());
 	let relay_parent = [1; 32].into();
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let subsystem =
 		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
@@ -795,7 +1616,7 @@ fn requests_dmq_contents() {
 	let relay_parent = [1; 32].into();
 	let para_a = ParaId::from(5_u32);
 	let para_b = ParaId::from(6_u32);
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let subsystem_client = Arc::new({
 		let mut subsystem_client = MockSubsystemClient::default();
@@ -837,6 +1658,45 @@ fn requests_dmq_contents() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn requests_dmq_contents_bounded() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+
+	let relay_parent = [1; 32].into();
+	let para_a = ParaId::from(5_u32);
+	let spawner = TaskExecutor::new();
+	let messages = (0..3)
+		.map(|i| InboundDownwardMessage { sent_at: i, msg: vec![i as u8] })
+		.collect::<Vec<_>>();
+
+	let subsystem_client = Arc::new({
+		let mut subsystem_client = MockSubsystemClient::default();
+		subsystem_client.dmq_contents.insert(para_a, messages.clone());
+		subsystem_client
+	});
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		for (limit, expected) in [(2, &messages[..2]), (5, &messages[..]), (0, &[][..])] {
+			let (tx, rx) = oneshot::channel();
+			ctx_handle
+				.send(FromOrchestra::Communication {
+					msg: RuntimeApiMessage::Request(
+						relay_parent,
+						Request::DmqContentsBounded(para_a, limit, tx),
+					),
+				})
+				.await;
+			assert_eq!(rx.await.unwrap().unwrap(), expected);
+		}
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
 #[test]
 fn requests_inbound_hrmp_channels_contents() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
@@ -845,7 +1705,7 @@ fn requests_inbound_hrmp_channels_contents() {
 	let para_a = ParaId::from(99_u32);
 	let para_b = ParaId::from(66_u32);
 	let para_c = ParaId::from(33_u32);
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let para_b_inbound_channels = [
 		(para_a, vec![]),
@@ -894,10 +1754,317 @@ fn requests_inbound_hrmp_channels_contents() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn requests_inbound_hrmp_channels_contents_all_falls_back_to_per_para_queries() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+
+	let relay_parent = [1; 32].into();
+	let para_a = ParaId::from(99_u32);
+	let para_b = ParaId::from(66_u32);
+	let para_c = ParaId::from(33_u32);
+	let spawner = TaskExecutor::new();
+
+	let para_b_inbound_channels =
+		[(para_a, vec![]), (para_c, vec![InboundHrmpMessage { sent_at: 1, data: vec![1, 2, 3] }])]
+			.into_iter()
+			.collect::<BTreeMap<_, _>>();
+
+	let subsystem_client = Arc::new({
+		let mut subsystem_client = MockSubsystemClient::default();
+
+		// The mocked runtime predates `inbound_hrmp_channels_contents_all`, so the paras are
+		// discovered through the availability cores.
+		subsystem_client.availability_cores = vec![
+			CoreState::Scheduled(ScheduledCore { para_id: para_a, collator: None }),
+			CoreState::Scheduled(ScheduledCore { para_id: para_b, collator: None }),
+			CoreState::Free,
+		];
+		subsystem_client.hrmp_channels.insert(para_a, BTreeMap::new());
+		subsystem_client.hrmp_channels.insert(para_b, para_b_inbound_channels.clone());
+
+		subsystem_client
+	});
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::InboundHrmpChannelsContentsAll(tx),
+				),
+			})
+			.await;
+		assert_eq!(
+			rx.await.unwrap().unwrap(),
+			[(para_b, para_b_inbound_channels.clone())].into_iter().collect(),
+		);
+
+		// The per-para contents are now served from the cache.
+		let (tx, rx) = oneshot::channel();
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::InboundHrmpChannelsContents(para_b, tx),
+				),
+			})
+			.await;
+		assert_eq!(rx.await.unwrap().unwrap(), para_b_inbound_channels);
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
+#[test]
+fn requests_minimum_backing_votes_for_paras() {
+	let relay_parent = [1; 32].into();
+	let session_index = 1;
+	let para_a = ParaId::from(1_u32);
+	let para_b = ParaId::from(2_u32);
+
+	// (runtime api version, expected votes for `[para_a, para_b]`)
+	let cases = [
+		// Per-para thresholds are supported.
+		(Request::MINIMUM_BACKING_VOTES_FOR_PARAS_RUNTIME_REQUIREMENT, vec![2, 3]),
+		// Only the session-wide threshold is supported.
+		(Request::MINIMUM_BACKING_VOTES_RUNTIME_REQUIREMENT, vec![4, 4]),
+	];
+
+	for (api_version, expected) in cases {
+		let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+		let spawner = TaskExecutor::new();
+
+		let subsystem_client = Arc::new(MockSubsystemClient {
+			api_version: Some(api_version),
+			minimum_backing_votes: 4,
+			minimum_backing_votes_for_paras: [(para_a, 2), (para_b, 3)].into_iter().collect(),
+			..Default::default()
+		});
+
+		let subsystem =
+			RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+		let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+		let test_task = async move {
+			let (tx, rx) = oneshot::channel();
+			ctx_handle
+				.send(FromOrchestra::Communication {
+					msg: RuntimeApiMessage::Request(
+						relay_parent,
+						Request::MinimumBackingVotesForParas(
+							session_index,
+							vec![para_a, para_b],
+							tx,
+						),
+					),
+				})
+				.await;
+			assert_eq!(rx.await.unwrap().unwrap(), expected);
+
+			ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+		};
+		futures::executor::block_on(future::join(subsystem_task, test_task));
+	}
+}
+
+#[test]
+fn requests_key_ownership_proof_for_session() {
+	let relay_parent = [1; 32].into();
+	let alice: ValidatorId = sp_keyring::Sr25519Keyring::Alice.public().into();
+	let past_proof = slashing::OpaqueKeyOwnershipProof::new(vec![1]);
+	let current_proof = slashing::OpaqueKeyOwnershipProof::new(vec![2]);
+
+	// (runtime api version, session index, expected proof or `None` if not supported)
+	let cases = [
+		// Proofs of any session are supported.
+		(Request::KEY_OWNERSHIP_PROOF_FOR_SESSION_RUNTIME_REQUIREMENT, 1, Some(past_proof.clone())),
+		(
+			Request::KEY_OWNERSHIP_PROOF_FOR_SESSION_RUNTIME_REQUIREMENT,
+			2,
+			Some(current_proof.clone()),
+		),
+		// Only the proofs of the current session are supported.
+		(Request::KEY_OWNERSHIP_PROOF_RUNTIME_REQUIREMENT, 1, None),
+		(Request::KEY_OWNERSHIP_PROOF_RUNTIME_REQUIREMENT, 2, Some(current_proof.clone())),
+	];
+
+	for (api_version, session_index, expected) in cases {
+		let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+		let spawner = TaskExecutor::new();
+
+		let subsystem_client = Arc::new(MockSubsystemClient {
+			api_version: Some(api_version),
+			session_index_for_child: 2,
+			key_ownership_proofs: [
+				((1, alice.clone()), past_proof.clone()),
+				((2, alice.clone()), current_proof.clone()),
+			]
+			.into_iter()
+			.collect(),
+			..Default::default()
+		});
+
+		let subsystem =
+			RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+		let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+		let alice = alice.clone();
+		let test_task = async move {
+			let (tx, rx) = oneshot::channel();
+			ctx_handle
+				.send(FromOrchestra::Communication {
+					msg: RuntimeApiMessage::Request(
+						relay_parent,
+						Request::KeyOwnershipProofForSession(session_index, alice, tx),
+					),
+				})
+				.await;
+
+			match expected {
+				Some(expected) => assert_eq!(rx.await.unwrap().unwrap(), Some(expected)),
+				None => assert!(matches!(
+					rx.await.unwrap(),
+					Err(RuntimeApiError::NotSupported {
+						runtime_api_name: "key_ownership_proof_for_session"
+					})
+				)),
+			}
+
+			ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+		};
+		futures::executor::block_on(future::join(subsystem_task, test_task));
+	}
+}
+
+#[test]
+fn requests_unapplied_slashes_for_session() {
+	let relay_parent = [1; 32].into();
+	let pending = slashing::PendingSlashes {
+		keys: Default::default(),
+		kind: slashing::SlashingOffenceKind::ForInvalid,
+	};
+	let candidate_a = CandidateHash(Hash::repeat_byte(1));
+	let candidate_b = CandidateHash(Hash::repeat_byte(2));
+	let candidate_c = CandidateHash(Hash::repeat_byte(3));
+	let unapplied_slashes = vec![
+		(1, candidate_a, pending.clone()),
+		(2, candidate_b, pending.clone()),
+		(1, candidate_c, pending),
+	];
+
+	for api_version in [
+		// Filtered by the runtime.
+		Request::UNAPPLIED_SLASHES_FOR_SESSION_RUNTIME_REQUIREMENT,
+		// Filtered by the subsystem.
+		Request::UNAPPLIED_SLASHES_RUNTIME_REQUIREMENT,
+	] {
+		let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+		let spawner = TaskExecutor::new();
+
+		let subsystem_client = Arc::new(MockSubsystemClient {
+			api_version: Some(api_version),
+			unapplied_slashes: unapplied_slashes.clone(),
+			..Default::default()
+		});
+
+		let subsystem =
+			RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+		let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+		let test_task = async move {
+			for (session_index, expected) in [(1, vec![candidate_a, candidate_c]), (3, vec![])] {
+				let (tx, rx) = oneshot::channel();
+				ctx_handle
+					.send(FromOrchestra::Communication {
+						msg: RuntimeApiMessage::Request(
+							relay_parent,
+							Request::UnappliedSlashesForSession(session_index, tx),
+						),
+					})
+					.await;
+
+				let slashes = rx.await.unwrap().unwrap();
+				assert_eq!(
+					slashes
+						.into_iter()
+						.map(|(candidate_hash, _)| candidate_hash)
+						.collect::<Vec<_>>(),
+					expected,
+				);
+			}
+
+			ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+		};
+		futures::executor::block_on(future::join(subsystem_task, test_task));
+	}
+}
+
+#[test]
+fn requests_disputes_in_sessions() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let spawner = TaskExecutor::new();
+	let relay_parent = [1; 32].into();
+	let state = DisputeState {
+		validators_for: Default::default(),
+		validators_against: Default::default(),
+		start: 1,
+		concluded_at: None,
+	};
+	let candidate_a = CandidateHash(Hash::repeat_byte(1));
+	let candidate_b = CandidateHash(Hash::repeat_byte(2));
+	let candidate_c = CandidateHash(Hash::repeat_byte(3));
+	let candidate_d = CandidateHash(Hash::repeat_byte(4));
+
+	let subsystem_client = Arc::new(MockSubsystemClient {
+		disputes: vec![
+			(1, candidate_a, state.clone()),
+			(2, candidate_b, state.clone()),
+			(3, candidate_c, state.clone()),
+			(1, candidate_d, state),
+		],
+		..Default::default()
+	});
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		for (sessions, expected) in [
+			(vec![1, 3], vec![candidate_a, candidate_c, candidate_d]),
+			(vec![2], vec![candidate_b]),
+			(vec![4], vec![]),
+		] {
+			let (tx, rx) = oneshot::channel();
+			ctx_handle
+				.send(FromOrchestra::Communication {
+					msg: RuntimeApiMessage::Request(
+						relay_parent,
+						Request::DisputesInSessions(sessions, tx),
+					),
+				})
+				.await;
+
+			let disputes = rx.await.unwrap().unwrap();
+			assert_eq!(
+				disputes
+					.into_iter()
+					.map(|(_, candidate_hash, _)| candidate_hash)
+					.collect::<Vec<_>>(),
+				expected,
+			);
+		}
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
 #[test]
 fn requests_validation_code_by_hash() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let (subsystem_client, validation_code) = {
 		let mut subsystem_client = MockSubsystemClient::default();
@@ -938,12 +2105,68 @@ fn requests_validation_code_by_hash() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn requests_validation_code_by_hashes() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let spawner = TaskExecutor::new();
+
+	let mut subsystem_client = MockSubsystemClient::default();
+	let code_a = ValidationCode::from(vec![1; 32]);
+	let code_b = ValidationCode::from(vec![2; 32]);
+	let missing_hash = ValidationCode::from(vec![3; 32]).hash();
+	subsystem_client.validation_code_by_hash.insert(code_a.hash(), code_a.clone());
+	subsystem_client.validation_code_by_hash.insert(code_b.hash(), code_b.clone());
+	let subsystem_client = Arc::new(subsystem_client);
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+
+	let relay_parent = [1; 32].into();
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::ValidationCodeByHashes(
+						vec![code_b.hash(), missing_hash, code_a.hash()],
+						tx,
+					),
+				),
+			})
+			.await;
+
+		assert_eq!(
+			rx.await.unwrap().unwrap(),
+			vec![Some(code_b.clone()), None, Some(code_a.clone())]
+		);
+
+		// Served from the cache.
+		let (tx, rx) = oneshot::channel();
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(
+					relay_parent,
+					Request::ValidationCodeByHashes(vec![code_a.hash(), code_b.hash()], tx),
+				),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), vec![Some(code_a), Some(code_b)]);
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
 #[test]
 fn multiple_requests_in_parallel_are_working() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
 	let subsystem_client = Arc::new(MockSubsystemClient::default());
 	let relay_parent = [1; 32].into();
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 	let mutex = subsystem_client.availability_cores_wait.clone();
 
 	let subsystem =
@@ -992,6 +2215,44 @@ fn multiple_requests_in_parallel_are_working() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn availability_cores_are_cached_per_relay_parent() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	let subsystem_client = Arc::new(MockSubsystemClient {
+		availability_cores: vec![CoreState::Free],
+		..Default::default()
+	});
+	let relay_parent = [1; 32].into();
+	let other_relay_parent = [2; 32].into();
+	let spawner = TaskExecutor::new();
+
+	let subsystem =
+		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		for (relay_parent, expected_calls) in
+			[(relay_parent, 1), (relay_parent, 1), (other_relay_parent, 2)]
+		{
+			let (tx, rx) = oneshot::channel();
+			ctx_handle
+				.send(FromOrchestra::Communication {
+					msg: RuntimeApiMessage::Request(relay_parent, Request::AvailabilityCores(tx)),
+				})
+				.await;
+
+			assert_eq!(rx.await.unwrap().unwrap(), subsystem_client.availability_cores);
+			assert_eq!(
+				subsystem_client.availability_cores_calls.load(Ordering::SeqCst),
+				expected_calls
+			);
+		}
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
+
 #[test]
 fn requests_babe_epoch() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
@@ -1007,7 +2268,7 @@ fn requests_babe_epoch() {
 	subsystem_client.babe_epoch = Some(epoch.clone());
 	let subsystem_client = Arc::new(subsystem_client);
 	let relay_parent = [1; 32].into();
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let subsystem =
 		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
@@ -1031,7 +2292,7 @@ fn requests_babe_epoch() {
 #[test]
 fn requests_submit_pvf_check_statement() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 	let subsystem_client = Arc::new(MockSubsystemClient::default());
 
 	let subsystem =
@@ -1091,7 +2352,7 @@ fn requests_submit_pvf_check_statement() {
 #[test]
 fn requests_pvfs_require_precheck() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 
 	let subsystem_client = Arc::new({
 		let mut subsystem_client = MockSubsystemClient::default();
@@ -1127,7 +2388,7 @@ fn requests_validation_code_hash() {
 	let relay_parent = [1; 32].into();
 	let para_a = ParaId::from(5_u32);
 	let para_b = ParaId::from(6_u32);
-	let spawner = sp_core::testing::TaskExecutor::new();
+	let spawner = TaskExecutor::new();
 	let validation_code_hash = dummy_validation_code().hash();
 
 	let mut subsystem_client = MockSubsystemClient::default();
@@ -1168,3 +2429,34 @@ fn requests_validation_code_hash() {
 
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
+
+#[cfg(feature = "testing")]
+#[test]
+fn async_backing_params_can_be_overridden() {
+	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
+	// The mock client doesn't support the request, the runtime must not be queried.
+	let subsystem_client = Arc::new(MockSubsystemClient::default());
+	let relay_parent = [1; 32].into();
+	let spawner = TaskExecutor::new();
+	let params =
+		async_backing::AsyncBackingParams { max_candidate_depth: 3, allowed_ancestry_len: 2 };
+
+	let subsystem = RuntimeApiSubsystem::new(subsystem_client, Metrics(None), SpawnGlue(spawner))
+		.with_async_backing_params(params);
+	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
+	let test_task = async move {
+		let (tx, rx) = oneshot::channel();
+
+		ctx_handle
+			.send(FromOrchestra::Communication {
+				msg: RuntimeApiMessage::Request(relay_parent, Request::AsyncBackingParams(tx)),
+			})
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), params);
+
+		ctx_handle.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	futures::executor::block_on(future::join(subsystem_task, test_task));
+}
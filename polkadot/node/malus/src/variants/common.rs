@@ -238,8 +238,9 @@ where
 						pov,
 						executor_params,
 						exec_kind,
+						exec_timeout,
+						memory_limit_override,
 						response_sender,
-						..
 					},
 			} => {
 				match self.fake_validation {
@@ -254,6 +255,8 @@ where
 									pov,
 									executor_params,
 									exec_kind,
+									exec_timeout,
+									memory_limit_override,
 									response_sender,
 								},
 							})
@@ -292,6 +295,8 @@ where
 										pov,
 										executor_params,
 										exec_kind,
+										exec_timeout,
+										memory_limit_override,
 										response_sender,
 									},
 								})
@@ -332,6 +337,8 @@ where
 										pov,
 										executor_params,
 										exec_kind,
+										exec_timeout,
+										memory_limit_override,
 										response_sender,
 									},
 								})
@@ -347,6 +354,8 @@ where
 							pov,
 							executor_params,
 							exec_kind,
+							exec_timeout,
+							memory_limit_override,
 							response_sender,
 						},
 					}),
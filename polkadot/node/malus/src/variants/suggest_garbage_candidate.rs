@@ -73,7 +73,7 @@ where
 	fn intercept_incoming(
 		&self,
 		subsystem_sender: &mut Sender,
-		msg: FromOrchestra<Self::Message>,
+		mut msg: FromOrchestra<Self::Message>,
 	) -> Option<FromOrchestra<Self::Message>> {
 		match msg {
 			FromOrchestra::Communication {
@@ -83,6 +83,7 @@ where
 						ref candidate,
 						ref validation_data,
 						ref _pov,
+						ref mut result_tx,
 					),
 			} => {
 				gum::debug!(
@@ -250,6 +251,7 @@ where
 							malicious_candidate,
 							validation_data,
 							pov,
+							result_tx.take(),
 						),
 					};
 
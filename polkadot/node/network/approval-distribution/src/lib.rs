@@ -67,7 +67,7 @@ use polkadot_primitives::{
 };
 use rand::{CryptoRng, Rng, SeedableRng};
 use std::{
-	collections::{hash_map, BTreeMap, HashMap, HashSet, VecDeque},
+	collections::{btree_map, hash_map, BTreeMap, HashMap, HashSet, VecDeque},
 	sync::Arc,
 	time::Duration,
 };
@@ -1320,6 +1320,25 @@ impl State {
 		self.enable_aggression(network_sender, Resend::No, metrics).await;
 	}
 
+	/// Drop all the state tracked for a reverted block. Other blocks are left untouched, even if
+	/// they contain the same candidates.
+	fn purge_block(&mut self, hash: Hash) {
+		self.pending_known.remove(&hash);
+
+		let Some(block_entry) = self.blocks.remove(&hash) else { return };
+		self.topologies.dec_session_refs(block_entry.session);
+		self.recent_outdated_blocks.note_outdated(hash);
+
+		if let btree_map::Entry::Occupied(mut entry) =
+			self.blocks_by_number.entry(block_entry.number)
+		{
+			entry.get_mut().retain(|h| h != &hash);
+			if entry.get().is_empty() {
+				entry.remove();
+			}
+		}
+	}
+
 	// When finality is lagging as a last resort nodes start sending the messages they have
 	// multiples times. This means it is safe to accept duplicate messages without punishing the
 	// peer and reduce the reputation and can end up banning the Peer, which in turn will create
@@ -2123,6 +2142,30 @@ impl State {
 		all_sigs
 	}
 
+	/// Retrieve all assignments known for the given relay block, one entry per claimed candidate.
+	fn get_assignments_for_block(&self, hash: Hash) -> Vec<(ValidatorIndex, CandidateIndex)> {
+		let Some(block_entry) = self.blocks.get(&hash) else {
+			gum::debug!(
+				target: LOG_TARGET,
+				?hash,
+				"`get_assignments_for_block`: could not find block entry for given hash!"
+			);
+			return Vec::new()
+		};
+
+		let mut assignments = block_entry
+			.approval_entries
+			.keys()
+			.flat_map(|(validator_index, candidate_indices)| {
+				candidate_indices
+					.iter_ones()
+					.map(move |index| (*validator_index, index as CandidateIndex))
+			})
+			.collect_vec();
+		assignments.sort();
+		assignments
+	}
+
 	async fn unify_with_peer(
 		sender: &mut impl overseer::SubsystemSender<NetworkBridgeTxMessage>,
 		metrics: &Metrics,
@@ -2915,6 +2958,19 @@ impl ApprovalDistribution {
 				gum::debug!(target: LOG_TARGET, lag, "Received `ApprovalCheckingLagUpdate`");
 				state.approval_checking_lag = lag;
 			},
+			ApprovalDistributionMessage::GetAssignmentsForBlock(hash, tx) => {
+				let assignments = state.get_assignments_for_block(hash);
+				if let Err(_) = tx.send(assignments) {
+					gum::debug!(
+						target: LOG_TARGET,
+						"Sending back assignments failed, oneshot got closed"
+					);
+				}
+			},
+			ApprovalDistributionMessage::PurgeBlock(hash) => {
+				gum::debug!(target: LOG_TARGET, ?hash, "Received `PurgeBlock`");
+				state.purge_block(hash);
+			},
 		}
 	}
 }
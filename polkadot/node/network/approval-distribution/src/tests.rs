@@ -2411,6 +2411,132 @@ fn update_peer_authority_id() {
 	);
 }
 
+#[test]
+fn get_assignments_for_block_returns_known_assignments() {
+	let parent_hash = Hash::repeat_byte(0xFF);
+	let hash = Hash::repeat_byte(0xAA);
+	let unknown_hash = Hash::repeat_byte(0xCC);
+
+	let _ = test_harness(
+		Arc::new(MockAssignmentCriteria { tranche: Ok(0) }),
+		Arc::new(SystemClock {}),
+		state_without_reputation_delay(),
+		|mut virtual_overseer| async move {
+			let overseer = &mut virtual_overseer;
+
+			// new block `hash` with 2 candidates
+			let meta = BlockApprovalMeta {
+				hash,
+				parent_hash,
+				number: 1,
+				candidates: vec![Default::default(); 2],
+				slot: 1.into(),
+				session: 1,
+				vrf_story: RelayVRFStory(Default::default()),
+			};
+			let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
+			overseer_send(overseer, msg).await;
+
+			// import two assignments locally, one of them claiming both candidates
+			for (validator_index, candidate_indices) in
+				[(ValidatorIndex(0), vec![0 as CandidateIndex, 1]), (ValidatorIndex(2), vec![1])]
+			{
+				let core_bitfield = vec![CoreIndex(0)].try_into().unwrap();
+				let cert = fake_assignment_cert_v2(hash, validator_index, core_bitfield);
+				let candidate_indices: CandidateBitfield = candidate_indices.try_into().unwrap();
+				overseer_send(
+					overseer,
+					ApprovalDistributionMessage::DistributeAssignment(cert, candidate_indices),
+				)
+				.await;
+			}
+
+			let (tx, rx) = oneshot::channel();
+			overseer_send(overseer, ApprovalDistributionMessage::GetAssignmentsForBlock(hash, tx))
+				.await;
+			assert_eq!(
+				rx.await.unwrap(),
+				vec![(ValidatorIndex(0), 0), (ValidatorIndex(0), 1), (ValidatorIndex(2), 1)]
+			);
+
+			let (tx, rx) = oneshot::channel();
+			overseer_send(
+				overseer,
+				ApprovalDistributionMessage::GetAssignmentsForBlock(unknown_hash, tx),
+			)
+			.await;
+			assert!(rx.await.unwrap().is_empty());
+
+			assert!(overseer.recv().timeout(TIMEOUT).await.is_none(), "no message should be sent");
+			virtual_overseer
+		},
+	);
+}
+
+#[test]
+fn purge_block_drops_only_the_reverted_block() {
+	let parent_hash = Hash::repeat_byte(0xFF);
+	let hash_a = Hash::repeat_byte(0xAA);
+	let hash_b = Hash::repeat_byte(0xBB);
+
+	let _ = test_harness(
+		Arc::new(MockAssignmentCriteria { tranche: Ok(0) }),
+		Arc::new(SystemClock {}),
+		state_without_reputation_delay(),
+		|mut virtual_overseer| async move {
+			let overseer = &mut virtual_overseer;
+
+			// two forks at the same height carrying the same candidates
+			let metas = [hash_a, hash_b]
+				.into_iter()
+				.map(|hash| BlockApprovalMeta {
+					hash,
+					parent_hash,
+					number: 1,
+					candidates: vec![Default::default(); 2],
+					slot: 1.into(),
+					session: 1,
+					vrf_story: RelayVRFStory(Default::default()),
+				})
+				.collect();
+			overseer_send(overseer, ApprovalDistributionMessage::NewBlocks(metas)).await;
+
+			for hash in [hash_a, hash_b] {
+				let core_bitfield = vec![CoreIndex(0)].try_into().unwrap();
+				let cert = fake_assignment_cert_v2(hash, ValidatorIndex(0), core_bitfield);
+				let candidate_indices: CandidateBitfield =
+					vec![0 as CandidateIndex, 1].try_into().unwrap();
+				overseer_send(
+					overseer,
+					ApprovalDistributionMessage::DistributeAssignment(cert, candidate_indices),
+				)
+				.await;
+			}
+
+			overseer_send(overseer, ApprovalDistributionMessage::PurgeBlock(hash_a)).await;
+
+			let (tx, rx) = oneshot::channel();
+			overseer_send(
+				overseer,
+				ApprovalDistributionMessage::GetAssignmentsForBlock(hash_a, tx),
+			)
+			.await;
+			assert!(rx.await.unwrap().is_empty());
+
+			let (tx, rx) = oneshot::channel();
+			overseer_send(
+				overseer,
+				ApprovalDistributionMessage::GetAssignmentsForBlock(hash_b, tx),
+			)
+			.await;
+			assert_eq!(rx.await.unwrap(), vec![(ValidatorIndex(0), 0), (ValidatorIndex(0), 1)]);
+
+			assert!(overseer.recv().timeout(TIMEOUT).await.is_none(), "no message should be sent");
+			virtual_overseer
+		},
+	);
+}
+
 /// E.g. if someone copies the keys...
 #[test]
 fn import_remotely_then_locally() {
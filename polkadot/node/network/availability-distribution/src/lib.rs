@@ -193,6 +193,16 @@ impl AvailabilityDistributionSubsystem {
 						&mut warn_freq,
 					)?;
 				},
+				FromOrchestra::Communication {
+					msg: AvailabilityDistributionMessage::FetchPoVs(params),
+				} => {
+					log_error(
+						pov_requester::fetch_povs(&mut ctx, &mut runtime, params, metrics.clone())
+							.await,
+						"pov_requester::fetch_povs",
+						&mut warn_freq,
+					)?;
+				},
 			}
 		}
 	}
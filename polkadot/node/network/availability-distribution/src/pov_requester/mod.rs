@@ -16,7 +16,7 @@
 
 //! PoV requester takes care of requesting PoVs from validators of a backing group.
 
-use futures::{channel::oneshot, future::BoxFuture, FutureExt};
+use futures::{channel::oneshot, future::BoxFuture, FutureExt, StreamExt};
 
 use polkadot_node_network_protocol::request_response::{
 	outgoing::{RequestError, Requests},
@@ -25,8 +25,8 @@ use polkadot_node_network_protocol::request_response::{
 };
 use polkadot_node_primitives::PoV;
 use polkadot_node_subsystem::{
-	messages::{IfDisconnected, NetworkBridgeTxMessage},
-	overseer,
+	messages::{FetchPoVParams, IfDisconnected, NetworkBridgeTxMessage},
+	overseer, SubsystemSender,
 };
 use polkadot_node_subsystem_util::runtime::RuntimeInfo;
 use polkadot_primitives::{
@@ -39,6 +39,9 @@ use crate::{
 	Metrics, LOG_TARGET,
 };
 
+/// The maximum number of `PoV`s of a single batch fetched from the network in parallel.
+const MAX_PARALLEL_POV_FETCHES: usize = 4;
+
 /// Start background worker for taking care of fetching the requested `PoV` from the network.
 #[overseer::contextbounds(AvailabilityDistribution, prefix = self::overseer)]
 pub async fn fetch_pov<Context>(
@@ -79,6 +82,91 @@ pub async fn fetch_pov<Context>(
 	Ok(())
 }
 
+/// Start background worker for taking care of fetching a batch of `PoV`s from the network.
+///
+/// At most `MAX_PARALLEL_POV_FETCHES` requests of the batch are in flight at any time. Entries
+/// with an invalid validator index are skipped, which cancels their senders.
+#[overseer::contextbounds(AvailabilityDistribution, prefix = self::overseer)]
+pub async fn fetch_povs<Context>(
+	ctx: &mut Context,
+	runtime: &mut RuntimeInfo,
+	params: Vec<FetchPoVParams>,
+	metrics: Metrics,
+) -> Result<()> {
+	let mut jobs = Vec::with_capacity(params.len());
+	let mut invalid_validator_index = false;
+	for FetchPoVParams { relay_parent, from_validator, para_id, candidate_hash, pov_hash, tx } in
+		params
+	{
+		let info = &runtime.get_session_info(ctx.sender(), relay_parent).await?.session_info;
+		match info.discovery_keys.get(from_validator.0 as usize) {
+			Some(authority_id) => jobs.push(PoVFetch {
+				para_id,
+				candidate_hash,
+				pov_hash,
+				authority_id: authority_id.clone(),
+				tx,
+			}),
+			None => invalid_validator_index = true,
+		}
+	}
+
+	let sender = ctx.sender().clone();
+	ctx.spawn("pov-batch-fetcher", fetch_povs_job(sender, jobs, metrics).boxed())
+		.map_err(|e| FatalError::SpawnTask(e))?;
+
+	if invalid_validator_index {
+		return Err(JfyiError::InvalidValidatorIndex.into())
+	}
+	Ok(())
+}
+
+/// A single `PoV` fetch of a batch.
+struct PoVFetch {
+	para_id: ParaId,
+	candidate_hash: CandidateHash,
+	pov_hash: Hash,
+	authority_id: AuthorityDiscoveryId,
+	tx: oneshot::Sender<PoV>,
+}
+
+/// Future to be spawned for fetching a batch of `PoV`s, at most `MAX_PARALLEL_POV_FETCHES` at a
+/// time.
+async fn fetch_povs_job<Sender>(sender: Sender, jobs: Vec<PoVFetch>, metrics: Metrics)
+where
+	Sender: SubsystemSender<NetworkBridgeTxMessage>,
+{
+	futures::stream::iter(jobs)
+		.for_each_concurrent(MAX_PARALLEL_POV_FETCHES, |job| {
+			let mut sender = sender.clone();
+			let metrics = metrics.clone();
+			async move {
+				let PoVFetch { para_id, candidate_hash, pov_hash, authority_id, tx } = job;
+				let (req, pending_response) = OutgoingRequest::new(
+					Recipient::Authority(authority_id.clone()),
+					PoVFetchingRequest { candidate_hash },
+				);
+				sender
+					.send_message(NetworkBridgeTxMessage::SendRequests(
+						vec![Requests::PoVFetchingV1(req)],
+						IfDisconnected::ImmediateError,
+					))
+					.await;
+
+				fetch_pov_job(
+					para_id,
+					pov_hash,
+					authority_id,
+					pending_response.boxed(),
+					tx,
+					metrics,
+				)
+				.await
+			}
+		})
+		.await
+}
+
 /// Future to be spawned for taking care of handling reception and sending of PoV.
 async fn fetch_pov_job(
 	para_id: ParaId,
@@ -135,6 +223,7 @@ mod tests {
 		AllMessages, AvailabilityDistributionMessage, RuntimeApiMessage, RuntimeApiRequest,
 	};
 	use polkadot_node_subsystem_test_helpers as test_helpers;
+	use polkadot_node_subsystem_util::TimeoutExt;
 	use polkadot_primitives::{CandidateHash, ExecutorParams, Hash, NodeFeatures, ValidatorIndex};
 	use test_helpers::mock::make_ferdie_keystore;
 
@@ -155,6 +244,121 @@ mod tests {
 		test_run(pov.hash(), pov);
 	}
 
+	#[test]
+	fn fetches_batch_of_povs() {
+		sp_tracing::try_init_simple();
+		let povs: Vec<_> = (0..6u8).map(|i| PoV { block_data: BlockData(vec![i; 8]) }).collect();
+
+		let pool = TaskExecutor::new();
+		let (mut context, mut virtual_overseer) =
+			polkadot_node_subsystem_test_helpers::make_subsystem_context::<
+				AvailabilityDistributionMessage,
+				TaskExecutor,
+			>(pool.clone());
+		let keystore = make_ferdie_keystore();
+		let mut runtime = polkadot_node_subsystem_util::runtime::RuntimeInfo::new(Some(keystore));
+
+		// The last PoV is requested with a wrong hash.
+		let (params, receivers): (Vec<_>, Vec<_>) = povs
+			.iter()
+			.enumerate()
+			.map(|(i, pov)| {
+				let (tx, rx) = oneshot::channel();
+				let params = FetchPoVParams {
+					relay_parent: Hash::default(),
+					from_validator: ValidatorIndex(0),
+					para_id: ParaId::default(),
+					candidate_hash: CandidateHash(Hash::repeat_byte(i as u8)),
+					pov_hash: if i + 1 == povs.len() { Hash::default() } else { pov.hash() },
+					tx,
+				};
+				(params, rx)
+			})
+			.unzip();
+
+		let testee = async {
+			fetch_povs(&mut context, &mut runtime, params, Metrics::new_dummy())
+				.await
+				.expect("Should succeed");
+		};
+
+		let tester = async move {
+			let mut pending = Vec::new();
+			let mut answered = 0;
+			while answered < povs.len() {
+				match virtual_overseer.recv().await {
+					AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+						_,
+						RuntimeApiRequest::SessionIndexForChild(tx),
+					)) => {
+						tx.send(Ok(0)).unwrap();
+					},
+					AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+						_,
+						RuntimeApiRequest::SessionInfo(_, tx),
+					)) => {
+						tx.send(Ok(Some(make_session_info()))).unwrap();
+					},
+					AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+						_,
+						RuntimeApiRequest::SessionExecutorParams(_, tx),
+					)) => {
+						tx.send(Ok(Some(ExecutorParams::default()))).unwrap();
+					},
+					AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+						_,
+						RuntimeApiRequest::NodeFeatures(_, si_tx),
+					)) => {
+						si_tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+					},
+					AllMessages::NetworkBridgeTx(NetworkBridgeTxMessage::SendRequests(
+						mut reqs,
+						_,
+					)) => {
+						let req = assert_matches!(
+							reqs.pop(),
+							Some(Requests::PoVFetchingV1(outgoing)) => {outgoing}
+						);
+						pending.push(req);
+						if pending.len() + answered < povs.len() &&
+							pending.len() < MAX_PARALLEL_POV_FETCHES
+						{
+							continue
+						}
+
+						// No more requests are issued until some of the pending ones are answered.
+						assert!(virtual_overseer
+							.recv()
+							.timeout(std::time::Duration::from_millis(100))
+							.await
+							.is_none());
+						for req in pending.drain(..) {
+							let pov = &povs[req.payload.candidate_hash.0.as_bytes()[0] as usize];
+							req.pending_response
+								.send(Ok((
+									PoVFetchingResponse::PoV(pov.clone()).encode(),
+									ProtocolName::from(""),
+								)))
+								.unwrap();
+							answered += 1;
+						}
+					},
+					msg => gum::debug!(target: LOG_TARGET, msg = ?msg, "Received msg"),
+				}
+			}
+
+			let mut receivers = receivers;
+			let last = receivers.pop().unwrap();
+			for (rx, pov) in receivers.into_iter().zip(povs) {
+				assert_eq!(rx.await, Ok(pov));
+			}
+			assert_eq!(last.await, Err(oneshot::Canceled));
+		};
+		futures::pin_mut!(testee);
+		futures::pin_mut!(tester);
+		executor::block_on(future::join(testee, tester));
+	}
+
 	fn test_run(pov_hash: Hash, pov: PoV) {
 		let pool = TaskExecutor::new();
 		let (mut context, mut virtual_overseer) =
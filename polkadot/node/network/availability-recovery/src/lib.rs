@@ -57,7 +57,10 @@ use polkadot_node_network_protocol::{
 use polkadot_node_primitives::AvailableData;
 use polkadot_node_subsystem::{
 	errors::RecoveryError,
-	messages::{AvailabilityRecoveryMessage, AvailabilityStoreMessage},
+	messages::{
+		AvailabilityRecoveryMessage, AvailabilityStoreMessage,
+		RecoveryStrategy as RequestedRecoveryStrategy,
+	},
 	overseer, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, SpawnedSubsystem,
 	SubsystemContext, SubsystemError,
 };
@@ -205,6 +208,9 @@ fn reconstructed_data_matches_root(
 /// Accumulate all awaiting sides for some particular `AvailableData`.
 struct RecoveryHandle {
 	candidate_hash: CandidateHash,
+	/// Whether the recovery only fetches from the backing group, see
+	/// [`RequestedRecoveryStrategy::BackingGroupOnly`].
+	backing_group_only: bool,
 	remote: RemoteHandle<RecoveryResult>,
 	awaiting: Vec<oneshot::Sender<RecoveryResult>>,
 }
@@ -350,6 +356,7 @@ async fn launch_recovery_task<Context>(
 	response_sender: oneshot::Sender<RecoveryResult>,
 	recovery_strategies: VecDeque<Box<dyn RecoveryStrategy<<Context as SubsystemContext>::Sender>>>,
 	params: RecoveryParams,
+	backing_group_only: bool,
 ) -> Result<()> {
 	let candidate_hash = params.candidate_hash;
 	let recovery_task = RecoveryTask::new(ctx.sender().clone(), params, recovery_strategies);
@@ -358,6 +365,7 @@ async fn launch_recovery_task<Context>(
 
 	state.ongoing_recoveries.push(RecoveryHandle {
 		candidate_hash,
+		backing_group_only,
 		remote: remote_handle,
 		awaiting: vec![response_sender],
 	});
@@ -374,6 +382,7 @@ async fn handle_recover<Context>(
 	receipt: CandidateReceipt,
 	session_index: SessionIndex,
 	backing_group: Option<GroupIndex>,
+	requested_strategy: RequestedRecoveryStrategy,
 	response_sender: oneshot::Sender<RecoveryResult>,
 	metrics: &Metrics,
 	erasure_task_tx: futures::channel::mpsc::Sender<ErasureTask>,
@@ -392,13 +401,22 @@ async fn handle_recover<Context>(
 		return response_sender.send(result).map_err(|_| Error::CanceledResponseSender)
 	}
 
-	if let Some(i) =
-		state.ongoing_recoveries.iter_mut().find(|i| i.candidate_hash == candidate_hash)
-	{
+	let backing_group_only = requested_strategy == RequestedRecoveryStrategy::BackingGroupOnly;
+	// A recovery restricted to the backing group may fail where a wider one would succeed, so it
+	// is only joined by the requests restricted to the backing group as well.
+	if let Some(i) = state.ongoing_recoveries.iter_mut().find(|i| {
+		i.candidate_hash == candidate_hash && (backing_group_only || !i.backing_group_only)
+	}) {
 		i.awaiting.push(response_sender);
 		return Ok(())
 	}
 
+	let backing_group = match requested_strategy {
+		RequestedRecoveryStrategy::FullNetwork => None,
+		RequestedRecoveryStrategy::BackingGroupOnly |
+		RequestedRecoveryStrategy::BackingGroupFirst => backing_group,
+	};
+
 	let session_info_res = state
 		.runtime_info
 		.get_session_info_by_index(ctx.sender(), state.live_block.1, session_index)
@@ -418,6 +436,8 @@ async fn handle_recover<Context>(
 					let mut small_pov_size = true;
 
 					match recovery_strategy_kind {
+						// The backing group is the only source, the PoV size doesn't matter.
+						_ if backing_group_only => {},
 						RecoveryStrategyKind::BackersFirstIfSizeLower(fetch_chunks_threshold) |
 						RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(
 							fetch_chunks_threshold,
@@ -448,6 +468,10 @@ async fn handle_recover<Context>(
 					};
 
 					match (&recovery_strategy_kind, small_pov_size) {
+						_ if backing_group_only =>
+							recovery_strategies.push_back(Box::new(FetchFull::new(
+								FetchFullParams { validators: backing_validators.to_vec() },
+							))),
 						(RecoveryStrategyKind::BackersFirstAlways, _) |
 						(RecoveryStrategyKind::BackersFirstIfSizeLower(_), true) |
 						(
@@ -476,7 +500,7 @@ async fn handle_recover<Context>(
 
 			// We can only attempt systematic recovery if we received the core index of the
 			// candidate and chunk mapping is enabled.
-			if let Some(core_index) = maybe_core_index {
+			if let Some(core_index) = maybe_core_index.filter(|_| !backing_group_only) {
 				if matches!(
 					recovery_strategy_kind,
 					RecoveryStrategyKind::BackersThenSystematicChunks |
@@ -521,9 +545,13 @@ async fn handle_recover<Context>(
 				}
 			}
 
-			recovery_strategies.push_back(Box::new(FetchChunks::new(FetchChunksParams {
-				n_validators: session_info.validators.len(),
-			})));
+			// Without any strategy, e.g. if there is no backing group to fetch from, the recovery
+			// fails as unavailable.
+			if !backing_group_only {
+				recovery_strategies.push_back(Box::new(FetchChunks::new(FetchChunksParams {
+					n_validators: session_info.validators.len(),
+				})));
+			}
 
 			let session_info = session_info.clone();
 
@@ -550,6 +578,7 @@ async fn handle_recover<Context>(
 					chunk_mapping_enabled,
 					erasure_task_tx,
 				},
+				backing_group_only,
 			)
 			.await
 		},
@@ -748,6 +777,7 @@ impl AvailabilityRecoverySubsystem {
 										session_index,
 										maybe_backing_group,
 										maybe_core_index,
+										requested_strategy,
 										response_sender,
 									)
 								} => handle_recover(
@@ -756,6 +786,7 @@ impl AvailabilityRecoverySubsystem {
 										receipt,
 										session_index,
 										maybe_backing_group,
+										requested_strategy,
 										response_sender,
 										&metrics,
 										erasure_task_tx.clone(),
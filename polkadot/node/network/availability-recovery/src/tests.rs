@@ -767,6 +767,7 @@ fn availability_is_recovered_from_chunks_if_no_group_provided(#[case] systematic
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -809,6 +810,7 @@ fn availability_is_recovered_from_chunks_if_no_group_provided(#[case] systematic
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -896,6 +898,7 @@ fn availability_is_recovered_from_chunks_even_if_backing_group_supplied_if_chunk
 				test_state.session_index,
 				Some(GroupIndex(0)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -938,6 +941,7 @@ fn availability_is_recovered_from_chunks_even_if_backing_group_supplied_if_chunk
 				test_state.session_index,
 				Some(GroupIndex(1)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1030,6 +1034,7 @@ fn bad_merkle_path_leads_to_recovery_error(#[case] systematic_recovery: bool) {
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1118,6 +1123,7 @@ fn wrong_chunk_index_leads_to_recovery_error(#[case] systematic_recovery: bool)
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1240,6 +1246,7 @@ fn invalid_erasure_coding_leads_to_invalid_error(#[case] systematic_recovery: bo
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1305,6 +1312,7 @@ fn invalid_pov_hash_leads_to_invalid_error() {
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1359,6 +1367,7 @@ fn fast_path_backing_group_recovers() {
 				test_state.session_index,
 				Some(GroupIndex(0)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1456,6 +1465,7 @@ fn recovers_from_only_chunks_if_pov_large(
 				test_state.session_index,
 				Some(GroupIndex(0)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1509,6 +1519,7 @@ fn recovers_from_only_chunks_if_pov_large(
 				test_state.session_index,
 				Some(GroupIndex(1)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1629,6 +1640,7 @@ fn fast_path_backing_group_recovers_if_pov_small(
 				test_state.session_index,
 				Some(GroupIndex(0)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1719,6 +1731,7 @@ fn no_answers_in_fast_path_causes_chunk_requests(#[case] systematic_recovery: bo
 				test_state.session_index,
 				Some(GroupIndex(0)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1767,6 +1780,86 @@ fn no_answers_in_fast_path_causes_chunk_requests(#[case] systematic_recovery: bo
 	});
 }
 
+#[test]
+fn backing_group_only_recovery_does_not_request_chunks() {
+	let test_state = TestState::default();
+	let req_protocol_names = ReqProtocolNames::new(&GENESIS_HASH, None);
+	let subsystem = with_chunks_only(
+		request_receiver(&req_protocol_names),
+		&req_protocol_names,
+		Metrics::new_dummy(),
+	);
+
+	test_harness(subsystem, |mut virtual_overseer| async move {
+		overseer_signal(
+			&mut virtual_overseer,
+			OverseerSignal::ActiveLeaves(ActiveLeavesUpdate::start_work(new_leaf(
+				test_state.current,
+				1,
+			))),
+		)
+		.await;
+
+		let (tx, rx) = oneshot::channel();
+
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityRecoveryMessage::RecoverAvailableData(
+				test_state.candidate.clone(),
+				test_state.session_index,
+				Some(GroupIndex(0)),
+				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupOnly,
+				tx,
+			),
+		)
+		.await;
+
+		test_state.test_runtime_api_session_info(&mut virtual_overseer).await;
+
+		test_state.test_runtime_api_node_features(&mut virtual_overseer).await;
+
+		let candidate_hash = test_state.candidate.hash();
+
+		test_state.respond_to_available_data_query(&mut virtual_overseer, false).await;
+
+		// The backing group is asked even though the subsystem is configured to fetch chunks only.
+		test_state
+			.test_full_data_requests(
+				&req_protocol_names,
+				candidate_hash,
+				&mut virtual_overseer,
+				|_| Has::No,
+				GroupIndex(0),
+			)
+			.await;
+
+		// No fallback to chunks.
+		assert_eq!(rx.await.unwrap().unwrap_err(), RecoveryError::Unavailable);
+
+		let (tx, rx) = oneshot::channel();
+
+		// Without a backing group there is nothing to fetch from.
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityRecoveryMessage::RecoverAvailableData(
+				test_state.candidate.clone(),
+				test_state.session_index,
+				None,
+				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupOnly,
+				tx,
+			),
+		)
+		.await;
+
+		test_state.respond_to_available_data_query(&mut virtual_overseer, false).await;
+
+		assert_eq!(rx.await.unwrap().unwrap_err(), RecoveryError::Unavailable);
+		virtual_overseer
+	});
+}
+
 #[rstest]
 #[case(true)]
 #[case(false)]
@@ -1806,6 +1899,7 @@ fn task_canceled_when_receivers_dropped(#[case] systematic_recovery: bool) {
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1864,6 +1958,7 @@ fn chunks_retry_until_all_nodes_respond(#[case] systematic_recovery: bool) {
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -1953,6 +2048,7 @@ fn network_bridge_not_returning_responses_wont_stall_retrieval() {
 				test_state.session_index,
 				Some(GroupIndex(0)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2050,6 +2146,7 @@ fn all_not_returning_requests_still_recovers_on_return(#[case] systematic_recove
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2162,6 +2259,7 @@ fn returns_early_if_we_have_the_data(#[case] systematic_recovery: bool) {
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2206,6 +2304,7 @@ fn returns_early_if_present_in_the_subsystem_cache() {
 				test_state.session_index,
 				Some(GroupIndex(0)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2247,6 +2346,7 @@ fn returns_early_if_present_in_the_subsystem_cache() {
 				test_state.session_index,
 				Some(GroupIndex(0)),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2301,6 +2401,7 @@ fn does_not_query_local_validator(#[case] systematic_recovery: bool) {
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2371,6 +2472,7 @@ fn invalid_local_chunk(#[case] systematic_recovery: bool) {
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2460,6 +2562,7 @@ fn systematic_chunks_are_not_requested_again_in_regular_recovery() {
 					test_state.session_index,
 					None,
 					Some(test_state.core_index),
+					RequestedRecoveryStrategy::BackingGroupFirst,
 					tx,
 				),
 			)
@@ -2563,6 +2666,7 @@ fn chunk_indices_are_mapped_to_different_validators(
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2681,6 +2785,7 @@ fn number_of_request_retries_is_bounded(
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2775,6 +2880,7 @@ fn systematic_recovery_retries_from_backers() {
 				test_state.session_index,
 				Some(group_index),
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2881,6 +2987,7 @@ fn test_legacy_network_protocol_with_mapping_disabled(#[case] systematic_recover
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -2957,6 +3064,7 @@ fn test_legacy_network_protocol_with_mapping_enabled(#[case] systematic_recovery
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -3032,6 +3140,7 @@ fn test_systematic_recovery_skipped_if_no_core_index() {
 				test_state.session_index,
 				None,
 				None,
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -3095,6 +3204,7 @@ fn test_systematic_recovery_skipped_if_mapping_disabled() {
 				test_state.session_index,
 				None,
 				Some(test_state.core_index),
+				RequestedRecoveryStrategy::BackingGroupFirst,
 				tx,
 			),
 		)
@@ -91,9 +91,11 @@ pub(crate) struct PeerData {
 }
 
 /// Shared state between incoming and outgoing.
-
+///
+/// The same instance shall be passed to both [`NetworkBridgeRx`] and [`NetworkBridgeTx`], so the
+/// outgoing side knows the protocol versions negotiated with the connected peers.
 #[derive(Default, Clone)]
-pub(crate) struct Shared(Arc<Mutex<SharedInner>>);
+pub struct Shared(Arc<Mutex<SharedInner>>);
 
 #[derive(Default)]
 struct SharedInner {
@@ -100,6 +100,8 @@ impl<N, AD> NetworkBridgeRx<N, AD> {
 	///
 	/// This assumes that the network service has had the notifications protocol for the network
 	/// bridge already registered. See [`peer_sets_info`].
+	///
+	/// The `shared` state must be the same for both [`NetworkBridgeRx`] and [`NetworkBridgeTx`].
 	pub fn new(
 		network_service: N,
 		authority_discovery_service: AD,
@@ -108,10 +110,9 @@ impl<N, AD> NetworkBridgeRx<N, AD> {
 		peerset_protocol_names: PeerSetProtocolNames,
 		mut notification_services: HashMap<PeerSet, Box<dyn NotificationService>>,
 		notification_sinks: Arc<Mutex<HashMap<(PeerSet, PeerId), Box<dyn MessageSink>>>>,
+		shared: Shared,
 		approval_voting_parallel_enabled: bool,
 	) -> Self {
-		let shared = Shared::default();
-
 		let validation_service = notification_services
 			.remove(&PeerSet::Validation)
 			.expect("validation protocol was enabled so `NotificationService` must exist; qed");
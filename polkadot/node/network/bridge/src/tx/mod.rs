@@ -18,7 +18,9 @@
 use super::*;
 
 use polkadot_node_network_protocol::{
-	peer_set::PeerSetProtocolNames, request_response::ReqProtocolNames, Versioned,
+	peer_set::{CollationVersion, PeerSetProtocolNames},
+	request_response::ReqProtocolNames,
+	Versioned,
 };
 
 use polkadot_node_subsystem::{
@@ -28,6 +30,7 @@ use polkadot_node_subsystem::{
 };
 
 use polkadot_node_network_protocol::request_response::Requests;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
 use sc_network::{MessageSink, ReputationChange};
 
 use crate::validator_discovery;
@@ -57,6 +60,7 @@ pub struct NetworkBridgeTx<N, AD> {
 	req_protocol_names: ReqProtocolNames,
 	peerset_protocol_names: PeerSetProtocolNames,
 	notification_sinks: Arc<Mutex<HashMap<(PeerSet, PeerId), Box<dyn MessageSink>>>>,
+	shared: Shared,
 }
 
 impl<N, AD> NetworkBridgeTx<N, AD> {
@@ -65,6 +69,8 @@ impl<N, AD> NetworkBridgeTx<N, AD> {
 	///
 	/// This assumes that the network service has had the notifications protocol for the network
 	/// bridge already registered. See [`peer_sets_info`].
+	///
+	/// The `shared` state must be the same for both [`NetworkBridgeRx`] and [`NetworkBridgeTx`].
 	pub fn new(
 		network_service: N,
 		authority_discovery_service: AD,
@@ -72,6 +78,7 @@ impl<N, AD> NetworkBridgeTx<N, AD> {
 		req_protocol_names: ReqProtocolNames,
 		peerset_protocol_names: PeerSetProtocolNames,
 		notification_sinks: Arc<Mutex<HashMap<(PeerSet, PeerId), Box<dyn MessageSink>>>>,
+		shared: Shared,
 	) -> Self {
 		Self {
 			network_service,
@@ -80,6 +87,7 @@ impl<N, AD> NetworkBridgeTx<N, AD> {
 			req_protocol_names,
 			peerset_protocol_names,
 			notification_sinks,
+			shared,
 		}
 	}
 }
@@ -91,7 +99,7 @@ where
 	AD: validator_discovery::AuthorityDiscovery + Clone + Sync,
 {
 	fn start(self, ctx: Context) -> SpawnedSubsystem {
-		let future = run_network_out(self, ctx)
+		let future = run_network_out(self, ctx, rand::rngs::StdRng::from_entropy())
 			.map_err(|e| SubsystemError::with_origin("network-bridge", e))
 			.boxed();
 		SpawnedSubsystem { name: "network-bridge-tx-subsystem", future }
@@ -99,7 +107,7 @@ where
 }
 
 #[overseer::contextbounds(NetworkBridgeTx, prefix = self::overseer)]
-async fn handle_subsystem_messages<Context, N, AD>(
+async fn handle_subsystem_messages<Context, N, AD, R>(
 	mut ctx: Context,
 	mut network_service: N,
 	mut authority_discovery_service: AD,
@@ -107,10 +115,13 @@ async fn handle_subsystem_messages<Context, N, AD>(
 	req_protocol_names: ReqProtocolNames,
 	peerset_protocol_names: PeerSetProtocolNames,
 	notification_sinks: Arc<Mutex<HashMap<(PeerSet, PeerId), Box<dyn MessageSink>>>>,
+	shared: Shared,
+	mut rng: R,
 ) -> Result<(), Error>
 where
 	N: Network,
 	AD: validator_discovery::AuthorityDiscovery + Clone,
+	R: Rng + Send,
 {
 	let mut validator_discovery =
 		validator_discovery::Service::<N, AD>::new(peerset_protocol_names.clone());
@@ -131,6 +142,8 @@ where
 						&req_protocol_names,
 						&peerset_protocol_names,
 						&notification_sinks,
+						&shared,
+						&mut rng,
 					)
 					.await;
 			},
@@ -149,6 +162,8 @@ async fn handle_incoming_subsystem_communication<Context, N, AD>(
 	req_protocol_names: &ReqProtocolNames,
 	peerset_protocol_names: &PeerSetProtocolNames,
 	notification_sinks: &Arc<Mutex<HashMap<(PeerSet, PeerId), Box<dyn MessageSink>>>>,
+	shared: &Shared,
+	rng: &mut (impl Rng + Send),
 ) -> (N, AD)
 where
 	N: Network,
@@ -292,6 +307,35 @@ where
 				}
 			}
 		},
+		NetworkBridgeTxMessage::SendCollationMessageToRandomPeers { message, count } => {
+			let version = match message {
+				Versioned::V1(_) => CollationVersion::V1,
+				Versioned::V2(_) | Versioned::V3(_) => CollationVersion::V2,
+			};
+			let peers = random_collation_peers(shared, notification_sinks, version, count, rng);
+
+			gum::trace!(
+				target: LOG_TARGET,
+				action = "SendCollationMessageToRandomPeers",
+				?peers,
+				requested = count,
+			);
+
+			match message {
+				Versioned::V1(msg) => send_collation_message_v1(
+					peers,
+					WireMessage::ProtocolMessage(msg),
+					&metrics,
+					notification_sinks,
+				),
+				Versioned::V2(msg) | Versioned::V3(msg) => send_collation_message_v2(
+					peers,
+					WireMessage::ProtocolMessage(msg),
+					&metrics,
+					notification_sinks,
+				),
+			}
+		},
 		NetworkBridgeTxMessage::SendRequests(reqs, if_disconnected) => {
 			gum::trace!(
 				target: LOG_TARGET,
@@ -353,6 +397,41 @@ where
 
 			return (network_service, ads)
 		},
+		NetworkBridgeTxMessage::ConnectToValidatorsWithPriority {
+			validator_ids,
+			priority,
+			peer_set,
+			failed,
+		} => {
+			gum::trace!(
+				target: LOG_TARGET,
+				action = "ConnectToValidatorsWithPriority",
+				peer_set = ?peer_set,
+				ids = ?validator_ids,
+				?priority,
+				"Received a prioritized validator connection request",
+			);
+
+			let desired = validator_ids
+				.iter()
+				.chain(priority.iter())
+				.collect::<std::collections::HashSet<_>>()
+				.len();
+			metrics.note_desired_peer_count(peer_set, desired);
+
+			let (network_service, ads) = validator_discovery
+				.on_request_with_priority(
+					validator_ids,
+					priority,
+					peer_set,
+					failed,
+					network_service,
+					authority_discovery_service,
+				)
+				.await;
+
+			return (network_service, ads)
+		},
 		NetworkBridgeTxMessage::ConnectToResolvedValidators { validator_addrs, peer_set } => {
 			gum::trace!(
 				target: LOG_TARGET,
@@ -390,14 +469,43 @@ where
 	(network_service, authority_discovery_service)
 }
 
+/// Samples up to `count` peers connected on the collation peer-set with the given protocol
+/// version, so they are able to decode the messages of that version.
+///
+/// The peers are sorted before sampling, so the outcome only depends on the given `rng`.
+fn random_collation_peers(
+	shared: &Shared,
+	notification_sinks: &Arc<Mutex<HashMap<(PeerSet, PeerId), Box<dyn MessageSink>>>>,
+	version: CollationVersion,
+	count: usize,
+	rng: &mut impl Rng,
+) -> Vec<PeerId> {
+	let version = ProtocolVersion::from(version);
+	let mut peers = shared
+		.0
+		.lock()
+		.collation_peers
+		.iter()
+		.filter(|(_, peer_data)| peer_data.version == version)
+		.map(|(peer, _)| *peer)
+		.collect::<Vec<_>>();
+	let notification_sinks = notification_sinks.lock();
+	peers.retain(|peer| notification_sinks.contains_key(&(PeerSet::Collation, *peer)));
+	peers.sort();
+
+	peers.choose_multiple(rng, count).copied().collect()
+}
+
 #[overseer::contextbounds(NetworkBridgeTx, prefix = self::overseer)]
-async fn run_network_out<N, AD, Context>(
+async fn run_network_out<N, AD, Context, R>(
 	bridge: NetworkBridgeTx<N, AD>,
 	ctx: Context,
+	rng: R,
 ) -> Result<(), Error>
 where
 	N: Network,
 	AD: validator_discovery::AuthorityDiscovery + Clone + Sync,
+	R: Rng + Send,
 {
 	let NetworkBridgeTx {
 		network_service,
@@ -406,6 +514,7 @@ where
 		req_protocol_names,
 		peerset_protocol_names,
 		notification_sinks,
+		shared,
 	} = bridge;
 
 	handle_subsystem_messages(
@@ -416,6 +525,8 @@ where
 		req_protocol_names,
 		peerset_protocol_names,
 		notification_sinks,
+		shared,
+		rng,
 	)
 	.await?;
 
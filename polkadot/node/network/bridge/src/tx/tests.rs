@@ -15,6 +15,7 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
+use assert_matches::assert_matches;
 use futures::executor;
 use polkadot_node_subsystem_util::TimeoutExt;
 
@@ -71,6 +72,7 @@ struct TestNetworkHandle {
 	_peerset_protocol_names: PeerSetProtocolNames,
 	notification_sinks: Arc<Mutex<HashMap<(PeerSet, PeerId), Box<dyn MessageSink>>>>,
 	action_tx: Arc<Mutex<metered::UnboundedMeteredSender<NetworkAction>>>,
+	shared: Shared,
 }
 
 struct TestMessageSink {
@@ -117,10 +119,12 @@ fn new_test_network(
 	TestNetworkHandle,
 	TestAuthorityDiscovery,
 	Arc<Mutex<HashMap<(PeerSet, PeerId), Box<dyn MessageSink>>>>,
+	Shared,
 ) {
 	let (action_tx, action_rx) = metered::unbounded();
 	let notification_sinks = Arc::new(Mutex::new(HashMap::new()));
 	let action_tx = Arc::new(Mutex::new(action_tx));
+	let shared = Shared::default();
 
 	(
 		TestNetwork {
@@ -132,9 +136,11 @@ fn new_test_network(
 			_peerset_protocol_names: peerset_protocol_names,
 			action_tx,
 			notification_sinks: notification_sinks.clone(),
+			shared: shared.clone(),
 		},
 		TestAuthorityDiscovery,
 		notification_sinks,
+		shared,
 	)
 }
 
@@ -223,10 +229,19 @@ impl TestNetworkHandle {
 	async fn connect_peer(
 		&mut self,
 		peer: PeerId,
-		_protocol_version: ValidationVersion,
+		protocol_version: ValidationVersion,
 		peer_set: PeerSet,
 		_role: ObservedRole,
 	) {
+		let peer_data = PeerData { view: View::default(), version: protocol_version.into() };
+		{
+			let mut shared = self.shared.0.lock();
+			match peer_set {
+				PeerSet::Validation => shared.validation_peers.insert(peer, peer_data),
+				PeerSet::Collation => shared.collation_peers.insert(peer, peer_data),
+			};
+		}
+
 		self.notification_sinks.lock().insert(
 			(peer_set, peer),
 			Box::new(TestMessageSink::new(peer, peer_set, self.action_tx.clone())),
@@ -248,7 +263,7 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(test: impl FnOnce(TestHarne
 	let peerset_protocol_names = PeerSetProtocolNames::new(genesis_hash, fork_id);
 
 	let pool = sp_core::testing::TaskExecutor::new();
-	let (network, network_handle, discovery, network_notification_sinks) =
+	let (network, network_handle, discovery, network_notification_sinks, shared) =
 		new_test_network(peerset_protocol_names.clone());
 
 	let (context, virtual_overseer) =
@@ -261,11 +276,13 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(test: impl FnOnce(TestHarne
 		req_protocol_names,
 		peerset_protocol_names,
 		network_notification_sinks,
+		shared,
 	);
 
-	let network_bridge_out_fut = run_network_out(bridge_out, context)
-		.map_err(|e| panic!("bridge-out subsystem execution failed {:?}", e))
-		.map(|_| ());
+	let network_bridge_out_fut =
+		run_network_out(bridge_out, context, rand::rngs::StdRng::seed_from_u64(0))
+			.map_err(|e| panic!("bridge-out subsystem execution failed {:?}", e))
+			.map(|_| ());
 
 	let test_fut = test(TestHarness { network_handle, virtual_overseer });
 
@@ -479,3 +496,158 @@ fn network_protocol_versioning_send() {
 		virtual_overseer
 	});
 }
+
+#[test]
+fn send_collation_message_to_random_peers() {
+	test_harness(|test_harness| async move {
+		let TestHarness { mut network_handle, mut virtual_overseer } = test_harness;
+
+		let collation_peers: HashSet<_> = (0..4).map(|_| PeerId::random()).collect();
+		for peer in &collation_peers {
+			network_handle
+				.connect_peer(*peer, ValidationVersion::V2, PeerSet::Collation, ObservedRole::Full)
+				.timeout(TIMEOUT)
+				.await
+				.expect("Timeout does not occur");
+		}
+		network_handle
+			.connect_peer(
+				PeerId::random(),
+				ValidationVersion::V2,
+				PeerSet::Validation,
+				ObservedRole::Full,
+			)
+			.timeout(TIMEOUT)
+			.await
+			.expect("Timeout does not occur");
+
+		let msg = protocol_v2::CollationProtocol::CollatorProtocol(
+			protocol_v2::CollatorProtocolMessage::Declare(
+				Sr25519Keyring::Alice.public().into(),
+				0_u32.into(),
+				dummy_collator_signature(),
+			),
+		);
+		let encoded = WireMessage::ProtocolMessage(msg.clone()).encode();
+
+		// Requesting more peers than connected sends the message to all the collation peers.
+		for (count, expected) in [(2, 2), (10, collation_peers.len())] {
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: NetworkBridgeTxMessage::SendCollationMessageToRandomPeers {
+						message: Versioned::V2(msg.clone()),
+						count,
+					},
+				})
+				.await;
+
+			let mut receivers = HashSet::new();
+			for _ in 0..expected {
+				assert_matches!(
+					network_handle
+						.next_network_action()
+						.timeout(TIMEOUT)
+						.await
+						.expect("Timeout does not occur"),
+					NetworkAction::WriteNotification(peer, PeerSet::Collation, message) => {
+						assert_eq!(message, encoded);
+						assert!(collation_peers.contains(&peer));
+						assert!(receivers.insert(peer));
+					}
+				);
+			}
+
+			// No other peer is sent the message.
+			assert!(network_handle
+				.next_network_action()
+				.timeout(std::time::Duration::from_millis(100))
+				.await
+				.is_none());
+		}
+
+		virtual_overseer
+	});
+}
+
+#[test]
+fn send_collation_message_to_random_peers_respects_versions() {
+	test_harness(|test_harness| async move {
+		let TestHarness { mut network_handle, mut virtual_overseer } = test_harness;
+
+		let v1_peers: HashSet<_> = (0..2).map(|_| PeerId::random()).collect();
+		let v2_peers: HashSet<_> = (0..2).map(|_| PeerId::random()).collect();
+		for (peers, version) in
+			[(&v1_peers, ValidationVersion::V1), (&v2_peers, ValidationVersion::V2)]
+		{
+			for peer in peers {
+				network_handle
+					.connect_peer(*peer, version, PeerSet::Collation, ObservedRole::Full)
+					.timeout(TIMEOUT)
+					.await
+					.expect("Timeout does not occur");
+			}
+		}
+
+		let msg_v1 = protocol_v1::CollationProtocol::CollatorProtocol(
+			protocol_v1::CollatorProtocolMessage::Declare(
+				Sr25519Keyring::Alice.public().into(),
+				0_u32.into(),
+				dummy_collator_signature(),
+			),
+		);
+		let msg_v2 = protocol_v2::CollationProtocol::CollatorProtocol(
+			protocol_v2::CollatorProtocolMessage::Declare(
+				Sr25519Keyring::Alice.public().into(),
+				0_u32.into(),
+				dummy_collator_signature(),
+			),
+		);
+
+		// Each message is only sent to the peers speaking its version, however many are requested.
+		for (message, encoded, expected_peers) in [
+			(
+				Versioned::V1(msg_v1.clone()),
+				WireMessage::ProtocolMessage(msg_v1).encode(),
+				&v1_peers,
+			),
+			(
+				Versioned::V2(msg_v2.clone()),
+				WireMessage::ProtocolMessage(msg_v2).encode(),
+				&v2_peers,
+			),
+		] {
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: NetworkBridgeTxMessage::SendCollationMessageToRandomPeers {
+						message,
+						count: 10,
+					},
+				})
+				.await;
+
+			let mut receivers = HashSet::new();
+			for _ in 0..expected_peers.len() {
+				assert_matches!(
+					network_handle
+						.next_network_action()
+						.timeout(TIMEOUT)
+						.await
+						.expect("Timeout does not occur"),
+					NetworkAction::WriteNotification(peer, PeerSet::Collation, message) => {
+						assert_eq!(message, encoded);
+						assert!(receivers.insert(peer));
+					}
+				);
+			}
+			assert_eq!(&receivers, expected_peers);
+
+			assert!(network_handle
+				.next_network_action()
+				.timeout(std::time::Duration::from_millis(100))
+				.await
+				.is_none());
+		}
+
+		virtual_overseer
+	});
+}
@@ -147,25 +147,80 @@ impl<N: Network, AD: AuthorityDiscovery> Service<N, AD> {
 		mut authority_discovery_service: AD,
 	) -> (N, AD) {
 		// collect multiaddress of validators
-		let mut failed_to_resolve: usize = 0;
-		let mut newly_requested = HashSet::new();
 		let requested = validator_ids.len();
-		for authority in validator_ids.into_iter() {
-			let result = authority_discovery_service
-				.get_addresses_by_authority_id(authority.clone())
-				.await;
-			if let Some(addresses) = result {
-				newly_requested.extend(addresses);
-			} else {
-				failed_to_resolve += 1;
-				gum::debug!(
-					target: LOG_TARGET,
-					"Authority Discovery couldn't resolve {:?}",
-					authority
-				);
+		let (newly_requested, failed_to_resolve) =
+			resolve_addresses(&mut authority_discovery_service, validator_ids).await;
+
+		gum::debug!(
+			target: LOG_TARGET,
+			?peer_set,
+			?requested,
+			?failed_to_resolve,
+			"New ConnectToValidators request",
+		);
+
+		let r = self.on_resolved_request(newly_requested, peer_set, network_service).await;
+
+		let _ = failed.send(failed_to_resolve);
+
+		(r, authority_discovery_service)
+	}
+
+	/// Like [`Self::on_request`], but the validators in `priority` are resolved and added to the
+	/// reserved set before the rest of `validator_ids` is looked at.
+	///
+	/// Prioritized validators are always part of the resulting peer set, even if they are missing
+	/// from `validator_ids`. The number of ids which failed to resolve is reported over `failed`
+	/// exactly as for [`Self::on_request`].
+	pub async fn on_request_with_priority(
+		&mut self,
+		validator_ids: Vec<AuthorityDiscoveryId>,
+		priority: Vec<AuthorityDiscoveryId>,
+		peer_set: PeerSet,
+		failed: oneshot::Sender<usize>,
+		mut network_service: N,
+		mut authority_discovery_service: AD,
+	) -> (N, AD) {
+		let priority: Vec<AuthorityDiscoveryId> =
+			priority.into_iter().collect::<HashSet<_>>().into_iter().collect();
+		let prioritized = priority.len();
+		let (mut newly_requested, mut failed_to_resolve) =
+			resolve_addresses(&mut authority_discovery_service, priority.clone()).await;
+
+		gum::debug!(
+			target: LOG_TARGET,
+			?peer_set,
+			?prioritized,
+			?failed_to_resolve,
+			"New prioritized ConnectToValidators request",
+		);
+
+		// Reserve slots for the prioritized validators right away, so that connecting to them
+		// does not have to wait for the whole set to be resolved.
+		if !newly_requested.is_empty() {
+			self.state[peer_set]
+				.previously_requested
+				.extend(extract_peer_ids(newly_requested.iter().cloned()));
+
+			if let Err(e) = network_service
+				.add_peers_to_reserved_set(
+					self.peerset_protocol_names.get_main_name(peer_set),
+					newly_requested.clone(),
+				)
+				.await
+			{
+				gum::warn!(target: LOG_TARGET, err = ?e, "AuthorityDiscoveryService returned an invalid multiaddress");
 			}
 		}
 
+		let remaining: Vec<AuthorityDiscoveryId> =
+			validator_ids.into_iter().filter(|id| !priority.contains(id)).collect();
+		let requested = prioritized + remaining.len();
+		let (remaining_addrs, remaining_failed) =
+			resolve_addresses(&mut authority_discovery_service, remaining).await;
+		newly_requested.extend(remaining_addrs);
+		failed_to_resolve += remaining_failed;
+
 		gum::debug!(
 			target: LOG_TARGET,
 			?peer_set,
@@ -182,6 +237,28 @@ impl<N: Network, AD: AuthorityDiscovery> Service<N, AD> {
 	}
 }
 
+/// Resolve the addresses of the given validators, returning them along with the number of
+/// validators which couldn't be resolved.
+async fn resolve_addresses<AD: AuthorityDiscovery>(
+	authority_discovery_service: &mut AD,
+	validator_ids: Vec<AuthorityDiscoveryId>,
+) -> (HashSet<Multiaddr>, usize) {
+	let mut failed_to_resolve: usize = 0;
+	let mut addrs = HashSet::new();
+	for authority in validator_ids.into_iter() {
+		let result = authority_discovery_service
+			.get_addresses_by_authority_id(authority.clone())
+			.await;
+		if let Some(addresses) = result {
+			addrs.extend(addresses);
+		} else {
+			failed_to_resolve += 1;
+			gum::debug!(target: LOG_TARGET, "Authority Discovery couldn't resolve {:?}", authority);
+		}
+	}
+	(addrs, failed_to_resolve)
+}
+
 fn extract_peer_ids(multiaddr: impl Iterator<Item = Multiaddr>) -> HashSet<PeerId> {
 	multiaddr
 		.filter_map(|mut addr| match addr.pop() {
@@ -221,6 +298,7 @@ mod tests {
 	#[derive(Default, Clone)]
 	struct TestNetwork {
 		peers_set: HashSet<PeerId>,
+		added: Vec<HashSet<PeerId>>,
 	}
 
 	#[derive(Default, Clone, Debug)]
@@ -265,7 +343,9 @@ mod tests {
 			_protocol: ProtocolName,
 			multiaddresses: HashSet<Multiaddr>,
 		) -> Result<(), String> {
-			self.peers_set.extend(extract_peer_ids(multiaddresses.into_iter()));
+			let peers = extract_peer_ids(multiaddresses.into_iter());
+			self.peers_set.extend(peers.iter().cloned());
+			self.added.push(peers);
 			Ok(())
 		}
 
@@ -410,4 +490,49 @@ mod tests {
 			assert_eq!(failed, 1);
 		});
 	}
+
+	#[test]
+	fn prioritized_validators_are_reserved_first() {
+		let mut service = new_service();
+
+		let (ns, ads) = new_network();
+
+		let authority_ids = known_authorities();
+		let unknown: AuthorityDiscoveryId = Sr25519Keyring::Ferdie.public().into();
+
+		futures::executor::block_on(async move {
+			let (failed, failed_rx) = oneshot::channel();
+			let (ns, ads) = service
+				.on_request_with_priority(
+					vec![authority_ids[0].clone(), authority_ids[1].clone(), unknown.clone()],
+					vec![authority_ids[1].clone(), unknown],
+					PeerSet::Validation,
+					failed,
+					ns,
+					ads,
+				)
+				.await;
+
+			let peer_of = |id: &AuthorityDiscoveryId| {
+				extract_peer_ids(ads.by_authority_id.get(id).unwrap().clone().into_iter())
+					.into_iter()
+					.next()
+					.unwrap()
+			};
+
+			// Only the prioritized validator was added ahead of the full set.
+			assert_eq!(ns.added, vec![HashSet::from([peer_of(&authority_ids[1])])]);
+			assert_eq!(
+				ns.peers_set,
+				HashSet::from([peer_of(&authority_ids[0]), peer_of(&authority_ids[1])])
+			);
+
+			let state = &service.state[PeerSet::Validation];
+			assert_eq!(state.previously_requested, ns.peers_set);
+
+			// The unknown authority is only counted once.
+			let failed = failed_rx.await.unwrap();
+			assert_eq!(failed, 1);
+		});
+	}
 }
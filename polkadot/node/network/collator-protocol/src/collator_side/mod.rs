@@ -717,6 +717,24 @@ async fn process_msg<Context>(
 			state.collating_on = Some(id);
 			state.implicit_view = Some(ImplicitView::new(Some(id)));
 		},
+		StopCollating => {
+			let Some(para_id) = state.collating_on.take() else {
+				gum::debug!(target: LOG_TARGET, "StopCollating message while not collating on any");
+				return Ok(())
+			};
+			gum::info!(target: LOG_TARGET, %para_id, "Stopped collating");
+
+			state.implicit_view = None;
+			state.per_relay_parent.clear();
+			state.collation_result_senders.clear();
+			state.waiting_collation_fetches.clear();
+			state.reconnect_timeout = Fuse::terminated();
+			state.validator_groups_buf =
+				ValidatorGroupsBuffer::with_capacity(VALIDATORS_BUFFER_CAPACITY);
+
+			// The buffer is empty now, so this releases the connections to validators.
+			connect_to_validators(ctx, &state.validator_groups_buf).await;
+		},
 		DistributeCollation {
 			candidate_receipt,
 			parent_head_data_hash,
@@ -774,7 +792,10 @@ async fn process_msg<Context>(
 				);
 			}
 		},
-		msg @ (Invalid(..) | Seconded(..)) => {
+		msg @ (Invalid(..) |
+		Seconded(..) |
+		SetReservedCollators(..) |
+		NotifyCollationFetchTimeout(..)) => {
 			gum::warn!(
 				target: LOG_TARGET,
 				"{:?} message is not expected on the collator side of the protocol",
@@ -1493,3 +1493,49 @@ fn connect_to_buffered_groups() {
 		},
 	);
 }
+
+#[test]
+fn stop_collating_releases_validator_connections() {
+	let test_state = TestState::default();
+	let local_peer_id = test_state.local_peer_id;
+	let collator_pair = test_state.collator_pair.clone();
+
+	test_harness(
+		local_peer_id,
+		collator_pair,
+		ReputationAggregator::new(|_| true),
+		|test_harness| async move {
+			let mut virtual_overseer = test_harness.virtual_overseer;
+
+			overseer_send(
+				&mut virtual_overseer,
+				CollatorProtocolMessage::CollateOn(test_state.para_id),
+			)
+			.await;
+
+			update_view(&test_state, &mut virtual_overseer, vec![(test_state.relay_parent, 10)], 1)
+				.await;
+
+			distribute_collation(&mut virtual_overseer, &test_state, test_state.relay_parent, true)
+				.await;
+
+			overseer_send(&mut virtual_overseer, CollatorProtocolMessage::StopCollating).await;
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::NetworkBridgeTx(
+					NetworkBridgeTxMessage::ConnectToValidators { validator_ids, peer_set, .. }
+				) => {
+					assert!(validator_ids.is_empty());
+					assert_eq!(peer_set, PeerSet::Collation);
+				}
+			);
+
+			// Nothing to stop anymore.
+			overseer_send(&mut virtual_overseer, CollatorProtocolMessage::StopCollating).await;
+			assert!(overseer_recv_with_timeout(&mut virtual_overseer, TIMEOUT).await.is_none());
+
+			test_harness
+		},
+	);
+}
@@ -57,6 +57,13 @@ impl Metrics {
 		self.0.as_ref().map(|metrics| metrics.collation_request_duration.start_timer())
 	}
 
+	/// Note a collation fetch which timed out.
+	pub fn on_collation_fetch_timeout(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.collation_fetch_timeouts.inc();
+		}
+	}
+
 	/// Provide a timer for `request_unblocked_collations` which observes on drop.
 	pub fn time_request_unblocked_collations(
 		&self,
@@ -75,6 +82,7 @@ struct MetricsInner {
 	collator_peer_count: prometheus::Gauge<prometheus::U64>,
 	collation_request_duration: prometheus::Histogram,
 	request_unblocked_collations: prometheus::Histogram,
+	collation_fetch_timeouts: prometheus::Counter<prometheus::U64>,
 }
 
 impl metrics::Metrics for Metrics {
@@ -135,6 +143,13 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			collation_fetch_timeouts: prometheus::register(
+				prometheus::Counter::new(
+					"polkadot_parachain_collator_protocol_validator_collation_fetch_timeouts_total",
+					"Number of collation fetches which timed out.",
+				)?,
+				registry,
+			)?,
 		};
 
 		Ok(Metrics(Some(metrics)))
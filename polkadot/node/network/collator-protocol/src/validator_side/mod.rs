@@ -93,6 +93,8 @@ const COST_WRONG_PARA: Rep = Rep::Malicious("A collator provided a collation for
 const COST_PROTOCOL_MISUSE: Rep =
 	Rep::Malicious("A collator advertising a collation for an async backing relay parent using V1");
 const COST_UNNEEDED_COLLATOR: Rep = Rep::CostMinor("An unneeded collator connected");
+const COST_SLOW_COLLATOR: Rep =
+	Rep::CostMinor("A collator was reported slow to provide a collation by another subsystem");
 const BENEFIT_NOTIFY_GOOD: Rep =
 	Rep::BenefitMinor("A collator was noted good by another subsystem");
 
@@ -409,9 +411,22 @@ struct State {
 
 	/// Aggregated reputation change
 	reputation: ReputationAggregator,
+
+	/// Collators we exclusively accept collations from, per para.
+	///
+	/// Paras without an entry are not restricted.
+	reserved_collators: HashMap<ParaId, HashSet<CollatorId>>,
 }
 
 impl State {
+	/// Returns `true` if the collator is allowed to collate on the given para, according to the
+	/// reserved collators set for it.
+	fn is_collator_allowed(&self, para_id: &ParaId, collator_id: &CollatorId) -> bool {
+		self.reserved_collators
+			.get(para_id)
+			.map_or(true, |collators| collators.contains(collator_id))
+	}
+
 	// Returns the number of seconded and pending collations for a specific `ParaId`. Pending
 	// collations are:
 	// 1. Collations being fetched from a collator.
@@ -835,6 +850,18 @@ async fn process_incoming_peer_message<Context>(
 				return
 			}
 
+			if !state.is_collator_allowed(&para_id, &collator_id) {
+				gum::debug!(
+					target: LOG_TARGET,
+					peer_id = ?origin,
+					?collator_id,
+					?para_id,
+					"Declared collator is not reserved for the para",
+				);
+				disconnect_peer(ctx.sender(), origin).await;
+				return
+			}
+
 			if state.current_assignments.contains_key(&para_id) {
 				gum::debug!(
 					target: LOG_TARGET,
@@ -1477,6 +1504,12 @@ async fn process_msg<Context>(
 				"CollateOn message is not expected on the validator side of the protocol",
 			);
 		},
+		StopCollating => {
+			gum::warn!(
+				target: LOG_TARGET,
+				"StopCollating message is not expected on the validator side of the protocol",
+			);
+		},
 		DistributeCollation { .. } => {
 			gum::warn!(
 				target: LOG_TARGET,
@@ -1595,6 +1628,54 @@ async fn process_msg<Context>(
 
 			dequeue_next_collation_and_fetch(ctx, state, parent, (id, Some(candidate_hash))).await;
 		},
+		SetReservedCollators(para_id, collators) => {
+			if collators.is_empty() {
+				gum::debug!(target: LOG_TARGET, ?para_id, "Clearing reserved collators");
+				state.reserved_collators.remove(&para_id);
+				return
+			}
+
+			gum::debug!(
+				target: LOG_TARGET,
+				?para_id,
+				num_collators = collators.len(),
+				"Setting reserved collators",
+			);
+			state.reserved_collators.insert(para_id, collators);
+
+			let disallowed_peers = state
+				.peer_data
+				.iter()
+				.filter(|(_, data)| {
+					data.collating_para() == Some(para_id) &&
+						data.collator_id()
+							.map_or(false, |id| !state.is_collator_allowed(&para_id, id))
+				})
+				.map(|(peer_id, _)| *peer_id)
+				.collect::<Vec<_>>();
+
+			for peer_id in disallowed_peers {
+				gum::debug!(
+					target: LOG_TARGET,
+					?peer_id,
+					?para_id,
+					"Disconnecting collator which is not reserved for the para",
+				);
+				disconnect_peer(ctx.sender(), peer_id).await;
+			}
+		},
+		NotifyCollationFetchTimeout(relay_parent, collator_id) => {
+			gum::debug!(
+				target: LOG_TARGET,
+				?relay_parent,
+				?collator_id,
+				"Collation fetch reported as timed out",
+			);
+			if let Some(peer_id) = collator_peer_id(&state.peer_data, &collator_id) {
+				modify_reputation(&mut state.reputation, ctx.sender(), peer_id, COST_SLOW_COLLATOR)
+					.await;
+			}
+		},
 	}
 }
 
@@ -1949,6 +2030,7 @@ async fn kick_off_seconding<Context>(
 			candidate_receipt,
 			pvd,
 			pov,
+			None,
 		))
 		.await;
 		// There's always a single collation being fetched at any moment of time.
@@ -2029,6 +2111,7 @@ async fn handle_collation_fetch_response(
 				peer_id = ?pending_collation.peer_id,
 				"Request timed out"
 			);
+			state.metrics.on_collation_fetch_timeout();
 			// For now we don't want to change reputation on timeout, to mitigate issues like
 			// this: https://github.com/paritytech/polkadot/issues/4617
 			Err(None)
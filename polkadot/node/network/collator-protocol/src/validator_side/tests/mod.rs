@@ -332,6 +332,7 @@ async fn assert_candidate_backing_second(
 			candidate_receipt,
 			received_pvd,
 			incoming_pov,
+			_,
 		)) => {
 			assert_eq!(expected_relay_parent, relay_parent);
 			assert_eq!(expected_para_id, candidate_receipt.descriptor.para_id());
@@ -1048,6 +1049,144 @@ fn disconnect_if_wrong_declare() {
 	})
 }
 
+#[test]
+fn reserved_collators_are_enforced() {
+	let mut test_state = TestState::default();
+
+	test_harness(ReputationAggregator::new(|_| true), |test_harness| async move {
+		let TestHarness { mut virtual_overseer, .. } = test_harness;
+
+		let relay_parent = test_state.relay_parent;
+		let para_id = test_state.chain_ids[0];
+		update_view(&mut virtual_overseer, &mut test_state, vec![(relay_parent, 0)]).await;
+
+		let peer_b = PeerId::random();
+		let peer_c = PeerId::random();
+		let peer_d = PeerId::random();
+		let peer_e = PeerId::random();
+
+		connect_and_declare_collator(
+			&mut virtual_overseer,
+			peer_b,
+			test_state.collators[0].clone(),
+			para_id,
+			CollationVersion::V1,
+		)
+		.await;
+
+		// Already declared collators which are not reserved get disconnected.
+		overseer_send(
+			&mut virtual_overseer,
+			CollatorProtocolMessage::SetReservedCollators(
+				para_id,
+				HashSet::from([test_state.collators[1].public()]),
+			),
+		)
+		.await;
+		assert_collator_disconnect(&mut virtual_overseer, peer_b).await;
+
+		connect_and_declare_collator(
+			&mut virtual_overseer,
+			peer_c,
+			test_state.collators[2].clone(),
+			para_id,
+			CollationVersion::V1,
+		)
+		.await;
+		assert_collator_disconnect(&mut virtual_overseer, peer_c).await;
+
+		connect_and_declare_collator(
+			&mut virtual_overseer,
+			peer_d,
+			test_state.collators[1].clone(),
+			para_id,
+			CollationVersion::V1,
+		)
+		.await;
+		assert!(overseer_recv_with_timeout(&mut virtual_overseer, Duration::from_millis(30))
+			.await
+			.is_none());
+
+		// An empty set clears the restriction.
+		overseer_send(
+			&mut virtual_overseer,
+			CollatorProtocolMessage::SetReservedCollators(para_id, HashSet::new()),
+		)
+		.await;
+
+		connect_and_declare_collator(
+			&mut virtual_overseer,
+			peer_e,
+			test_state.collators[3].clone(),
+			para_id,
+			CollationVersion::V1,
+		)
+		.await;
+		assert!(overseer_recv_with_timeout(&mut virtual_overseer, Duration::from_millis(30))
+			.await
+			.is_none());
+
+		virtual_overseer
+	})
+}
+
+#[test]
+fn collation_fetch_timeout_is_reported() {
+	let mut test_state = TestState::default();
+
+	test_harness(ReputationAggregator::new(|_| true), |test_harness| async move {
+		let TestHarness { mut virtual_overseer, .. } = test_harness;
+
+		let relay_parent = test_state.relay_parent;
+		update_view(&mut virtual_overseer, &mut test_state, vec![(relay_parent, 0)]).await;
+
+		let peer_b = PeerId::random();
+
+		connect_and_declare_collator(
+			&mut virtual_overseer,
+			peer_b,
+			test_state.collators[0].clone(),
+			test_state.chain_ids[0],
+			CollationVersion::V1,
+		)
+		.await;
+
+		overseer_send(
+			&mut virtual_overseer,
+			CollatorProtocolMessage::NotifyCollationFetchTimeout(
+				relay_parent,
+				test_state.collators[0].public(),
+			),
+		)
+		.await;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::NetworkBridgeTx(NetworkBridgeTxMessage::ReportPeer(
+				ReportPeerMessage::Single(peer, rep),
+			)) => {
+				assert_eq!(peer, peer_b);
+				assert_eq!(rep.value, COST_SLOW_COLLATOR.cost_or_benefit());
+			}
+		);
+
+		// Unknown collators are ignored.
+		overseer_send(
+			&mut virtual_overseer,
+			CollatorProtocolMessage::NotifyCollationFetchTimeout(
+				relay_parent,
+				test_state.collators[1].public(),
+			),
+		)
+		.await;
+		assert!(overseer_recv_with_timeout(&mut virtual_overseer, Duration::from_millis(30))
+			.await
+			.is_none());
+
+		virtual_overseer
+	})
+}
+
 #[test]
 fn delay_reputation_change() {
 	let mut test_state = TestState::default();
@@ -1276,6 +1276,7 @@ fn child_blocked_from_seconding_by_parent(#[case] valid_parent: bool) {
 				candidate_receipt,
 				received_pvd,
 				incoming_pov,
+				_,
 			)) => {
 				assert_eq!(head_c, relay_parent);
 				assert_eq!(test_state.chain_ids[0], candidate_receipt.descriptor.para_id());
@@ -1329,6 +1330,7 @@ fn child_blocked_from_seconding_by_parent(#[case] valid_parent: bool) {
 					candidate_receipt,
 					received_pvd,
 					incoming_pov,
+					_,
 				)) => {
 					assert_eq!(head_c, relay_parent);
 					assert_eq!(test_state.chain_ids[0], candidate_receipt.descriptor.para_id());
@@ -25,7 +25,7 @@
 //! the `NetworkBridgeRxMessage::NewGossipTopology` message.
 
 use std::{
-	collections::{HashMap, HashSet},
+	collections::{BTreeMap, HashMap, HashSet},
 	fmt,
 	time::{Duration, Instant},
 	u32,
@@ -41,8 +41,8 @@ use sp_application_crypto::{AppCrypto, ByteArray};
 use sp_keystore::{Keystore, KeystorePtr};
 
 use polkadot_node_network_protocol::{
-	authority_discovery::AuthorityDiscovery, peer_set::PeerSet, GossipSupportNetworkMessage,
-	PeerId, Versioned,
+	authority_discovery::AuthorityDiscovery, grid_topology::SessionGridTopology, peer_set::PeerSet,
+	GossipSupportNetworkMessage, PeerId, Versioned,
 };
 use polkadot_node_subsystem::{
 	messages::{
@@ -92,6 +92,9 @@ const LOW_CONNECTIVITY_WARN_DELAY: Duration = Duration::from_secs(600);
 /// If connectivity is lower than this in percent, issue warning in logs.
 const LOW_CONNECTIVITY_WARN_THRESHOLD: usize = 90;
 
+/// The number of most recent session topologies kept for diagnostics.
+const MAX_RECORDED_TOPOLOGIES: usize = 3;
+
 /// The Gossip Support subsystem.
 pub struct GossipSupport<AD> {
 	keystore: KeystorePtr,
@@ -136,6 +139,10 @@ pub struct GossipSupport<AD> {
 	/// The oldest session we need to build a topology for because
 	/// the finalized blocks are from a session we haven't built a topology for.
 	finalized_needed_session: Option<u32>,
+	/// The topologies received from the network bridge, by session.
+	///
+	/// Only the `MAX_RECORDED_TOPOLOGIES` most recent sessions are kept.
+	topologies: BTreeMap<SessionIndex, SessionGridTopology>,
 	/// Subsystem metrics.
 	metrics: Metrics,
 }
@@ -163,6 +170,7 @@ where
 			min_known_session: u32::MAX,
 			authority_discovery,
 			finalized_needed_session: None,
+			topologies: BTreeMap::new(),
 			metrics,
 		}
 	}
@@ -196,6 +204,11 @@ where
 				FromOrchestra::Communication {
 					msg: GossipSupportMessage::NetworkBridgeUpdate(ev),
 				} => self.handle_connect_disconnect(ev),
+				FromOrchestra::Communication {
+					msg: GossipSupportMessage::GetTopology(session_index, tx),
+				} => {
+					let _ = tx.send(self.topologies.get(&session_index).cloned());
+				},
 				FromOrchestra::Signal(OverseerSignal::ActiveLeaves(ActiveLeavesUpdate {
 					activated,
 					..
@@ -685,7 +698,12 @@ where
 			},
 			NetworkBridgeEvent::OurViewChange(_) => {},
 			NetworkBridgeEvent::PeerViewChange(_, _) => {},
-			NetworkBridgeEvent::NewGossipTopology { .. } => {},
+			NetworkBridgeEvent::NewGossipTopology(topology) => {
+				self.topologies.insert(topology.session, topology.topology);
+				while self.topologies.len() > MAX_RECORDED_TOPOLOGIES {
+					self.topologies.pop_first();
+				}
+			},
 			NetworkBridgeEvent::PeerMessage(_, message) => {
 				// match void -> LLVM unreachable
 				match message {
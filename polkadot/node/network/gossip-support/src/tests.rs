@@ -1213,6 +1213,62 @@ fn disconnect_when_not_in_past_present_future() {
 	);
 }
 
+#[test]
+fn returns_recorded_topologies() {
+	let topology = |session: SessionIndex| {
+		SessionGridTopology::new(
+			vec![0],
+			vec![TopologyPeerInfo {
+				validator_index: ValidatorIndex(session),
+				discovery_id: Sr25519Keyring::Alice.public().into(),
+				peer_ids: Vec::new(),
+			}],
+		)
+	};
+
+	test_harness(
+		make_subsystem_with_authority_discovery(MockAuthorityDiscovery::new(
+			PAST_PRESENT_FUTURE_AUTHORITIES.clone(),
+		)),
+		|mut overseer| async move {
+			for session in 1..=(MAX_RECORDED_TOPOLOGIES as SessionIndex + 1) {
+				let msg = GossipSupportMessage::NetworkBridgeUpdate(
+					NetworkBridgeEvent::NewGossipTopology(
+						polkadot_node_subsystem::messages::network_bridge_event::NewGossipTopology {
+							session,
+							topology: topology(session),
+							local_index: None,
+						},
+					),
+				);
+				overseer.send(FromOrchestra::Communication { msg }).await;
+			}
+
+			let get_topology = |session| {
+				let (tx, rx) = oneshot::channel();
+				(GossipSupportMessage::GetTopology(session, tx), rx)
+			};
+
+			// The oldest session was evicted.
+			let (msg, rx) = get_topology(1);
+			overseer.send(FromOrchestra::Communication { msg }).await;
+			assert_eq!(rx.await.unwrap(), None);
+
+			let newest = MAX_RECORDED_TOPOLOGIES as SessionIndex + 1;
+			let (msg, rx) = get_topology(newest);
+			overseer.send(FromOrchestra::Communication { msg }).await;
+			assert_eq!(rx.await.unwrap(), Some(topology(newest)));
+
+			// Unknown sessions have no topology.
+			let (msg, rx) = get_topology(newest + 1);
+			overseer.send(FromOrchestra::Communication { msg }).await;
+			assert_eq!(rx.await.unwrap(), None);
+
+			overseer
+		},
+	);
+}
+
 #[test]
 fn test_log_output() {
 	sp_tracing::try_init_simple();
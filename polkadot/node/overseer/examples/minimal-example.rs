@@ -84,6 +84,8 @@ impl Subsystem1 {
 				pov: PoV { block_data: BlockData(Vec::new()) }.into(),
 				executor_params: Default::default(),
 				exec_kind: PvfExecKind::Backing(dummy_hash()),
+				exec_timeout: None,
+				memory_limit_override: None,
 				response_sender: tx,
 			};
 			ctx.send_message(msg).await;
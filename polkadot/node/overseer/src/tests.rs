@@ -112,6 +112,8 @@ where
 							pov: PoV { block_data: BlockData(Vec::new()) }.into(),
 							executor_params: Default::default(),
 							exec_kind: PvfExecKind::Backing(dummy_hash()),
+							exec_timeout: None,
+							memory_limit_override: None,
 							response_sender: tx,
 						})
 						.await;
@@ -812,6 +814,8 @@ fn test_candidate_validation_msg() -> CandidateValidationMessage {
 		pov,
 		executor_params: Default::default(),
 		exec_kind: PvfExecKind::Backing(dummy_hash()),
+		exec_timeout: None,
+		memory_limit_override: None,
 		response_sender,
 	}
 }
@@ -827,11 +831,14 @@ fn test_chain_api_msg() -> ChainApiMessage {
 }
 
 fn test_collator_generation_msg() -> CollationGenerationMessage {
-	CollationGenerationMessage::Initialize(CollationGenerationConfig {
-		key: CollatorPair::generate().0,
-		collator: Some(Box::new(|_, _| TestCollator.boxed())),
-		para_id: Default::default(),
-	})
+	CollationGenerationMessage::Initialize(
+		CollationGenerationConfig {
+			key: CollatorPair::generate().0,
+			collator: Some(Box::new(|_, _| TestCollator.boxed())),
+			para_id: Default::default(),
+		},
+		None,
+	)
 }
 struct TestCollator;
 
@@ -864,6 +871,7 @@ fn test_availability_recovery_msg() -> AvailabilityRecoveryMessage {
 		Default::default(),
 		None,
 		None,
+		Default::default(),
 		sender,
 	)
 }
@@ -54,7 +54,7 @@ pub use polkadot_dispute_distribution::DisputeDistributionSubsystem;
 pub use polkadot_gossip_support::GossipSupport as GossipSupportSubsystem;
 pub use polkadot_network_bridge::{
 	Metrics as NetworkBridgeMetrics, NetworkBridgeRx as NetworkBridgeRxSubsystem,
-	NetworkBridgeTx as NetworkBridgeTxSubsystem,
+	NetworkBridgeTx as NetworkBridgeTxSubsystem, Shared as NetworkBridgeShared,
 };
 pub use polkadot_node_collation_generation::CollationGenerationSubsystem;
 pub use polkadot_node_core_approval_voting::ApprovalVotingSubsystem;
@@ -231,6 +231,7 @@ where
 
 	let metrics = <OverseerMetrics as MetricsTrait>::register(registry)?;
 	let notification_sinks = Arc::new(Mutex::new(HashMap::new()));
+	let network_bridge_shared = NetworkBridgeShared::default();
 
 	let spawner = SpawnGlue(spawner);
 
@@ -246,6 +247,7 @@ where
 			req_protocol_names.clone(),
 			peerset_protocol_names.clone(),
 			notification_sinks.clone(),
+			network_bridge_shared.clone(),
 		))
 		.network_bridge_rx(NetworkBridgeRxSubsystem::new(
 			network_service.clone(),
@@ -255,6 +257,7 @@ where
 			peerset_protocol_names,
 			notification_services,
 			notification_sinks,
+			network_bridge_shared,
 			enable_approval_voting_parallel,
 		))
 		.availability_distribution(AvailabilityDistributionSubsystem::new(
@@ -456,6 +459,7 @@ where
 
 	let metrics = <OverseerMetrics as MetricsTrait>::register(registry)?;
 	let notification_sinks = Arc::new(Mutex::new(HashMap::new()));
+	let network_bridge_shared = NetworkBridgeShared::default();
 
 	let spawner = SpawnGlue(spawner);
 
@@ -470,6 +474,7 @@ where
 			req_protocol_names.clone(),
 			peerset_protocol_names.clone(),
 			notification_sinks.clone(),
+			network_bridge_shared.clone(),
 		))
 		.network_bridge_rx(NetworkBridgeRxSubsystem::new(
 			network_service.clone(),
@@ -479,6 +484,7 @@ where
 			peerset_protocol_names,
 			notification_services,
 			notification_sinks,
+			network_bridge_shared,
 			enable_approval_voting_parallel,
 		))
 		.availability_distribution(AvailabilityDistributionSubsystem::new(
@@ -655,6 +661,7 @@ where
 	use polkadot_node_subsystem_util::metrics::Metrics;
 
 	let notification_sinks = Arc::new(Mutex::new(HashMap::new()));
+	let network_bridge_shared = NetworkBridgeShared::default();
 
 	let spawner = SpawnGlue(spawner);
 
@@ -668,6 +675,7 @@ where
 			req_protocol_names.clone(),
 			peerset_protocol_names.clone(),
 			notification_sinks.clone(),
+			network_bridge_shared.clone(),
 		))
 		.network_bridge_rx(NetworkBridgeRxSubsystem::new(
 			network_service.clone(),
@@ -677,6 +685,7 @@ where
 			peerset_protocol_names,
 			notification_services,
 			notification_sinks,
+			network_bridge_shared,
 			false,
 		))
 		.availability_distribution(DummySubsystem)
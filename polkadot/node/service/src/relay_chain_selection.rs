@@ -546,6 +546,7 @@ where
 					DisputeCoordinatorMessage::DetermineUndisputedChain {
 						base: (target_number, target_hash),
 						block_descriptions: subchain_block_descriptions,
+						max_depth: None,
 						tx,
 					},
 					std::any::type_name::<Self>(),
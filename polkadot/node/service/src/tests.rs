@@ -380,6 +380,7 @@ async fn test_skeleton(
 			DisputeCoordinatorMessage::DetermineUndisputedChain {
 				base: _,
 				block_descriptions: _,
+				max_depth: _,
 				tx,
 			}
 		) => {
@@ -336,6 +336,7 @@ pub async fn benchmark_availability_read(
 						candidate_num as u32 % (std::cmp::max(5, config.n_cores) / 5) as u32,
 					)),
 					Some(*state.candidate_hash_to_core_index.get(&candidate.hash()).unwrap()),
+					Default::default(),
 					tx,
 				),
 			);
@@ -54,7 +54,7 @@ impl MockAvailabilityRecovery {
 						return
 					},
 				orchestra::FromOrchestra::Communication { msg } => match msg {
-					AvailabilityRecoveryMessage::RecoverAvailableData(_, _, _, _, tx) => {
+					AvailabilityRecoveryMessage::RecoverAvailableData(_, _, _, _, _, tx) => {
 						let available_data = AvailableData {
 							pov: Arc::new(PoV { block_data: BlockData(Vec::new()) }),
 							validation_data: PersistedValidationData {
@@ -41,6 +41,28 @@ pub enum RuntimeApiError {
 	},
 }
 
+impl RuntimeApiError {
+	/// Returns `true` if the request might succeed when retried later on.
+	///
+	/// The classification is conservative: only failures caused by the requested block (or its
+	/// state) not being available to the client are retriable. Errors raised while executing the
+	/// runtime, codec failures and unsupported APIs are permanent, retrying them is pointless.
+	///
+	/// Subsystems which cannot make progress without the answer and whose work outlives a single
+	/// leaf (e.g. dispute participation or approval checking) may retry retriable errors with a
+	/// bounded backoff. Subsystems working on behalf of an active leaf only (e.g. backing or
+	/// provisioning) should rather give up, as the leaf will be superseded anyway.
+	pub fn is_retriable(&self) -> bool {
+		match self {
+			Self::Execution { source, .. } => matches!(
+				source.downcast_ref::<sp_api::ApiError>(),
+				Some(sp_api::ApiError::UnknownBlock(_))
+			),
+			Self::NotSupported { .. } => false,
+		}
+	}
+}
+
 /// A description of an error causing the chain API request to be unservable.
 #[derive(Debug, Clone)]
 pub struct ChainApiError {
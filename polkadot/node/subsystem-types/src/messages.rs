@@ -48,17 +48,18 @@ use polkadot_primitives::{
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState,
 	},
 	ApprovalVotingParams, AuthorityDiscoveryId, BlockNumber, CandidateCommitments, CandidateHash,
-	CandidateIndex, CoreIndex, DisputeState, ExecutorParams, GroupIndex, GroupRotationInfo, Hash,
-	HeadData, Header as BlockHeader, Id as ParaId, InboundDownwardMessage, InboundHrmpMessage,
-	MultiDisputeStatementSet, NodeFeatures, OccupiedCoreAssumption, PersistedValidationData,
-	PvfCheckStatement, PvfExecKind as RuntimePvfExecKind, SessionIndex, SessionInfo,
-	SignedAvailabilityBitfield, SignedAvailabilityBitfields, ValidationCode, ValidationCodeHash,
-	ValidatorId, ValidatorIndex, ValidatorSignature,
+	CandidateIndex, CollatorId, CoreIndex, DisputeState, ExecutorParams, GroupIndex,
+	GroupRotationInfo, Hash, HeadData, Header as BlockHeader, Id as ParaId, InboundDownwardMessage,
+	InboundHrmpMessage, MultiDisputeStatementSet, NodeFeatures, OccupiedCoreAssumption,
+	PersistedValidationData, PvfCheckStatement, PvfExecKind as RuntimePvfExecKind, SessionIndex,
+	SessionInfo, SignedAvailabilityBitfield, SignedAvailabilityBitfields, ValidationCode,
+	ValidationCodeHash, ValidatorId, ValidatorIndex, ValidatorSignature,
 };
 use polkadot_statement_table::v2::Misbehavior;
 use std::{
 	collections::{BTreeMap, HashMap, HashSet, VecDeque},
 	sync::Arc,
+	time::Duration,
 };
 
 /// Network events as transmitted to other subsystems, wrapped in their message types.
@@ -104,7 +105,17 @@ pub enum CandidateBackingMessage {
 	CanSecond(CanSecondRequest, oneshot::Sender<bool>),
 	/// Note that the Candidate Backing subsystem should second the given candidate in the context
 	/// of the given relay-parent (ref. by hash). This candidate must be validated.
-	Second(Hash, CandidateReceipt, PersistedValidationData, PoV),
+	///
+	/// If a sender is provided, it is notified of the outcome once the candidate has been
+	/// seconded or refused. It is dropped if the outcome can't be determined, e.g. because
+	/// the relay parent went out of view while the candidate was being validated.
+	Second(
+		Hash,
+		CandidateReceipt,
+		PersistedValidationData,
+		PoV,
+		Option<oneshot::Sender<Result<(), SecondError>>>,
+	),
 	/// Note a validator's statement about a particular candidate in the context of the given
 	/// relay-parent. Disagreements about validity must be escalated to a broader check by the
 	/// Disputes Subsystem, though that escalation is deferred until the approval voting stage to
@@ -112,6 +123,36 @@ pub enum CandidateBackingMessage {
 	Statement(Hash, SignedFullStatementWithPVD),
 }
 
+/// The error result type of a [`CandidateBackingMessage::Second`] request.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SecondError {
+	/// The persisted validation data doesn't match the hash in the candidate descriptor.
+	#[error("Persisted validation data doesn't match the candidate descriptor")]
+	WrongPersistedValidationData,
+	/// The relay parent of the candidate is not in our view.
+	#[error("Relay parent {0:?} is outside of our view")]
+	OutOfView(Hash),
+	/// The local validator is disabled at the relay parent.
+	#[error("Local validator is disabled")]
+	LocalValidatorDisabled,
+	/// The candidate is for a para outside of our assignment.
+	#[error("Candidate is for para {0:?}, outside of our assignment")]
+	NotAssigned(ParaId),
+	/// We have already issued a statement about this candidate.
+	#[error("A statement was already issued for the candidate")]
+	StatementAlreadyIssued,
+	/// The candidate failed validation.
+	#[error("Candidate failed validation")]
+	Invalid,
+	/// The candidate can't be seconded under the current fragment chains, e.g. because it
+	/// conflicts with another candidate we seconded.
+	#[error("Seconding the candidate is not allowed")]
+	NotAllowed,
+	/// The candidate was rejected by the prospective parachains subsystem.
+	#[error("Candidate rejected by prospective parachains")]
+	RejectedByProspectiveParachains,
+}
+
 /// Blanket error for validation failing for internal reasons.
 #[derive(Debug, Error)]
 #[error("Validation failed with {0:?}")]
@@ -168,6 +209,18 @@ pub enum CandidateValidationMessage {
 		executor_params: ExecutorParams,
 		/// Execution kind, used for timeouts and retries (backing/approvals)
 		exec_kind: PvfExecKind,
+		/// Explicit execution timeout, overriding the one derived from `exec_kind`.
+		///
+		/// The validation host clamps it to a safe maximum. `None` uses the timeout of the
+		/// execution kind.
+		exec_timeout: Option<Duration>,
+		/// Explicit memory limit in bytes, overriding the maximum number of memory pages set by
+		/// `executor_params` for this execution only.
+		///
+		/// The validation host rounds it up to whole Wasm pages and clamps it to the maximum the
+		/// executor supports. Note that the override is part of the executor parameters, so the
+		/// PVF may need to be prepared again. `None` uses the session's executor parameters as-is.
+		memory_limit_override: Option<u64>,
 		/// The sending side of the response channel
 		response_sender: oneshot::Sender<Result<ValidationResult, ValidationFailed>>,
 	},
@@ -233,6 +286,12 @@ pub enum CollatorProtocolMessage {
 	///
 	/// This should be sent before any `DistributeCollation` message.
 	CollateOn(ParaId),
+	/// Signal to the collator protocol that it should stop collating on the para set by
+	/// `CollateOn`. All the pending collations are dropped and the connections to validators are
+	/// released.
+	///
+	/// A subsequent `CollateOn` resumes collating.
+	StopCollating,
 	/// Provide a collation to distribute to validators with an optional result sender.
 	DistributeCollation {
 		/// The receipt of the candidate.
@@ -262,6 +321,30 @@ pub enum CollatorProtocolMessage {
 	///
 	/// The hash is the relay parent.
 	Seconded(Hash, SignedFullStatement),
+	/// Restrict the collators we accept collations from for the given para.
+	///
+	/// Once set, only peers declaring as one of the given collators for the para are accepted,
+	/// already connected peers collating on the para with any other collator id are disconnected.
+	/// An empty set clears the restriction for the para.
+	///
+	/// Reserved collators are not exempt from reputation handling: a collator reported for an
+	/// invalid collation (see `Invalid`) is still punished and might get disconnected, even if
+	/// it is reserved.
+	///
+	/// This is only expected on the validator side of the protocol.
+	SetReservedCollators(ParaId, HashSet<CollatorId>),
+	/// Fetching a collation from the given collator took too long, report it as slow.
+	///
+	/// The hash is the relay parent. The collator gets a minor reputation cost, so that slow
+	/// collators end up disconnected in favour of responsive ones.
+	///
+	/// The validator side itself doesn't change the reputation of collators on its own fetch
+	/// timeouts, it only records them in the
+	/// `polkadot_parachain_collator_protocol_validator_collation_fetch_timeouts_total` metric.
+	/// This message is sent by the subsystems which want a timeout to be punished.
+	///
+	/// This is only expected on the validator side of the protocol.
+	NotifyCollationFetchTimeout(Hash, CollatorId),
 }
 
 impl Default for CollatorProtocolMessage {
@@ -315,6 +398,20 @@ pub enum DisputeCoordinatorMessage {
 		/// 		- or the imported statements are backing/approval votes, which are always accepted.
 		pending_confirmation: Option<oneshot::Sender<ImportStatementsResult>>,
 	},
+	/// Import statements by validators about many candidates at once.
+	///
+	/// Every item is handled exactly as if it was sent in its own `ImportStatements` message,
+	/// including the recovery of availability for unknown candidates.
+	ImportStatementsBatch {
+		/// The statements to import, per candidate.
+		items: Vec<ImportStatementsItem>,
+		/// Inform the requester once we finished importing all the items (if a sender was
+		/// provided).
+		///
+		/// The results are in the order of `items`, with the same meaning as for
+		/// `ImportStatements`.
+		pending_confirmation: Option<oneshot::Sender<Vec<ImportStatementsResult>>>,
+	},
 	/// Fetch a list of all recent disputes the coordinator is aware of.
 	/// These are disputes which have occurred any time in recent sessions,
 	/// and which may have already concluded.
@@ -330,6 +427,12 @@ pub enum DisputeCoordinatorMessage {
 	/// Sign and issue local dispute votes. A value of `true` indicates validity, and `false`
 	/// invalidity.
 	IssueLocalStatement(SessionIndex, CandidateHash, CandidateReceipt, bool),
+	/// Check whether we already issued a statement for a candidate, e.g. when resuming after a
+	/// restart.
+	///
+	/// Returns `Some(validity)` if any of our validators voted on the candidate and `None`
+	/// otherwise. If we voted on both sides, `Some(false)` is returned.
+	GetLocalStatementStatus(SessionIndex, CandidateHash, oneshot::Sender<Option<bool>>),
 	/// Determine the highest undisputed block within the given chain, based on where candidates
 	/// were included. If even the base block should not be finalized due to a dispute,
 	/// then `None` should be returned on the channel.
@@ -343,9 +446,19 @@ pub enum DisputeCoordinatorMessage {
 		base: (BlockNumber, Hash),
 		/// Descriptions of all the blocks counting upwards from the block after the base number
 		block_descriptions: Vec<BlockDescription>,
+		/// The maximum number of blocks above `base` to consider, if any. Blocks beyond it are
+		/// ignored, so the response is the best undisputed block within that window.
+		max_depth: Option<BlockNumber>,
 		/// The block to vote on, might be base in case there is no better.
 		tx: oneshot::Sender<(BlockNumber, Hash)>,
 	},
+	/// Prune the concluded disputes of candidates which were only included below the given
+	/// finalized block number, together with their votes. Concluded disputes of candidates with
+	/// no known inclusion are pruned once their session is over.
+	///
+	/// Active (unconcluded) disputes are never pruned, regardless of their age. Sends back the
+	/// number of pruned disputes once the changes are written to the database.
+	PruneFinalized(BlockNumber, oneshot::Sender<usize>),
 }
 
 /// The result of `DisputeCoordinatorMessage::ImportStatements`.
@@ -357,6 +470,21 @@ pub enum ImportStatementsResult {
 	ValidImport,
 }
 
+/// The statements about a single candidate, as imported by
+/// `DisputeCoordinatorMessage::ImportStatementsBatch`.
+#[derive(Debug)]
+pub struct ImportStatementsItem {
+	/// The candidate receipt itself.
+	pub candidate_receipt: CandidateReceipt,
+	/// The session the candidate appears in.
+	pub session: SessionIndex,
+	/// Statements, with signatures checked, by validators participating in disputes.
+	///
+	/// The validator index passed alongside each statement should correspond to the index
+	/// of the validator in the set.
+	pub statements: Vec<(SignedDisputeStatement, ValidatorIndex)>,
+}
+
 /// Messages going to the dispute distribution subsystem.
 #[derive(Debug)]
 pub enum DisputeDistributionMessage {
@@ -428,6 +556,18 @@ pub enum NetworkBridgeTxMessage {
 	/// NOTE: Messages will be processed in order.
 	SendCollationMessages(Vec<(Vec<PeerId>, net_protocol::VersionedCollationProtocol)>),
 
+	/// Send a message to a random subset of the peers connected on the collation peer-set.
+	///
+	/// The network bridge samples up to `count` peers, so the distribution subsystems can control
+	/// the fan-out without tracking the connected peers themselves. The message is sent to the
+	/// sampled peers as is, thus its version must be supported by all of them.
+	SendCollationMessageToRandomPeers {
+		/// The message to send.
+		message: net_protocol::VersionedCollationProtocol,
+		/// The maximum number of peers to send the message to.
+		count: usize,
+	},
+
 	/// Send requests via substrate request/response.
 	/// Second parameter, tells what to do if we are not yet connected to the peer.
 	SendRequests(Vec<Requests>, IfDisconnected),
@@ -452,6 +592,23 @@ pub enum NetworkBridgeTxMessage {
 		/// authority discovery has failed to resolve.
 		failed: oneshot::Sender<usize>,
 	},
+	/// Same as `ConnectToValidators`, but hints the network bridge to establish connections to
+	/// the validators in `priority` before the rest of `validator_ids`.
+	///
+	/// This is useful during disputes, where connecting to the validators holding the disputed
+	/// candidate's data first speeds up participation when connection slots are scarce.
+	ConnectToValidatorsWithPriority {
+		/// Ids of the validators to connect to.
+		validator_ids: Vec<AuthorityDiscoveryId>,
+		/// Ids of the validators to connect to first. They are connected to even if they are
+		/// not part of `validator_ids`.
+		priority: Vec<AuthorityDiscoveryId>,
+		/// The underlying protocol to use for this request.
+		peer_set: PeerSet,
+		/// Sends back the number of `AuthorityDiscoveryId`s which
+		/// authority discovery has failed to resolve.
+		failed: oneshot::Sender<usize>,
+	},
 	/// Alternative to `ConnectToValidators` in case you already know the `Multiaddrs` you want to
 	/// be connected to.
 	ConnectToResolvedValidators {
@@ -496,6 +653,48 @@ pub enum AvailabilityDistributionMessage {
 		/// The sender will be canceled if the fetching failed for some reason.
 		tx: oneshot::Sender<PoV>,
 	},
+	/// Instruct availability distribution to fetch several remote PoVs.
+	///
+	/// Each entry is handled like a `FetchPoV` message and answered through its own sender, but
+	/// the network requests are issued concurrently, up to a limit.
+	///
+	/// NOTE: The results of these fetches are not yet locally validated and could be bogus.
+	FetchPoVs(Vec<FetchPoVParams>),
+}
+
+/// A single PoV to fetch with `AvailabilityDistributionMessage::FetchPoVs`.
+#[derive(Debug)]
+pub struct FetchPoVParams {
+	/// The relay parent giving the necessary context.
+	pub relay_parent: Hash,
+	/// Validator to fetch the PoV from.
+	pub from_validator: ValidatorIndex,
+	/// The id of the parachain that produced this PoV.
+	/// This field is only used to provide more context when logging errors
+	/// from the `AvailabilityDistribution` subsystem.
+	pub para_id: ParaId,
+	/// Candidate hash to fetch the PoV for.
+	pub candidate_hash: CandidateHash,
+	/// Expected hash of the PoV, a PoV not matching this hash will be rejected.
+	pub pov_hash: Hash,
+	/// Sender for getting back the result of this fetch.
+	///
+	/// The sender will be canceled if the fetching failed for some reason.
+	pub tx: oneshot::Sender<PoV>,
+}
+
+/// The strategy used by `AvailabilityRecoveryMessage::RecoverAvailableData` to fetch the data.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryStrategy {
+	/// Only fetch the full data from the given backing group, without falling back to the rest
+	/// of the network. Fails with `RecoveryError::Unavailable` if no backing group is given.
+	BackingGroupOnly,
+	/// Try the given backing group first, as configured for the subsystem, then fall back to the
+	/// rest of the network.
+	#[default]
+	BackingGroupFirst,
+	/// Recover from the whole network, ignoring the given backing group.
+	FullNetwork,
 }
 
 /// Availability Recovery Message.
@@ -508,6 +707,7 @@ pub enum AvailabilityRecoveryMessage {
 		Option<GroupIndex>, // Optional backing group to request from first.
 		Option<CoreIndex>,  /* A `CoreIndex` needs to be specified for the recovery process to
 		                     * prefer systematic chunk recovery. */
+		RecoveryStrategy, // How the backing group is used.
 		oneshot::Sender<Result<AvailableData, crate::errors::RecoveryError>>,
 	),
 }
@@ -529,6 +729,13 @@ pub enum AvailabilityStoreMessage {
 	/// Query a `AvailableData` from the AV store.
 	QueryAvailableData(CandidateHash, oneshot::Sender<Option<AvailableData>>),
 
+	/// Query the `AvailableData` of a batch of candidates from the AV store.
+	///
+	/// The response is index-aligned with the input. Candidates without stored data map to
+	/// `None`. As every item may be several megabytes large, the response may be huge: prefer
+	/// querying in chunks of a few candidates rather than all at once.
+	QueryAvailableDataBatch(Vec<CandidateHash>, oneshot::Sender<Vec<Option<AvailableData>>>),
+
 	/// Query whether a `AvailableData` exists within the AV Store.
 	///
 	/// This is useful in cases when existence
@@ -536,12 +743,24 @@ pub enum AvailabilityStoreMessage {
 	/// megabytes of data to get a single bit of information.
 	QueryDataAvailability(CandidateHash, oneshot::Sender<bool>),
 
+	/// Query whether the `AvailableData` of a batch of candidates exists within the AV Store.
+	///
+	/// The response is index-aligned with the input.
+	QueryDataAvailabilityBatch(Vec<CandidateHash>, oneshot::Sender<Vec<bool>>),
+
 	/// Query an `ErasureChunk` from the AV store by the candidate hash and validator index.
 	QueryChunk(CandidateHash, ValidatorIndex, oneshot::Sender<Option<ErasureChunk>>),
 
 	/// Get the size of an `ErasureChunk` from the AV store by the candidate hash.
 	QueryChunkSize(CandidateHash, oneshot::Sender<Option<usize>>),
 
+	/// Get the sizes of `ErasureChunk`s from the AV store for a batch of candidate hashes.
+	///
+	/// The response is index-aligned with the input. Candidates without any stored chunk map to
+	/// `None`. Saves the messages of querying every candidate on its own, the store still reads
+	/// the metadata and one chunk of every candidate.
+	QueryChunkSizes(Vec<CandidateHash>, oneshot::Sender<Vec<Option<usize>>>),
+
 	/// Query all chunks that we have for the given candidate hash.
 	QueryAllChunks(CandidateHash, oneshot::Sender<Vec<(ValidatorIndex, ErasureChunk)>>),
 
@@ -587,6 +806,34 @@ pub enum AvailabilityStoreMessage {
 		/// Sending side of the channel to send result to.
 		tx: oneshot::Sender<Result<(), StoreAvailableDataError>>,
 	},
+
+	/// Store a batch of `AvailableData` in the AV store, writing all of them in a single database
+	/// transaction.
+	///
+	/// Every item is checked against its own expected erasure root, a mismatch only fails that
+	/// item. The results are index-aligned with the request.
+	StoreAvailableDataBatch(
+		Vec<StoreAvailableDataParams>,
+		oneshot::Sender<Vec<Result<(), StoreAvailableDataError>>>,
+	),
+}
+
+/// A single item of a [`AvailabilityStoreMessage::StoreAvailableDataBatch`] request.
+#[derive(Debug)]
+pub struct StoreAvailableDataParams {
+	/// A hash of the candidate this `available_data` belongs to.
+	pub candidate_hash: CandidateHash,
+	/// The number of validators in the session.
+	pub n_validators: u32,
+	/// The `AvailableData` itself.
+	pub available_data: AvailableData,
+	/// Erasure root we expect to get after chunking.
+	pub expected_erasure_root: Hash,
+	/// Core index where the candidate was backed.
+	pub core_index: CoreIndex,
+	/// Node features at the candidate relay parent. Used for computing the validator->chunk
+	/// mapping.
+	pub node_features: NodeFeatures,
 }
 
 /// The error result type of a [`AvailabilityStoreMessage::StoreAvailableData`] request.
@@ -609,6 +856,10 @@ pub enum ChainApiMessage {
 	/// Request the block header by hash.
 	/// Returns `None` if a block with the given hash is not present in the db.
 	BlockHeader(Hash, ChainApiResponseChannel<Option<BlockHeader>>),
+	/// Request the block headers of many blocks at once.
+	/// The headers are returned in the order of the given hashes, with `None` for every block
+	/// not present in the db.
+	BlockHeaders(Vec<Hash>, ChainApiResponseChannel<Vec<Option<BlockHeader>>>),
 	/// Get the cumulative weight of the given block, by hash.
 	/// If the block or weight is unknown, this returns `None`.
 	///
@@ -624,6 +875,9 @@ pub enum ChainApiMessage {
 	/// Request the last finalized block number.
 	/// This request always succeeds.
 	FinalizedBlockNumber(ChainApiResponseChannel<BlockNumber>),
+	/// Request the last finalized block number together with its hash.
+	/// This request always succeeds.
+	FinalizedBlock(ChainApiResponseChannel<(BlockNumber, Hash)>),
 	/// Request the `k` ancestor block hashes of a block with the given hash.
 	/// The response channel may return a `Vec` of size up to `k`
 	/// filled with ancestors hashes with the following order:
@@ -637,6 +891,18 @@ pub enum ChainApiMessage {
 		/// The response channel.
 		response_channel: ChainApiResponseChannel<Vec<Hash>>,
 	},
+	/// Check whether a block is a (strict) ancestor of another block.
+	///
+	/// This is cheaper than requesting the `Ancestors` of `descendant` and scanning them. Returns
+	/// `false` if `ancestor == descendant` or if either of the blocks is unknown.
+	IsAncestor {
+		/// The hash of the potential ancestor.
+		ancestor: Hash,
+		/// The hash of the potential descendant.
+		descendant: Hash,
+		/// The response channel.
+		response_channel: ChainApiResponseChannel<bool>,
+	},
 }
 
 /// Chain selection subsystem messages
@@ -646,6 +912,11 @@ pub enum ChainSelectionMessage {
 	Approved(Hash),
 	/// Request the leaves in descending order by score.
 	Leaves(oneshot::Sender<Vec<Hash>>),
+	/// Request the leaves along with their scores, in descending order by score.
+	///
+	/// Same as `Leaves`, but exposes the weight of each leaf, e.g. for monitoring and
+	/// fork-choice debugging.
+	LeavesWithScores(oneshot::Sender<Vec<(Hash, BlockWeight)>>),
 	/// Request the best leaf containing the given block in its ancestry. Return `None` if
 	/// there is no such leaf.
 	BestLeafContaining(Hash, oneshot::Sender<Option<Hash>>),
@@ -664,8 +935,20 @@ pub enum RuntimeApiRequest {
 	Version(RuntimeApiSender<u32>),
 	/// Get the next, current and some previous authority discovery set deduplicated.
 	Authorities(RuntimeApiSender<Vec<AuthorityDiscoveryId>>),
+	/// Get the authority discovery set of the given session deduplicated.
+	///
+	/// The set is read from the session info. If it is not stored, the live authorities are
+	/// returned for the session of the child of the relay parent. An empty set is returned for
+	/// sessions that are too old, while the request fails for sessions not started yet.
+	AuthoritiesForSession(SessionIndex, RuntimeApiSender<Vec<AuthorityDiscoveryId>>),
 	/// Get the current validator set.
 	Validators(RuntimeApiSender<Vec<ValidatorId>>),
+	/// Get the validators added and removed going from the first to the second session.
+	///
+	/// The validator sets are read from the session infos. If a session info is not stored, the
+	/// current validator set is used for the session of the child of the relay parent, otherwise
+	/// the request fails.
+	ValidatorsDiff(SessionIndex, SessionIndex, RuntimeApiSender<ValidatorSetDiff>),
 	/// Get the validator groups and group rotation info.
 	ValidatorGroups(RuntimeApiSender<(Vec<Vec<ValidatorIndex>>, GroupRotationInfo)>),
 	/// Get information on all availability cores.
@@ -678,6 +961,15 @@ pub enum RuntimeApiRequest {
 		OccupiedCoreAssumption,
 		RuntimeApiSender<Option<PersistedValidationData>>,
 	),
+	/// Get the persisted validation data for each of the given paras, taking the paired
+	/// `OccupiedCoreAssumption`.
+	///
+	/// All the data is read from the state of the same relay parent. The response is
+	/// index-aligned with the requested pairs, paras without validation data map to `None`.
+	PersistedValidationDataBatch(
+		Vec<(ParaId, OccupiedCoreAssumption)>,
+		RuntimeApiSender<Vec<Option<PersistedValidationData>>>,
+	),
 	/// Get the persisted validation data for a particular para along with the current validation
 	/// code hash, matching the data hash against an expected one.
 	AssumedValidationData(
@@ -691,37 +983,108 @@ pub enum RuntimeApiRequest {
 		polkadot_primitives::CandidateCommitments,
 		RuntimeApiSender<bool>,
 	),
+	/// Check the validation outputs of several candidates, e.g. when backing on multiple cores.
+	///
+	/// All the outputs are checked against the state of the same relay parent. Each check is
+	/// independent of the others, the outputs are not applied on top of each other. The response
+	/// is index-aligned with the requested pairs, `true` meaning the outputs pass all acceptance
+	/// criteria checks.
+	CheckValidationOutputsBatch(
+		Vec<(ParaId, polkadot_primitives::CandidateCommitments)>,
+		RuntimeApiSender<Vec<bool>>,
+	),
 	/// Get the session index that a child of the block will have.
 	SessionIndexForChild(RuntimeApiSender<SessionIndex>),
 	/// Get the validation code for a para, taking the given `OccupiedCoreAssumption`, which
 	/// will inform on how the validation data should be computed if the para currently
 	/// occupies a core.
 	ValidationCode(ParaId, OccupiedCoreAssumption, RuntimeApiSender<Option<ValidationCode>>),
+	/// Get the length in bytes of the validation code for a para, as with `ValidationCode`.
+	///
+	/// There is no runtime API returning only the length: the whole code is still fetched from the
+	/// runtime, unless already cached. Only the length is sent back and cached though, so the blob
+	/// is not passed on to the caller nor kept in the cache.
+	ValidationCodeLen(ParaId, OccupiedCoreAssumption, RuntimeApiSender<Option<u32>>),
 	/// Get validation code by its hash, either past, current or future code can be returned, as
 	/// long as state is still available.
 	ValidationCodeByHash(ValidationCodeHash, RuntimeApiSender<Option<ValidationCode>>),
+	/// Get validation code for each of the given hashes, as with `ValidationCodeByHash`.
+	///
+	/// All codes are read from the state of the same relay parent. The response is
+	/// index-aligned with the requested hashes, codes that are not stored map to `None`.
+	///
+	/// Validation code blobs can be several megabytes each, so the response for a large batch of
+	/// hashes can be very large. Callers should keep batches small.
+	ValidationCodeByHashes(Vec<ValidationCodeHash>, RuntimeApiSender<Vec<Option<ValidationCode>>>),
 	/// Get the candidate pending availability for a particular parachain by parachain / core
 	/// index
 	CandidatePendingAvailability(ParaId, RuntimeApiSender<Option<CommittedCandidateReceipt>>),
 	/// Get all events concerning candidates (backing, inclusion, time-out) in the parent of
 	/// the block in whose state this request is executed.
 	CandidateEvents(RuntimeApiSender<Vec<vstaging::CandidateEvent>>),
+	/// Get the candidate events of every block from `from` to `to`, both inclusive.
+	///
+	/// `from` must be an ancestor of `to` (or equal to it) and the range may span at most
+	/// `RuntimeApiRequest::MAX_CANDIDATE_EVENTS_RANGE` blocks, otherwise the request fails. The
+	/// response is ordered from `from` to `to`. The relay parent of the enclosing
+	/// `RuntimeApiMessage::Request` is not used.
+	CandidateEventsInRange {
+		/// The first block of the range.
+		from: Hash,
+		/// The last block of the range.
+		to: Hash,
+		/// Sends back the candidate events of each block in the range.
+		response: RuntimeApiSender<Vec<(Hash, Vec<vstaging::CandidateEvent>)>>,
+	},
 	/// Get the execution environment parameter set by session index
 	SessionExecutorParams(SessionIndex, RuntimeApiSender<Option<ExecutorParams>>),
 	/// Get the session info for the given session, if stored.
 	SessionInfo(SessionIndex, RuntimeApiSender<Option<SessionInfo>>),
+	/// Get the session infos for the given sessions, if stored.
+	///
+	/// The response is index-aligned with the requested sessions. Sessions that are not stored
+	/// map to `None`.
+	SessionInfos(Vec<SessionIndex>, RuntimeApiSender<Vec<Option<SessionInfo>>>),
 	/// Get all the pending inbound messages in the downward message queue for a para.
 	DmqContents(ParaId, RuntimeApiSender<Vec<InboundDownwardMessage<BlockNumber>>>),
+	/// Get at most the given number of pending inbound messages in the downward message queue
+	/// for a para, from the head of the queue, in queue order.
+	DmqContentsBounded(ParaId, u32, RuntimeApiSender<Vec<InboundDownwardMessage<BlockNumber>>>),
 	/// Get the contents of all channels addressed to the given recipient. Channels that have no
 	/// messages in them are also included.
 	InboundHrmpChannelsContents(
 		ParaId,
 		RuntimeApiSender<BTreeMap<ParaId, Vec<InboundHrmpMessage<BlockNumber>>>>,
 	),
+	/// Get the contents of all inbound HRMP channels, keyed by recipient and then by sender.
+	/// Recipients without any inbound channel are omitted.
+	///
+	/// On runtimes older than
+	/// `RuntimeApiRequest::INBOUND_HRMP_CHANNELS_CONTENTS_ALL_RUNTIME_REQUIREMENT` this is
+	/// assembled from per-para queries and only covers paras assigned to an availability core.
+	InboundHrmpChannelsContentsAll(
+		RuntimeApiSender<BTreeMap<ParaId, BTreeMap<ParaId, Vec<InboundHrmpMessage<BlockNumber>>>>>,
+	),
 	/// Get information about the BABE epoch the block was included in.
 	CurrentBabeEpoch(RuntimeApiSender<BabeEpoch>),
 	/// Get all disputes in relation to a relay parent.
 	FetchOnChainVotes(RuntimeApiSender<Option<polkadot_primitives::vstaging::ScrapedOnChainVotes>>),
+	/// Get the scraped on-chain votes of every block from `from` to `to`, both inclusive.
+	///
+	/// `from` must be an ancestor of `to` (or equal to it) and the range may span at most
+	/// `RuntimeApiRequest::MAX_ON_CHAIN_VOTES_RANGE` blocks, otherwise the request fails. The
+	/// response is ordered from `from` to `to`. The relay parent of the enclosing
+	/// `RuntimeApiMessage::Request` is not used.
+	FetchOnChainVotesInRange {
+		/// The first block of the range.
+		from: Hash,
+		/// The last block of the range.
+		to: Hash,
+		/// Sends back the scraped on-chain votes of each block in the range.
+		response: RuntimeApiSender<
+			Vec<(Hash, Option<polkadot_primitives::vstaging::ScrapedOnChainVotes>)>,
+		>,
+	},
 	/// Submits a PVF pre-checking statement into the transaction pool.
 	SubmitPvfCheckStatement(PvfCheckStatement, ValidatorSignature, RuntimeApiSender<()>),
 	/// Returns code hashes of PVFs that require pre-checking by validators in the active set.
@@ -736,14 +1099,42 @@ pub enum RuntimeApiRequest {
 	),
 	/// Returns all on-chain disputes at given block number. Available in `v3`.
 	Disputes(RuntimeApiSender<Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumber>)>>),
+	/// Returns the on-chain disputes at given block number which were raised in one of the given
+	/// sessions. Available in `v3`.
+	///
+	/// All the disputes are fetched from the runtime and filtered on the node, they are shared
+	/// with `Disputes` in the cache.
+	DisputesInSessions(
+		Vec<SessionIndex>,
+		RuntimeApiSender<Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumber>)>>,
+	),
 	/// Returns a list of validators that lost a past session dispute and need to be slashed.
 	/// `V5`
 	UnappliedSlashes(
 		RuntimeApiSender<Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>>,
 	),
+	/// Returns the validators that lost a dispute in the given session and need to be slashed.
+	///
+	/// On runtimes older than
+	/// `RuntimeApiRequest::UNAPPLIED_SLASHES_FOR_SESSION_RUNTIME_REQUIREMENT` all the unapplied
+	/// slashes are fetched and filtered on the node.
+	UnappliedSlashesForSession(
+		SessionIndex,
+		RuntimeApiSender<Vec<(CandidateHash, slashing::PendingSlashes)>>,
+	),
 	/// Returns a merkle proof of a validator session key.
 	/// `V5`
 	KeyOwnershipProof(ValidatorId, RuntimeApiSender<Option<slashing::OpaqueKeyOwnershipProof>>),
+	/// Returns a merkle proof of a validator session key in the given session.
+	///
+	/// If the session is the one of the relay parent's child, `KeyOwnershipProof` is used, so
+	/// this works on runtimes older than
+	/// `RuntimeApiRequest::KEY_OWNERSHIP_PROOF_FOR_SESSION_RUNTIME_REQUIREMENT` as well.
+	KeyOwnershipProofForSession(
+		SessionIndex,
+		ValidatorId,
+		RuntimeApiSender<Option<slashing::OpaqueKeyOwnershipProof>>,
+	),
 	/// Submits an unsigned extrinsic to slash validator who lost a past session dispute.
 	/// `V5`
 	SubmitReportDisputeLost(
@@ -753,10 +1144,23 @@ pub enum RuntimeApiRequest {
 	),
 	/// Get the minimum required backing votes.
 	MinimumBackingVotes(SessionIndex, RuntimeApiSender<u32>),
+	/// Get the minimum required backing votes for each of the given paras.
+	///
+	/// The values are those of the session of the relay parent, whose index shall be passed in
+	/// for caching purposes. The response is index-aligned with the requested paras. On runtimes
+	/// older than `RuntimeApiRequest::MINIMUM_BACKING_VOTES_FOR_PARAS_RUNTIME_REQUIREMENT` the
+	/// session-wide value is returned for every para.
+	MinimumBackingVotesForParas(SessionIndex, Vec<ParaId>, RuntimeApiSender<Vec<u32>>),
 	/// Returns all disabled validators at a given block height.
 	DisabledValidators(RuntimeApiSender<Vec<ValidatorIndex>>),
 	/// Get the backing state of the given para.
 	ParaBackingState(ParaId, RuntimeApiSender<Option<vstaging::async_backing::BackingState>>),
+	/// Get the backing state of the given para, along with the cores occupied by its candidates
+	/// pending availability.
+	///
+	/// The core mapping is derived from the availability cores, so it is supported by every
+	/// runtime supporting `ParaBackingState`.
+	ParaBackingStateWithCores(ParaId, RuntimeApiSender<Option<BackingStateWithCores>>),
 	/// Get candidate's acceptance limitations for asynchronous backing for a relay parent.
 	///
 	/// If it's not supported by the Runtime, the async backing is said to be disabled.
@@ -780,9 +1184,69 @@ pub enum RuntimeApiRequest {
 	SchedulingLookahead(SessionIndex, RuntimeApiSender<u32>),
 }
 
+/// The backing state of a para, along with the cores occupied by its candidates pending
+/// availability.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackingStateWithCores {
+	/// The backing state of the para.
+	pub backing_state: vstaging::async_backing::BackingState,
+	/// The core occupied by each candidate pending availability, index-aligned with
+	/// `backing_state.pending_availability`.
+	///
+	/// `None` if the candidate was not found on any of the availability cores.
+	pub pending_availability_cores: Vec<Option<CoreIndex>>,
+}
+
+impl BackingStateWithCores {
+	/// Maps the candidates pending availability of `backing_state` to the availability `cores`
+	/// they occupy.
+	pub fn new(backing_state: vstaging::async_backing::BackingState, cores: &[CoreState]) -> Self {
+		let pending_availability_cores = backing_state
+			.pending_availability
+			.iter()
+			.map(|candidate| {
+				cores
+					.iter()
+					.position(|core| match core {
+						CoreState::Occupied(occupied) =>
+							occupied.candidate_hash == candidate.candidate_hash,
+						_ => false,
+					})
+					.map(|index| CoreIndex(index as u32))
+			})
+			.collect();
+		Self { backing_state, pending_availability_cores }
+	}
+}
+
+/// The difference between the validator sets of two sessions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidatorSetDiff {
+	/// The validators of the later session which are not in the earlier one.
+	pub added: Vec<ValidatorId>,
+	/// The validators of the earlier session which are not in the later one.
+	pub removed: Vec<ValidatorId>,
+}
+
+impl ValidatorSetDiff {
+	/// Computes the difference going from the `from` to the `to` validator set, keeping the
+	/// order of the validators within each set.
+	pub fn new(from: &[ValidatorId], to: &[ValidatorId]) -> Self {
+		let from_set = from.iter().collect::<HashSet<_>>();
+		let to_set = to.iter().collect::<HashSet<_>>();
+		Self {
+			added: to.iter().filter(|id| !from_set.contains(id)).cloned().collect(),
+			removed: from.iter().filter(|id| !to_set.contains(id)).cloned().collect(),
+		}
+	}
+}
+
 impl RuntimeApiRequest {
 	/// Runtime version requirements for each message
 
+	/// `AuthoritiesForSession`
+	pub const AUTHORITIES_FOR_SESSION_RUNTIME_REQUIREMENT: u32 = 2;
+
 	/// `Disputes`
 	pub const DISPUTES_RUNTIME_REQUIREMENT: u32 = 3;
 
@@ -824,6 +1288,24 @@ impl RuntimeApiRequest {
 
 	/// `SchedulingLookahead`
 	pub const SCHEDULING_LOOKAHEAD_RUNTIME_REQUIREMENT: u32 = 12;
+
+	/// `InboundHrmpChannelsContentsAll`
+	pub const INBOUND_HRMP_CHANNELS_CONTENTS_ALL_RUNTIME_REQUIREMENT: u32 = 13;
+
+	/// `MinimumBackingVotesForParas`
+	pub const MINIMUM_BACKING_VOTES_FOR_PARAS_RUNTIME_REQUIREMENT: u32 = 13;
+
+	/// `UnappliedSlashesForSession`
+	pub const UNAPPLIED_SLASHES_FOR_SESSION_RUNTIME_REQUIREMENT: u32 = 13;
+
+	/// `KeyOwnershipProofForSession`
+	pub const KEY_OWNERSHIP_PROOF_FOR_SESSION_RUNTIME_REQUIREMENT: u32 = 13;
+
+	/// The maximum number of blocks a single `CandidateEventsInRange` request may span.
+	pub const MAX_CANDIDATE_EVENTS_RANGE: u32 = 64;
+
+	/// The maximum number of blocks a single `FetchOnChainVotesInRange` request may span.
+	pub const MAX_ON_CHAIN_VOTES_RANGE: u32 = 64;
 }
 
 /// A message to the Runtime API subsystem.
@@ -894,14 +1376,47 @@ pub enum ProvisionerMessage {
 #[derive(Debug)]
 pub enum CollationGenerationMessage {
 	/// Initialize the collation generation subsystem.
-	Initialize(CollationGenerationConfig),
+	///
+	/// The optional sender is informed once the config is installed, so collations can be
+	/// submitted without being ignored. It is dropped if the subsystem was already initialized.
+	Initialize(CollationGenerationConfig, Option<oneshot::Sender<()>>),
 	/// Reinitialize the collation generation subsystem, overriding the existing config.
-	Reinitialize(CollationGenerationConfig),
+	///
+	/// The optional sender is informed once the new config is installed.
+	Reinitialize(CollationGenerationConfig, Option<oneshot::Sender<()>>),
 	/// Submit a collation to the subsystem. This will package it into a signed
 	/// [`CommittedCandidateReceipt`] and distribute along the network to validators.
 	///
 	/// If sent before `Initialize`, this will be ignored.
-	SubmitCollation(SubmitCollationParams),
+	///
+	/// The optional sender is informed about the outcome of the early checks of the params:
+	/// obviously invalid params are rejected with an error, otherwise `Ok(())` is sent before the
+	/// candidate is constructed. The outcome of the construction and distribution is not reported.
+	/// The sender is dropped if the checks cannot be performed.
+	SubmitCollation(
+		SubmitCollationParams,
+		Option<oneshot::Sender<Result<(), SubmitCollationError>>>,
+	),
+	/// Stop generating collations for the configured para, without shutting down the subsystem.
+	///
+	/// This also signals the collator protocol to stop collating on the para, which disconnects
+	/// us from its validators. A subsequent `Initialize` resumes collation generation, while
+	/// `CollatorProtocolMessage::CollateOn` must be sent again to resume the distribution.
+	Shutdown,
+}
+
+/// The error result type of a [`CollationGenerationMessage::SubmitCollation`] request.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SubmitCollationError {
+	/// The collation was submitted before the subsystem was initialized.
+	#[error("Collation submitted before initialization")]
+	NotInitialized,
+	/// There is no persisted validation data for the para at the given relay parent.
+	#[error("No validation data for the para at relay parent {0:?}")]
+	NoValidationData(Hash),
+	/// The PoV size exceeds the maximum PoV size, even if compressed.
+	#[error("PoV size {0} exceeded maximum size of {1}")]
+	PoVSizeExceeded(usize, usize),
 }
 
 /// The result type of [`ApprovalVotingMessage::ImportAssignment`] request.
@@ -1002,6 +1517,8 @@ pub enum ApprovalVotingParallelMessage {
 		CandidateHash,
 		oneshot::Sender<HashMap<ValidatorIndex, (Vec<CandidateHash>, ValidatorSignature)>>,
 	),
+	/// Gets mapped into `ApprovalVotingMessage::GetApprovedCandidates`
+	GetApprovedCandidates(Hash, oneshot::Sender<Vec<CandidateHash>>),
 	/// Gets mapped into `ApprovalDistributionMessage::NewBlocks`
 	NewBlocks(Vec<BlockApprovalMeta>),
 	/// Gets mapped into `ApprovalDistributionMessage::DistributeAssignment`
@@ -1020,6 +1537,10 @@ pub enum ApprovalVotingParallelMessage {
 	),
 	/// Gets mapped into `ApprovalDistributionMessage::ApprovalCheckingLagUpdate`
 	ApprovalCheckingLagUpdate(BlockNumber),
+	/// Gets mapped into `ApprovalDistributionMessage::GetAssignmentsForBlock`
+	GetAssignmentsForBlock(Hash, oneshot::Sender<Vec<(ValidatorIndex, CandidateIndex)>>),
+	/// Gets mapped into `ApprovalDistributionMessage::PurgeBlock`
+	PurgeBlock(Hash),
 }
 
 impl TryFrom<ApprovalVotingParallelMessage> for ApprovalVotingMessage {
@@ -1031,6 +1552,8 @@ impl TryFrom<ApprovalVotingParallelMessage> for ApprovalVotingMessage {
 				Ok(ApprovalVotingMessage::ApprovedAncestor(hash, number, tx)),
 			ApprovalVotingParallelMessage::GetApprovalSignaturesForCandidate(candidate, tx) =>
 				Ok(ApprovalVotingMessage::GetApprovalSignaturesForCandidate(candidate, tx)),
+			ApprovalVotingParallelMessage::GetApprovedCandidates(hash, tx) =>
+				Ok(ApprovalVotingMessage::GetApprovedCandidates(hash, tx)),
 			_ => Err(()),
 		}
 	}
@@ -1053,6 +1576,10 @@ impl TryFrom<ApprovalVotingParallelMessage> for ApprovalDistributionMessage {
 				Ok(ApprovalDistributionMessage::GetApprovalSignatures(candidate_indicies, tx)),
 			ApprovalVotingParallelMessage::ApprovalCheckingLagUpdate(lag) =>
 				Ok(ApprovalDistributionMessage::ApprovalCheckingLagUpdate(lag)),
+			ApprovalVotingParallelMessage::GetAssignmentsForBlock(hash, tx) =>
+				Ok(ApprovalDistributionMessage::GetAssignmentsForBlock(hash, tx)),
+			ApprovalVotingParallelMessage::PurgeBlock(hash) =>
+				Ok(ApprovalDistributionMessage::PurgeBlock(hash)),
 			_ => Err(()),
 		}
 	}
@@ -1073,6 +1600,10 @@ impl From<ApprovalDistributionMessage> for ApprovalVotingParallelMessage {
 				ApprovalVotingParallelMessage::GetApprovalSignatures(candidate_indicies, tx),
 			ApprovalDistributionMessage::ApprovalCheckingLagUpdate(lag) =>
 				ApprovalVotingParallelMessage::ApprovalCheckingLagUpdate(lag),
+			ApprovalDistributionMessage::GetAssignmentsForBlock(hash, tx) =>
+				ApprovalVotingParallelMessage::GetAssignmentsForBlock(hash, tx),
+			ApprovalDistributionMessage::PurgeBlock(hash) =>
+				ApprovalVotingParallelMessage::PurgeBlock(hash),
 		}
 	}
 }
@@ -1170,6 +1701,13 @@ pub enum ApprovalVotingMessage {
 		CandidateHash,
 		oneshot::Sender<HashMap<ValidatorIndex, (Vec<CandidateHash>, ValidatorSignature)>>,
 	),
+
+	/// Returns the candidates included in the given relay chain block which have been
+	/// approved in the context of that block.
+	///
+	/// This is a more fine-grained complement to `ApprovedAncestor`, only reading from the
+	/// approval-voting database. Returns an empty vector if the block is unknown.
+	GetApprovedCandidates(Hash, oneshot::Sender<Vec<CandidateHash>>),
 }
 
 /// Message to the Approval Distribution subsystem.
@@ -1196,6 +1734,13 @@ pub enum ApprovalDistributionMessage {
 	),
 	/// Approval checking lag update measured in blocks.
 	ApprovalCheckingLagUpdate(BlockNumber),
+	/// Get all assignments known for the given relay block, as `(validator, candidate index)`
+	/// pairs. Meant for diagnostics only, returns an empty list if the block is unknown.
+	GetAssignmentsForBlock(Hash, oneshot::Sender<Vec<(ValidatorIndex, CandidateIndex)>>),
+	/// The given relay block was reverted, drop all the assignments, approvals and peer knowledge
+	/// tracked for it. State of other blocks, including the ones carrying the same candidates on
+	/// other forks, is left untouched.
+	PurgeBlock(Hash),
 }
 
 /// Message to the Gossip Support subsystem.
@@ -1204,6 +1749,14 @@ pub enum GossipSupportMessage {
 	/// Dummy constructor, so we can receive networking events.
 	#[from]
 	NetworkBridgeUpdate(NetworkBridgeEvent<net_protocol::GossipSupportNetworkMessage>),
+	/// Get the gossip topology of the given session, as received from the network bridge.
+	/// Meant for diagnostics only.
+	///
+	/// Returns `None` if no topology was recorded for the session.
+	GetTopology(
+		SessionIndex,
+		oneshot::Sender<Option<net_protocol::grid_topology::SessionGridTopology>>,
+	),
 }
 
 /// Request introduction of a seconded candidate into the prospective parachains subsystem.
@@ -1347,6 +1900,19 @@ pub struct ProspectiveValidationDataRequest {
 	pub parent_head_data: ParentHeadData,
 }
 
+/// Size statistics of the fragment chain of a para under an active leaf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FragmentTreeStats {
+	/// The active leaf the fragment chain is built under.
+	pub relay_parent: Hash,
+	/// The para-id the fragment chain belongs to.
+	pub para_id: ParaId,
+	/// The number of candidates held, both in the best chain and unconnected.
+	pub node_count: usize,
+	/// The length of the best chain of backable candidates.
+	pub max_depth: usize,
+}
+
 /// The parent head-data hash with optional data itself.
 #[derive(Debug, Clone)]
 pub enum ParentHeadData {
@@ -1444,4 +2010,18 @@ pub enum ProspectiveParachainsMessage {
 		ProspectiveValidationDataRequest,
 		oneshot::Sender<Option<PersistedValidationData>>,
 	),
+	/// Get the validation data of a batch of prospective candidates.
+	///
+	/// Each request is resolved in the same way as in `GetProspectiveValidationData`. The
+	/// response is index-aligned with the requests.
+	GetProspectiveValidationDataBatch(
+		Vec<ProspectiveValidationDataRequest>,
+		oneshot::Sender<Vec<Option<PersistedValidationData>>>,
+	),
+	/// Get the size statistics of the fragment chains under all the active leaves.
+	///
+	/// There is one entry per active leaf and para with a fragment chain, so the response is
+	/// bounded by the number of active leaves times the number of scheduled paras. Entries are
+	/// sorted by relay-parent and then by para-id.
+	GetFragmentTreeStats(oneshot::Sender<Vec<FragmentTreeStats>>),
 }
@@ -353,6 +353,36 @@ pub trait RuntimeApiSubsystemClient {
 	// === v12 ===
 	/// Fetch the scheduling lookahead value
 	async fn scheduling_lookahead(&self, at: Hash) -> Result<u32, ApiError>;
+
+	// === STAGING ===
+	/// Get the contents of all inbound HRMP channels, keyed by recipient and then by sender.
+	async fn inbound_hrmp_channels_contents_all(
+		&self,
+		at: Hash,
+	) -> Result<BTreeMap<Id, BTreeMap<Id, Vec<InboundHrmpMessage<BlockNumber>>>>, ApiError>;
+
+	/// Get the minimum number of backing votes for each of the given paras, as of the session of
+	/// the block `at`.
+	async fn minimum_backing_votes_for_paras(
+		&self,
+		at: Hash,
+		para_ids: Vec<Id>,
+	) -> Result<Vec<u32>, ApiError>;
+
+	/// Returns the validators that lost a dispute in the given session and need to be slashed.
+	async fn unapplied_slashes_for_session(
+		&self,
+		at: Hash,
+		session_index: SessionIndex,
+	) -> Result<Vec<(CandidateHash, slashing::PendingSlashes)>, ApiError>;
+
+	/// Returns a merkle proof of a validator session key in the given session.
+	async fn key_ownership_proof_for_session(
+		&self,
+		at: Hash,
+		session_index: SessionIndex,
+		validator_id: ValidatorId,
+	) -> Result<Option<slashing::OpaqueKeyOwnershipProof>, ApiError>;
 }
 
 /// Default implementation of [`RuntimeApiSubsystemClient`] using the client.
@@ -642,6 +672,40 @@ where
 	async fn scheduling_lookahead(&self, at: Hash) -> Result<u32, ApiError> {
 		self.client.runtime_api().scheduling_lookahead(at)
 	}
+
+	async fn inbound_hrmp_channels_contents_all(
+		&self,
+		at: Hash,
+	) -> Result<BTreeMap<Id, BTreeMap<Id, Vec<InboundHrmpMessage<BlockNumber>>>>, ApiError> {
+		self.client.runtime_api().inbound_hrmp_channels_contents_all(at)
+	}
+
+	async fn minimum_backing_votes_for_paras(
+		&self,
+		at: Hash,
+		para_ids: Vec<Id>,
+	) -> Result<Vec<u32>, ApiError> {
+		self.client.runtime_api().minimum_backing_votes_for_paras(at, para_ids)
+	}
+
+	async fn unapplied_slashes_for_session(
+		&self,
+		at: Hash,
+		session_index: SessionIndex,
+	) -> Result<Vec<(CandidateHash, slashing::PendingSlashes)>, ApiError> {
+		self.client.runtime_api().unapplied_slashes_for_session(at, session_index)
+	}
+
+	async fn key_ownership_proof_for_session(
+		&self,
+		at: Hash,
+		session_index: SessionIndex,
+		validator_id: ValidatorId,
+	) -> Result<Option<slashing::OpaqueKeyOwnershipProof>, ApiError> {
+		self.client
+			.runtime_api()
+			.key_ownership_proof_for_session(at, session_index, validator_id)
+	}
 }
 
 impl<Client, Block> HeaderBackend<Block> for DefaultSubsystemClient<Client>
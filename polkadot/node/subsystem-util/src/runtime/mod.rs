@@ -497,6 +497,38 @@ pub async fn request_min_backing_votes(
 	}
 }
 
+/// Request the min backing votes value for each of the given paras.
+/// The result is index-aligned with `paras`.
+/// Prior to runtime API version 6, just return a hardcoded constant for every para.
+pub async fn request_min_backing_votes_for_paras(
+	parent: Hash,
+	session_index: SessionIndex,
+	paras: Vec<ParaId>,
+	sender: &mut impl overseer::SubsystemSender<RuntimeApiMessage>,
+) -> Result<Vec<u32>> {
+	let n_paras = paras.len();
+	let min_backing_votes_res = recv_runtime(
+		request_from_runtime(parent, sender, |tx| {
+			RuntimeApiRequest::MinimumBackingVotesForParas(session_index, paras, tx)
+		})
+		.await,
+	)
+	.await;
+
+	if let Err(Error::RuntimeRequest(RuntimeApiError::NotSupported { .. })) = min_backing_votes_res
+	{
+		gum::trace!(
+			target: LOG_TARGET,
+			?parent,
+			"Querying the backing threshold from the runtime is not supported by the current Runtime API",
+		);
+
+		Ok(vec![LEGACY_MIN_BACKING_VOTES; n_paras])
+	} else {
+		min_backing_votes_res
+	}
+}
+
 /// Request the node features enabled in the runtime.
 /// Pass in the session index for caching purposes, as it should only change on session boundaries.
 /// Prior to runtime API version 9, just return `None`.
@@ -391,7 +391,7 @@ impl PolkadotTestNode {
 			CollationGenerationConfig { key: collator_key, collator: Some(collator), para_id };
 
 		self.overseer_handle
-			.send_msg(CollationGenerationMessage::Initialize(config), "Collator")
+			.send_msg(CollationGenerationMessage::Initialize(config, None), "Collator")
 			.await;
 
 		self.overseer_handle
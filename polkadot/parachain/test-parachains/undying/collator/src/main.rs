@@ -134,7 +134,7 @@ fn main() -> Result<()> {
 					para_id,
 				};
 				overseer_handle
-					.send_msg(CollationGenerationMessage::Initialize(config), "Collator")
+					.send_msg(CollationGenerationMessage::Initialize(config, None), "Collator")
 					.await;
 
 				overseer_handle
@@ -308,5 +308,30 @@ sp_api::decl_runtime_apis! {
 		/// Retrieve the scheduling lookahead
 		#[api_version(12)]
 		fn scheduling_lookahead() -> u32;
+
+		/***** STAGING *****/
+		/// Get the contents of all inbound HRMP channels, keyed by recipient and then by sender.
+		/// Channels that have no messages in them are also included.
+		#[api_version(13)]
+		fn inbound_hrmp_channels_contents_all(
+		) -> BTreeMap<ppp::Id, BTreeMap<ppp::Id, Vec<pcp::v2::InboundHrmpMessage<BlockNumber>>>>;
+
+		/// Get the minimum number of backing votes for a candidate of each of the given paras.
+		/// The result is index-aligned with `para_ids`.
+		#[api_version(13)]
+		fn minimum_backing_votes_for_paras(para_ids: Vec<ppp::Id>) -> Vec<u32>;
+
+		/// Returns the validators that lost a dispute in the given session and need to be slashed.
+		#[api_version(13)]
+		fn unapplied_slashes_for_session(
+			session_index: SessionIndex,
+		) -> Vec<(CandidateHash, slashing::PendingSlashes)>;
+
+		/// Returns a merkle proof of a validator session key in the given session.
+		#[api_version(13)]
+		fn key_ownership_proof_for_session(
+			session_index: SessionIndex,
+			validator_id: ValidatorId,
+		) -> Option<slashing::OpaqueKeyOwnershipProof>;
 	}
 }
@@ -551,6 +551,12 @@ impl<T: Config> Pallet<T> {
 		<UnappliedSlashes<T>>::iter().collect()
 	}
 
+	pub(crate) fn unapplied_slashes_for_session(
+		session_index: SessionIndex,
+	) -> Vec<(CandidateHash, PendingSlashes)> {
+		<UnappliedSlashes<T>>::iter_prefix(session_index).collect()
+	}
+
 	pub(crate) fn submit_unsigned_slashing_report(
 		dispute_proof: DisputeProof,
 		key_ownership_proof: <T as Config>::KeyOwnerProof,
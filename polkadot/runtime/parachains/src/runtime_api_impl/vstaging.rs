@@ -16,9 +16,16 @@
 
 //! Put implementations of functions from staging APIs here.
 
-use crate::{configuration, initializer};
+use crate::{configuration, disputes, hrmp, initializer};
+use alloc::{collections::btree_map::BTreeMap, vec::Vec};
+use codec::Encode;
+use frame_support::traits::KeyOwnerProofSystem;
 use frame_system::pallet_prelude::*;
-use polkadot_primitives::{vstaging::async_backing::Constraints, Id as ParaId};
+use polkadot_primitives::{
+	slashing, vstaging::async_backing::Constraints, CandidateHash, Id as ParaId,
+	InboundHrmpMessage, SessionIndex, ValidatorId, PARACHAIN_KEY_TYPE_ID,
+};
+use sp_session::GetSessionNumber;
 
 /// Implementation for `constraints` function from the runtime API
 pub fn backing_constraints<T: initializer::Config>(
@@ -50,3 +57,42 @@ pub fn backing_constraints<T: initializer::Config>(
 pub fn scheduling_lookahead<T: initializer::Config>() -> u32 {
 	configuration::ActiveConfig::<T>::get().scheduler_params.lookahead
 }
+
+/// Implementation for `minimum_backing_votes_for_paras` function from the runtime API.
+///
+/// The threshold is currently the same for all paras.
+pub fn minimum_backing_votes_for_paras<T: initializer::Config>(para_ids: Vec<ParaId>) -> Vec<u32> {
+	let minimum_backing_votes = super::v11::minimum_backing_votes::<T>();
+	alloc::vec![minimum_backing_votes; para_ids.len()]
+}
+
+/// Implementation for `unapplied_slashes_for_session` function from the runtime API.
+pub fn unapplied_slashes_for_session<T: disputes::slashing::Config>(
+	session_index: SessionIndex,
+) -> Vec<(CandidateHash, slashing::PendingSlashes)> {
+	disputes::slashing::Pallet::<T>::unapplied_slashes_for_session(session_index)
+}
+
+/// Implementation for `key_ownership_proof_for_session` function from the runtime API.
+///
+/// Proofs can only be generated against the current session of the key owner proof system, `None`
+/// is returned for any other session.
+pub fn key_ownership_proof_for_session<T: disputes::slashing::Config>(
+	session_index: SessionIndex,
+	validator_id: ValidatorId,
+) -> Option<slashing::OpaqueKeyOwnershipProof> {
+	let proof = T::KeyOwnerProofSystem::prove((PARACHAIN_KEY_TYPE_ID, validator_id))?;
+	(proof.session() == session_index)
+		.then(|| slashing::OpaqueKeyOwnershipProof::new(proof.encode()))
+}
+
+/// Implementation for `inbound_hrmp_channels_contents_all` function from the runtime API.
+///
+/// Recipients without any inbound channel are omitted.
+pub fn inbound_hrmp_channels_contents_all<T: hrmp::Config>(
+) -> BTreeMap<ParaId, BTreeMap<ParaId, Vec<InboundHrmpMessage<BlockNumberFor<T>>>>> {
+	hrmp::HrmpIngressChannelsIndex::<T>::iter_keys()
+		.map(|recipient| (recipient, hrmp::Pallet::<T>::inbound_hrmp_channels_contents(recipient)))
+		.filter(|(_, contents)| !contents.is_empty())
+		.collect()
+}
@@ -59,17 +59,28 @@ pub struct TransactionPoolParams {
 	/// The type of transaction pool to be instantiated.
 	#[arg(long, value_enum, default_value_t = TransactionPoolType::SingleState)]
 	pub pool_type: TransactionPoolType,
+
+	/// Number of the most recent view lifecycle events recorded by the fork-aware transaction
+	/// pool, for post-mortem debugging.
+	///
+	/// Disabled if not provided. Ignored by the single-state transaction pool.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_event_log_capacity: Option<usize>,
 }
 
 impl TransactionPoolParams {
 	/// Fill the given `PoolConfiguration` by looking at the cli parameters.
 	pub fn transaction_pool(&self, is_dev: bool) -> TransactionPoolOptions {
-		TransactionPoolOptions::new_with_params(
+		let options = TransactionPoolOptions::new_with_params(
 			self.pool_limit,
 			self.pool_kbytes * 1024,
 			self.tx_ban_seconds,
 			self.pool_type.into(),
 			is_dev,
-		)
+		);
+		match self.pool_event_log_capacity {
+			Some(capacity) => options.with_view_store_event_log(capacity),
+			None => options,
+		}
 	}
 }
@@ -83,6 +83,8 @@ const POOL_NO_TAGS: i32 = POOL_INVALID_TX + 9;
 const POOL_INVALID_BLOCK_ID: i32 = POOL_INVALID_TX + 10;
 /// The pool is not accepting future transactions.
 const POOL_FUTURE_TX: i32 = POOL_INVALID_TX + 11;
+/// The transaction validation did not complete in time.
+const POOL_VALIDATION_TIMEOUT: i32 = POOL_INVALID_TX + 12;
 /// Other error.
 const OTHER_ERR: i32 = BASE_ERROR + 40;
 
@@ -175,6 +177,11 @@ impl From<Error> for ErrorObjectOwned {
 					None::<()>,
 				)
 			},
+			Error::Pool(PoolError::ValidationTimeout) => ErrorObject::owned(
+				POOL_VALIDATION_TIMEOUT,
+				"Validation Timeout",
+				Some("The transaction validation did not complete in time"),
+			),
 			Error::UnsafeRpcCalled(e) => e.into(),
 			other => ErrorObject::owned(
 				OTHER_ERR,
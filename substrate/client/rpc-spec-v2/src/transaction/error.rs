@@ -96,6 +96,10 @@ impl<Hash> From<Error> for TransactionEvent<Hash> {
 				TransactionEvent::Invalid(TransactionError {
 					error: "The pool is not accepting future transactions".into(),
 				}),
+			Error::Pool(PoolError::ValidationTimeout) =>
+				TransactionEvent::Invalid(TransactionError {
+					error: "The transaction validation timed out".into(),
+				}),
 		}
 	}
 }
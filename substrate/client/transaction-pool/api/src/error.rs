@@ -69,6 +69,9 @@ pub enum Error {
 
 	#[error("The pool is not accepting future transactions")]
 	RejectedFutureTransaction,
+
+	#[error("Transaction validation timed out")]
+	ValidationTimeout,
 }
 
 impl Error {
@@ -87,7 +90,9 @@ impl Error {
 			// The node might be lagging behind, or during a warp sync.
 			Error::InvalidBlockId(_) |
 			// The pool is configured to not accept future transactions.
-			Error::RejectedFutureTransaction => {
+			Error::RejectedFutureTransaction |
+			// The validation may complete in time once the runtime is less busy.
+			Error::ValidationTimeout => {
 				true
 			}
 			_ => false
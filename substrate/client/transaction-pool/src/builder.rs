@@ -84,6 +84,15 @@ impl TransactionPoolOptions {
 		TransactionPoolOptions { options, txpool_type }
 	}
 
+	/// Enables the view store event log of the fork-aware transaction pool, recording up to
+	/// `capacity` most recent view lifecycle events for post-mortem debugging.
+	///
+	/// Ignored by the single-state transaction pool.
+	pub fn with_view_store_event_log(mut self, capacity: usize) -> TransactionPoolOptions {
+		self.options.view_store_event_log_capacity = Some(capacity);
+		self
+	}
+
 	/// Creates predefined options for benchmarking
 	pub fn new_for_benchmarks() -> TransactionPoolOptions {
 		TransactionPoolOptions {
@@ -98,6 +107,7 @@ impl TransactionPoolOptions {
 				},
 				reject_future_transactions: false,
 				ban_time: Duration::from_secs(30 * 60),
+				view_store_event_log_capacity: None,
 			},
 			txpool_type: TransactionPoolType::SingleState,
 		}
@@ -30,7 +30,10 @@ use sp_runtime::{
 		InvalidTransaction, TransactionSource, TransactionValidity, ValidTransaction,
 	},
 };
-use std::{collections::HashSet, sync::Arc};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+};
 use substrate_test_runtime::{
 	substrate_test_pallet::pallet::Call as PalletCall, BalancesCall, Block, BlockNumber, Extrinsic,
 	ExtrinsicBuilder, Hashing, RuntimeCall, Transfer, TransferData, H256,
@@ -46,6 +49,9 @@ pub(crate) struct TestApi {
 	pub clear_requirements: Arc<Mutex<HashSet<H256>>>,
 	pub add_requirements: Arc<Mutex<HashSet<H256>>>,
 	pub validation_requests: Arc<Mutex<Vec<Extrinsic>>>,
+	pub block_bodies: Arc<Mutex<HashMap<H256, Vec<Extrinsic>>>>,
+	/// The blocks at which the validation never completes.
+	pub stuck_blocks: Arc<Mutex<HashSet<H256>>>,
 }
 
 impl TestApi {
@@ -67,7 +73,10 @@ impl TestApi {
 impl ChainApi for TestApi {
 	type Block = Block;
 	type Error = error::Error;
-	type ValidationFuture = futures::future::Ready<error::Result<TransactionValidity>>;
+	type ValidationFuture = futures::future::Either<
+		futures::future::Ready<error::Result<TransactionValidity>>,
+		futures::future::Pending<error::Result<TransactionValidity>>,
+	>;
 	type BodyFuture = futures::future::Ready<error::Result<Option<Vec<Extrinsic>>>>;
 
 	/// Verify extrinsic at given block.
@@ -153,7 +162,11 @@ impl ChainApi for TestApi {
 			_ => unimplemented!(),
 		};
 
-		futures::future::ready(Ok(res))
+		if self.stuck_blocks.lock().contains(&at) {
+			return futures::future::Either::Right(futures::future::pending())
+		}
+
+		futures::future::Either::Left(futures::future::ready(Ok(res)))
 	}
 
 	fn validate_transaction_blocking(
@@ -196,8 +209,8 @@ impl ChainApi for TestApi {
 		(Hashing::hash(&encoded), len)
 	}
 
-	fn block_body(&self, _id: <Self::Block as BlockT>::Hash) -> Self::BodyFuture {
-		futures::future::ready(Ok(None))
+	fn block_body(&self, id: <Self::Block as BlockT>::Hash) -> Self::BodyFuture {
+		futures::future::ready(Ok(self.block_bodies.lock().get(&id).cloned()))
 	}
 
 	fn block_header(
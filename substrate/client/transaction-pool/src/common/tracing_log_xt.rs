@@ -18,10 +18,41 @@
 
 //! Utility for logging transaction collections with tracing crate.
 
+/// Default value of [`MAX_LOGGED_XT`].
+const DEFAULT_MAX_LOGGED_XT: usize = 1000;
+
+/// Maximal number of transactions logged by a single `log_xt_*` invocation.
+///
+/// Collections exceeding this limit are truncated, so logging a flood of transactions does not
+/// become a bottleneck on its own. Defaults to [`DEFAULT_MAX_LOGGED_XT`].
+///
+/// The limit can be raised for deep debugging builds by setting the `SC_TXPOOL_MAX_LOGGED_XT`
+/// environment variable at build time, e.g. `SC_TXPOOL_MAX_LOGGED_XT=1000000 cargo build`.
+pub(crate) const MAX_LOGGED_XT: usize = match option_env!("SC_TXPOOL_MAX_LOGGED_XT") {
+	Some(value) => parse_max_logged_xt(value),
+	None => DEFAULT_MAX_LOGGED_XT,
+};
+
+/// Parses the build-time provided value of [`MAX_LOGGED_XT`].
+const fn parse_max_logged_xt(value: &str) -> usize {
+	let bytes = value.as_bytes();
+	assert!(!bytes.is_empty(), "SC_TXPOOL_MAX_LOGGED_XT must not be empty");
+	let mut result: usize = 0;
+	let mut i = 0;
+	while i < bytes.len() {
+		assert!(bytes[i].is_ascii_digit(), "SC_TXPOOL_MAX_LOGGED_XT must be a decimal number");
+		result = result * 10 + (bytes[i] - b'0') as usize;
+		i += 1;
+	}
+	result
+}
+
 /// Logs every transaction from given `tx_collection` with given level.
+///
+/// At most [`MAX_LOGGED_XT`] transactions are logged.
 macro_rules! log_xt {
     (data: hash, target: $target:expr, $level:expr, $tx_collection:expr, $text_with_format:expr) => {
-        for tx in $tx_collection {
+        for tx in $tx_collection.into_iter().take($crate::common::tracing_log_xt::MAX_LOGGED_XT) {
             tracing::event!(
                 $level,
                 target = $target,
@@ -31,7 +62,7 @@ macro_rules! log_xt {
         }
     };
     (data: hash, target: $target:expr, $level:expr, $tx_collection:expr, $text_with_format:expr, $($arg:expr),*) => {
-        for tx in $tx_collection {
+        for tx in $tx_collection.into_iter().take($crate::common::tracing_log_xt::MAX_LOGGED_XT) {
             tracing::event!(
                 $level,
                 target = $target,
@@ -42,7 +73,7 @@ macro_rules! log_xt {
         }
     };
     (data: tuple, target: $target:expr, $level:expr, $tx_collection:expr, $text_with_format:expr) => {
-        for tx in $tx_collection {
+        for tx in $tx_collection.into_iter().take($crate::common::tracing_log_xt::MAX_LOGGED_XT) {
             tracing::event!(
                 $level,
                 target = $target,
@@ -52,6 +83,29 @@ macro_rules! log_xt {
             );
         }
     };
+    (data: hash_priority, target: $target:expr, $level:expr, $tx_collection:expr, $text_with_format:expr) => {
+        for (hash, priority) in $tx_collection.into_iter().take($crate::common::tracing_log_xt::MAX_LOGGED_XT) {
+            tracing::event!(
+                $level,
+                target = $target,
+                tx_hash = format!("{:?}", hash),
+                priority = ?priority,
+                $text_with_format,
+            );
+        }
+    };
+    (data: hash_priority, target: $target:expr, $level:expr, $tx_collection:expr, $text_with_format:expr, $($arg:expr),*) => {
+        for (hash, priority) in $tx_collection.into_iter().take($crate::common::tracing_log_xt::MAX_LOGGED_XT) {
+            tracing::event!(
+                $level,
+                target = $target,
+                tx_hash = format!("{:?}", hash),
+                priority = ?priority,
+                $text_with_format,
+                $($arg),*
+            );
+        }
+    };
 }
 macro_rules! log_xt_trace {
     (data: $datatype:ident, target: $target:expr, $($arg:tt)+) => {
@@ -65,5 +119,18 @@ macro_rules! log_xt_trace {
     };
 }
 
+macro_rules! log_xt_debug {
+    (data: $datatype:ident, target: $target:expr, $($arg:tt)+) => {
+        $crate::common::tracing_log_xt::log_xt!(data: $datatype, target: $target, tracing::Level::DEBUG, $($arg)+);
+    };
+    (target: $target:expr, $tx_collection:expr, $text_with_format:expr) => {
+        $crate::common::tracing_log_xt::log_xt!(data: hash, target: $target, tracing::Level::DEBUG, $tx_collection, $text_with_format);
+    };
+    (target: $target:expr, $tx_collection:expr, $text_with_format:expr, $($arg:expr)*) => {
+        $crate::common::tracing_log_xt::log_xt!(data: hash, target: $target, tracing::Level::DEBUG, $tx_collection, $text_with_format, $($arg)*);
+    };
+}
+
 pub(crate) use log_xt;
+pub(crate) use log_xt_debug;
 pub(crate) use log_xt_trace;
@@ -22,10 +22,13 @@ use super::{
 	dropped_watcher::{MultiViewDroppedWatcherController, StreamOfDropped},
 	import_notification_sink::MultiViewImportNotificationSink,
 	metrics::MetricsLink as PrometheusMetrics,
-	multi_view_listener::MultiViewListener,
+	multi_view_listener::{ExternalWatcherStream, MultiViewListener, TxStatusStream},
 	tx_mem_pool::{InsertionInfo, TxMemPool, TXMEMPOOL_TRANSACTION_LIMIT_MULTIPLIER},
 	view::View,
-	view_store::ViewStore,
+	view_store::{
+		all_views_saturated, submit_results_by_hash, TxDivergence, ViewPin, ViewStore,
+		ViewStoreEvent, ViewStoreSnapshot,
+	},
 };
 use crate::{
 	api::FullChainApi,
@@ -72,6 +75,9 @@ use std::{
 use tokio::select;
 use tracing::{debug, info, trace, warn};
 
+/// The number of view lifecycle events recorded by the view store of the pools intended for tests.
+const TEST_VIEW_STORE_EVENT_LOG_CAPACITY: usize = 512;
+
 /// Fork aware transaction pool task, that needs to be polled.
 pub type ForkAwareTxPoolTask = Pin<Box<dyn Future<Output = ()> + Send>>;
 
@@ -210,8 +216,12 @@ where
 		let (dropped_stream_controller, dropped_stream) =
 			MultiViewDroppedWatcherController::<ChainApi>::new();
 
-		let view_store =
-			Arc::new(ViewStore::new(pool_api.clone(), listener, dropped_stream_controller));
+		let view_store = Arc::new(ViewStore::new(
+			pool_api.clone(),
+			listener,
+			dropped_stream_controller,
+			Some(TEST_VIEW_STORE_EVENT_LOG_CAPACITY),
+		));
 
 		let dropped_monitor_task = Self::dropped_monitor_task(
 			dropped_stream,
@@ -304,6 +314,9 @@ where
 	///
 	/// The txpool essential tasks (including a revalidation worker) are spawned using provided
 	/// spawner.
+	///
+	/// If [`Options::view_store_event_log_capacity`] is set, the view store records the most recent
+	/// view lifecycle events, refer to [`Self::recent_view_store_events`].
 	pub fn new_with_background_worker(
 		options: Options,
 		is_validator: IsValidator,
@@ -335,8 +348,24 @@ where
 		let (dropped_stream_controller, dropped_stream) =
 			MultiViewDroppedWatcherController::<ChainApi>::new();
 
-		let view_store =
-			Arc::new(ViewStore::new(pool_api.clone(), listener, dropped_stream_controller));
+		let view_store = Arc::new(ViewStore::new(
+			pool_api.clone(),
+			listener,
+			dropped_stream_controller,
+			options.view_store_event_log_capacity,
+		));
+		view_store.on_view_created({
+			let metrics = metrics.clone();
+			move |_| metrics.report(|metrics| metrics.created_views.inc())
+		});
+		view_store.on_views_removed({
+			let metrics = metrics.clone();
+			move |removed_views| {
+				metrics.report(|metrics| {
+					metrics.finalized_removed_views.inc_by(removed_views.len() as _)
+				})
+			}
+		});
 		let dropped_monitor_task = Self::dropped_monitor_task(
 			dropped_stream,
 			mempool.clone(),
@@ -387,9 +416,26 @@ where
 		self.view_store.active_views.read().len()
 	}
 
-	/// Provides a number of views at the tips of the forks.
+	/// Provides a number of retracted views, no longer at the tips of the forks.
 	pub fn inactive_views_count(&self) -> usize {
-		self.view_store.inactive_views.read().len()
+		self.view_store.retracted_view_count()
+	}
+
+	/// Provides the hashes of the retracted views, no longer at the tips of the forks.
+	pub fn inactive_views_hashes(&self) -> Vec<Block::Hash> {
+		self.view_store.retracted_hashes()
+	}
+
+	/// Retracts the views at the tips of the forks whose block does not satisfy the given
+	/// predicate.
+	///
+	/// Shall not be called concurrently with the maintain process. Returns the block hashes of the
+	/// retracted views.
+	pub fn retain_views(
+		&self,
+		predicate: impl Fn(&HashAndNumber<Block>) -> bool,
+	) -> Vec<Block::Hash> {
+		self.view_store.retain_views(|view| predicate(&view.at))
 	}
 
 	/// Provides internal views statistics.
@@ -405,11 +451,183 @@ where
 			.collect()
 	}
 
+	/// Pins the view at given block hash, exempting it from the removal on finalization as long as
+	/// the returned guard is held.
+	///
+	/// Intended for long-running subscriptions watching the transactions at the specific block.
+	/// Returns `None` if there is no view at given hash.
+	pub fn pin_view(&self, at: Block::Hash) -> Option<ViewPin<Block::Hash>> {
+		self.view_store.pin_view(at)
+	}
+
+	/// Provides the hashes of all the views associated with the blocks at given number.
+	///
+	/// There may be several such views, one for every fork.
+	pub fn views_at_number(&self, number: NumberFor<Block>) -> Vec<Block::Hash> {
+		self.view_store
+			.views_at_number(number)
+			.into_iter()
+			.map(|view| view.at.hash)
+			.collect()
+	}
+
 	/// Checks if there is a view at the tip of the fork with given hash.
 	pub fn has_view(&self, hash: &Block::Hash) -> bool {
 		self.view_store.active_views.read().contains_key(hash)
 	}
 
+	/// Checks if the transaction with given hash is present in any of the views.
+	///
+	/// This is cheaper than fetching the transaction itself when only membership matters.
+	pub fn contains_transaction(&self, tx_hash: &ExtrinsicHash<ChainApi>) -> bool {
+		self.view_store.contains_transaction(tx_hash)
+	}
+
+	/// Returns the number of distinct transactions held by the views.
+	///
+	/// The transactions are deduplicated across the views, so a transaction present on several
+	/// forks is counted once.
+	pub fn transaction_count(&self) -> usize {
+		self.view_store.transaction_count()
+	}
+
+	/// Removes the view at given block hash and returns its ready and future transactions.
+	///
+	/// Intended for the view teardown, allowing pending transactions to be migrated elsewhere.
+	/// Returns `None` if there is no such view.
+	pub fn drain_view_transactions(&self, at: Block::Hash) -> Option<Vec<ExtrinsicFor<ChainApi>>> {
+		self.view_store.drain_view_transactions(at)
+	}
+
+	/// Prunes the given transactions from the view at given block hash, leaving other views
+	/// untouched.
+	///
+	/// Intended for transactions known to be included in that specific block. Returns the number
+	/// of removed transactions, or `None` if there is no such view.
+	pub fn prune_in_view(
+		&self,
+		at: Block::Hash,
+		hashes: &[ExtrinsicHash<ChainApi>],
+	) -> Option<usize> {
+		self.view_store.prune_in_view(at, hashes)
+	}
+
+	/// Rebuilds the view store from the given block.
+	///
+	/// Intended as a recovery mechanism, short of restarting the node, when the view store is
+	/// found to be inconsistent. All the views are removed and a fresh view at `base` is built and
+	/// filled with the transactions from the mempool. All in-flight external watchers are
+	/// terminated with the `Dropped` event.
+	///
+	/// Returns `false` if the view store could not be rebuilt: either there is no view to take the
+	/// configuration from, or the number of the `base` block is unknown.
+	pub async fn rebuild_from(&self, base: Block::Hash) -> bool {
+		let Some(view) = self.view_store.rebuild_from(base, self.api.clone()).await else {
+			return false
+		};
+		self.import_notification_sink.add_view(
+			view.at.hash,
+			view.pool.validated_pool().import_notification_stream().boxed(),
+		);
+		self.update_view_with_mempool(&view).await;
+		self.view_store.reindex_view(&view);
+		true
+	}
+
+	/// Validates the given transactions against every active view, without submitting them.
+	///
+	/// Neither the views nor the mempool are altered. Returns the per-view validation results,
+	/// in the order of the given transactions.
+	pub async fn validate(
+		&self,
+		source: TransactionSource,
+		xts: Vec<Block::Extrinsic>,
+	) -> HashMap<Block::Hash, Vec<Result<ExtrinsicHash<ChainApi>, ChainApi::Error>>> {
+		let source = TimedTransactionSource::from_transaction_source(source, false);
+		let xts = xts.into_iter().map(|xt| (source.clone(), Arc::from(xt))).collect::<Vec<_>>();
+		self.view_store
+			.validate(xts)
+			.await
+			.into_iter()
+			.map(|(at, results)| (at, results.into_iter().map(|r| r.map(|r| r.hash())).collect()))
+			.collect()
+	}
+
+	/// Starts to watch the transaction which is already present in the views.
+	///
+	/// Allows to subscribe to the transaction status after it was submitted without a watcher.
+	/// Returns `None` if no view holds the transaction or it is already watched.
+	pub async fn watch_existing_transaction(
+		&self,
+		tx_hash: ExtrinsicHash<ChainApi>,
+	) -> Option<TxStatusStream<ChainApi>> {
+		self.view_store.watch_existing_transaction(tx_hash).await
+	}
+
+	/// Submits a transaction and starts to watch its progress in the pool, returning a stream of
+	/// [`ExternalWatcherEvent`](super::ExternalWatcherEvent)s.
+	///
+	/// Behaves as [`TransactionPool::submit_and_watch`], but the first event of the stream also
+	/// carries the block the transaction was submitted at, allowing to correlate the subscription
+	/// with the fork it started on.
+	pub async fn submit_and_watch_at_block(
+		&self,
+		at: Block::Hash,
+		source: TransactionSource,
+		xt: ExtrinsicFor<ChainApi>,
+	) -> Result<ExternalWatcherStream<ChainApi>, ChainApi::Error> {
+		trace!(
+			target: LOG_TARGET,
+			tx_hash = ?self.tx_hash(&xt),
+			views = self.active_views_count(),
+			"fatp::submit_and_watch"
+		);
+
+		let InsertionInfo { hash: xt_hash, source: timed_source, .. } =
+			match self.mempool.push_watched(source, xt.clone()) {
+				Ok(result) => result,
+				Err(TxPoolApiError::ImmediatelyDropped) =>
+					self.attempt_transaction_replacement(source, true, xt.clone()).await?,
+				Err(e) => return Err(e.into()),
+			};
+
+		self.metrics.report(|metrics| metrics.submitted_transactions.inc());
+
+		self.view_store
+			.submit_and_watch(at, timed_source, xt)
+			.await
+			.inspect_err(|_| {
+				self.mempool.remove_transaction(&xt_hash);
+			})
+			.map(|mut outcome| {
+				self.mempool.update_transaction_priority(&outcome);
+				outcome.expect_watcher()
+			})
+	}
+
+	/// Captures the summary of all the views held by the pool.
+	///
+	/// Intended for debugging and tests.
+	pub fn export_snapshot(&self) -> ViewStoreSnapshot<Block::Hash, NumberFor<Block>> {
+		self.view_store.export_snapshot()
+	}
+
+	/// Lists the transactions whose ready/future classification differs across the active views.
+	///
+	/// The cost is O(views × pool), intended for occasional diagnostics only.
+	pub fn divergence_report(&self) -> Vec<TxDivergence<ExtrinsicHash<ChainApi>, Block::Hash>> {
+		self.view_store.divergence_report()
+	}
+
+	/// Returns the most recent view lifecycle events recorded by the view store, from the oldest
+	/// to the newest.
+	///
+	/// Intended for post-mortem debugging. The events are only recorded by the pools intended for
+	/// tests, or created with the view store event log enabled. The list is empty otherwise.
+	pub fn recent_view_store_events(&self) -> Vec<ViewStoreEvent<Block::Hash>> {
+		self.view_store.recent_events()
+	}
+
 	/// Returns a number of unwatched and watched transactions in internal mempool.
 	///
 	/// Intended for use in unit tests.
@@ -427,6 +645,17 @@ where
 		self.view_store.futures_at(at)
 	}
 
+	/// Returns the future transactions of all the views, each paired with the hash of the block of
+	/// the view holding it.
+	///
+	/// A transaction is reported once per view, so the list may be large on busy pools. Intended
+	/// for logging / diagnostics.
+	pub fn all_futures(
+		&self,
+	) -> Vec<(Block::Hash, Transaction<ExtrinsicHash<ChainApi>, ExtrinsicFor<ChainApi>>)> {
+		self.view_store.all_futures()
+	}
+
 	/// Returns a best-effort set of ready transactions for a given block, without executing full
 	/// maintain process.
 	///
@@ -731,7 +960,16 @@ where
 		// ... and submit them to the view_store. Please note that transactions rejected by mempool
 		// are not sent here.
 		let mempool = self.mempool.clone();
-		let results_map = view_store.submit(to_be_submitted.into_iter()).await;
+		let results = view_store.submit(to_be_submitted.into_iter()).await;
+		if all_views_saturated(&results) {
+			debug!(
+				target: LOG_TARGET,
+				views_count = results.len(),
+				"fatp::submit_at: all views are saturated"
+			);
+			self.metrics.report(|metrics| metrics.all_views_saturated_submissions.inc());
+		}
+		let results_map = submit_results_by_hash(results);
 		let mut submission_results = reduce_multiview_result(results_map).into_iter();
 
 		// Note for composing final result:
@@ -804,34 +1042,9 @@ where
 		source: TransactionSource,
 		xt: TransactionFor<Self>,
 	) -> Result<Pin<Box<TransactionStatusStreamFor<Self>>>, Self::Error> {
-		trace!(
-			target: LOG_TARGET,
-			tx_hash = ?self.tx_hash(&xt),
-			views = self.active_views_count(),
-			"fatp::submit_and_watch"
-		);
-		let xt = Arc::from(xt);
-
-		let InsertionInfo { hash: xt_hash, source: timed_source, .. } =
-			match self.mempool.push_watched(source, xt.clone()) {
-				Ok(result) => result,
-				Err(TxPoolApiError::ImmediatelyDropped) =>
-					self.attempt_transaction_replacement(source, true, xt.clone()).await?,
-				Err(e) => return Err(e.into()),
-			};
-
-		self.metrics.report(|metrics| metrics.submitted_transactions.inc());
-
-		self.view_store
-			.submit_and_watch(at, timed_source, xt)
+		self.submit_and_watch_at_block(at, source, Arc::from(xt))
 			.await
-			.inspect_err(|_| {
-				self.mempool.remove_transaction(&xt_hash);
-			})
-			.map(|mut outcome| {
-				self.mempool.update_transaction_priority(&outcome);
-				outcome.expect_watcher()
-			})
+			.map(|watcher| watcher.map(|event| event.status).boxed())
 	}
 
 	/// Intended to remove transactions identified by the given hashes, and any dependent
@@ -857,9 +1070,8 @@ where
 	/// maintain process was accomplished).
 	fn status(&self) -> PoolStatus {
 		self.view_store
-			.most_recent_view
-			.read()
-			.map(|hash| self.view_store.status()[&hash].clone())
+			.most_recent_view_hash()
+			.and_then(|hash| self.view_store.status().remove(&hash))
 			.unwrap_or(PoolStatus { ready: 0, ready_bytes: 0, future: 0, future_bytes: 0 })
 	}
 
@@ -887,7 +1099,7 @@ where
 	/// block (for which maintain process was accomplished).
 	// todo [#5491]: api change: we probably should have at here?
 	fn ready_transaction(&self, tx_hash: &TxHash<Self>) -> Option<Arc<Self::InPoolTransaction>> {
-		let most_recent_view = self.view_store.most_recent_view.read();
+		let most_recent_view = self.view_store.most_recent_view_hash();
 		let result = most_recent_view
 			.map(|block_hash| self.view_store.ready_transaction(block_hash, tx_hash))
 			.flatten();
@@ -1107,7 +1319,7 @@ where
 		// 2. Handle transactions from the tree route. Pruning transactions from the view first
 		// will make some space for mempool transactions in case we are at the view's limits.
 		let start = Instant::now();
-		self.update_view_with_fork(&view, tree_route, at.clone()).await;
+		let enacted_txs = self.update_view_with_fork(&view, tree_route).await;
 		debug!(
 			target: LOG_TARGET,
 			?at,
@@ -1125,7 +1337,16 @@ where
 			"update_view_with_mempool"
 		);
 		let view = Arc::from(view);
-		self.view_store.insert_new_view(view.clone(), tree_route).await;
+		let displaced_txs = self
+			.view_store
+			.insert_new_view_with_included(view.clone(), tree_route, &enacted_txs)
+			.await;
+		debug!(
+			target: LOG_TARGET,
+			?at,
+			displaced_txs_count = displaced_txs.len(),
+			"build_new_view"
+		);
 		Some(view)
 	}
 
@@ -1240,14 +1461,16 @@ where
 
 	/// Updates the view with the transactions from the given tree route.
 	///
-	/// Transactions from the retracted blocks are resubmitted to the given view. Tags for
-	/// transactions included in blocks on enacted fork are pruned from the provided view.
+	/// Transactions from the retracted blocks are resubmitted to the given view, refer to
+	/// [`ViewStore::resubmit_retracted`] for details. Tags for transactions included in blocks on
+	/// enacted fork are pruned from the provided view.
+	///
+	/// Returns the hashes of the transactions included in the blocks on the enacted fork.
 	async fn update_view_with_fork(
 		&self,
 		view: &View<ChainApi>,
 		tree_route: &TreeRoute<Block>,
-		hash_and_number: HashAndNumber<Block>,
-	) {
+	) -> HashSet<ExtrinsicHash<ChainApi>> {
 		debug!(
 			target: LOG_TARGET,
 			?tree_route,
@@ -1333,8 +1556,12 @@ where
 				});
 			}
 
-			let _ = view.pool.resubmit_at(&hash_and_number, resubmit_transactions).await;
+			self.view_store
+				.resubmit_retracted(view, tree_route, resubmit_transactions)
+				.await;
 		}
+
+		pruned_log
 	}
 
 	/// Executes the maintainance for the finalized event.
@@ -1404,8 +1631,7 @@ where
 	) -> Result<InsertionInfo<ExtrinsicHash<ChainApi>>, TxPoolApiError> {
 		let at = self
 			.view_store
-			.most_recent_view
-			.read()
+			.most_recent_view_hash()
 			.ok_or(TxPoolApiError::ImmediatelyDropped)?;
 
 		let (best_view, _) = self
@@ -1440,8 +1666,7 @@ where
 	) -> Result<InsertionInfo<ExtrinsicHash<ChainApi>>, TxPoolApiError> {
 		let at = self
 			.view_store
-			.most_recent_view
-			.read()
+			.most_recent_view_hash()
 			.ok_or(TxPoolApiError::ImmediatelyDropped)?;
 
 		let ValidTransaction { priority, .. } = self
@@ -59,6 +59,12 @@ pub struct Metrics {
 	pub view_revalidation_duration: Histogram,
 	/// Total number of the views created w/o cloning existing view.
 	pub non_cloned_views: Counter<U64>,
+	/// Total number of the views inserted into the view store.
+	pub created_views: Counter<U64>,
+	/// Total number of the views removed from the view store on finalization.
+	pub finalized_removed_views: Counter<U64>,
+	/// Total number of submissions skipped by all the views, as every view was full.
+	pub all_views_saturated_submissions: Counter<U64>,
 }
 
 impl MetricsRegistrant for Metrics {
@@ -171,6 +177,27 @@ impl MetricsRegistrant for Metrics {
 				)?,
 				registry,
 			)?,
+			created_views: register(
+				Counter::new(
+					"substrate_sub_txpool_created_views_total",
+					"Total number of the views inserted into the view store.",
+				)?,
+				registry,
+			)?,
+			finalized_removed_views: register(
+				Counter::new(
+					"substrate_sub_txpool_finalized_removed_views_total",
+					"Total number of the views removed from the view store on finalization.",
+				)?,
+				registry,
+			)?,
+			all_views_saturated_submissions: register(
+				Counter::new(
+					"substrate_sub_txpool_all_views_saturated_submissions_total",
+					"Total number of submissions skipped by all the views, as every view was full.",
+				)?,
+				registry,
+			)?,
 		}))
 	}
 }
@@ -374,6 +374,11 @@ mod view;
 mod view_store;
 
 pub use fork_aware_txpool::{ForkAwareTxPool, ForkAwareTxPoolTask};
+pub use multi_view_listener::{ExternalWatcherEvent, ExternalWatcherStream};
+pub use view_store::{
+	TxClassification, TxDivergence, ViewPin, ViewSnapshot, ViewStoreEvent, ViewStoreEventKind,
+	ViewStoreSnapshot,
+};
 
 mod stream_map_util {
 	use futures::Stream;
@@ -57,6 +57,22 @@ type CommandReceiver<T> = mpsc::TracingUnboundedReceiver<T>;
 /// It can represent both a single view's stream and an external watcher stream.
 pub type TxStatusStream<T> = Pin<Box<TransactionStatusStream<ExtrinsicHash<T>, BlockHash<T>>>>;
 
+/// The event sent out by the external watcher of the submitted transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalWatcherEvent<Hash, BlockHash> {
+	/// The status of the transaction.
+	pub status: TransactionStatus<Hash, BlockHash>,
+	/// The block the transaction was submitted at.
+	///
+	/// Only set for the first event of the stream, allowing to correlate the subscription with the
+	/// block context it started in.
+	pub submitted_at: Option<BlockHash>,
+}
+
+/// The stream of the [`ExternalWatcherEvent`]s of the submitted transaction.
+pub type ExternalWatcherStream<T> =
+	Pin<Box<dyn Stream<Item = ExternalWatcherEvent<ExtrinsicHash<T>, BlockHash<T>>> + Send>>;
+
 /// An aggregated stream providing events for all transactions from the view.
 ///
 /// This stream delivers updates for all transactions in the view, rather than for individual
@@ -73,6 +89,9 @@ enum ControllerCommand<ChainApi: graph::ChainApi> {
 	AddViewStream(BlockHash<ChainApi>, ViewStatusStream<ChainApi>),
 	/// Removes an existing view's stream associated with a specific block hash.
 	RemoveViewStream(BlockHash<ChainApi>),
+	/// Removes the controller of the external watcher of given transaction, provided that the
+	/// watcher's stream was dropped. Sent by the external watcher context when dropped.
+	RemoveExternalWatcher(ExtrinsicHash<ChainApi>),
 }
 
 /// Represents the transaction status update performed by transaction pool state machine. The
@@ -143,6 +162,7 @@ where
 		match self {
 			ControllerCommand::AddViewStream(h, _) => write!(f, "AddView({h})"),
 			ControllerCommand::RemoveViewStream(h) => write!(f, "RemoveView({h})"),
+			ControllerCommand::RemoveExternalWatcher(h) => write!(f, "RemoveExternalWatcher({h})"),
 			ControllerCommand::TransactionStatusRequest(c) => {
 				write!(f, "TransactionStatusRequest({c:?})")
 			},
@@ -224,6 +244,12 @@ pub type MultiViewListenerTask = Pin<Box<dyn Future<Output = ()> + Send>>;
 struct ExternalWatcherContext<ChainApi: graph::ChainApi> {
 	/// The hash of the transaction being monitored within this context.
 	tx_hash: ExtrinsicHash<ChainApi>,
+	/// The block the transaction was submitted at, if known.
+	///
+	/// The transaction is submitted to all the views regardless of this block, it is only kept to
+	/// correlate the watcher with the block context the caller started in. It is taken by the
+	/// first event sent out.
+	submitted_at: Option<BlockHash<ChainApi>>,
 	/// A receiver for controller commands sent by [`MultiViewListener`]'s task.
 	command_receiver: CommandReceiver<ExternalWatcherCommand<ChainApi>>,
 	/// A flag indicating whether the context should terminate.
@@ -237,6 +263,29 @@ struct ExternalWatcherContext<ChainApi: graph::ChainApi> {
 	/// The set of views (represented by block hashes) currently maintained by the transaction
 	/// pool.
 	known_views: HashSet<BlockHash<ChainApi>>,
+	/// The controller of the [`MultiViewListener`]'s task, used to request the removal of this
+	/// watcher's controller once the context is dropped.
+	listener_controller: Controller<ControllerCommand<ChainApi>>,
+}
+
+impl<ChainApi: graph::ChainApi> Drop for ExternalWatcherContext<ChainApi> {
+	fn drop(&mut self) {
+		// The external stream was either terminated or dropped by its user (e.g. RPC client
+		// disconnected). Close the side channel, so the listener's task can tell this controller
+		// is stale, and let the task clean it up.
+		self.command_receiver.close();
+		if let Err(error) = self
+			.listener_controller
+			.unbounded_send(ControllerCommand::RemoveExternalWatcher(self.tx_hash))
+		{
+			trace!(
+				target: LOG_TARGET,
+				tx_hash = ?self.tx_hash,
+				%error,
+				"external watcher drop: send message failed"
+			);
+		}
+	}
 }
 
 /// Commands to control the single external stream living within the multi view listener. These
@@ -261,20 +310,26 @@ where
 {
 	/// Creates new `ExternalWatcherContext` for particular transaction identified by `tx_hash`
 	///
-	/// The `command_receiver` is a side channel for receiving controller's
-	/// [commands][`ExternalWatcherCommand`].
+	/// The `submitted_at` is the block the transaction was submitted at, if known. The
+	/// `command_receiver` is a side channel for receiving controller's
+	/// [commands][`ExternalWatcherCommand`]. The `listener_controller` is used to notify the
+	/// listener's task when the context is dropped.
 	fn new(
 		tx_hash: ExtrinsicHash<ChainApi>,
+		submitted_at: Option<BlockHash<ChainApi>>,
 		command_receiver: CommandReceiver<ExternalWatcherCommand<ChainApi>>,
+		listener_controller: Controller<ControllerCommand<ChainApi>>,
 	) -> Self {
 		Self {
 			tx_hash,
+			submitted_at,
 			command_receiver,
 			terminate: false,
 			future_seen: false,
 			ready_seen: false,
 			views_keeping_tx_valid: Default::default(),
 			known_views: Default::default(),
+			listener_controller,
 		}
 	}
 
@@ -378,6 +433,18 @@ where
 		}
 	}
 
+	/// Builds the event sending out the given `status`.
+	///
+	/// The first event sent out carries the block the transaction was submitted at, if known.
+	fn new_event(
+		&mut self,
+		status: TransactionStatus<ExtrinsicHash<ChainApi>, BlockHash<ChainApi>>,
+	) -> ExternalWatcherEvent<ExtrinsicHash<ChainApi>, BlockHash<ChainApi>> {
+		let submitted_at = self.submitted_at.take();
+		trace!(target: LOG_TARGET, tx_hash = ?self.tx_hash, ?status, ?submitted_at, "mvl sending out");
+		ExternalWatcherEvent { status, submitted_at }
+	}
+
 	/// Handles transaction invalidation sent via side channel.
 	///
 	/// Function may set the context termination flag, which will close the stream.
@@ -508,6 +575,16 @@ where
 							})
 						},
 
+						Some(ControllerCommand::RemoveExternalWatcher(tx_hash)) => {
+							// A newer watcher may have been created for the same transaction in the
+							// meantime, only remove the controller if it is stale.
+							if let Entry::Occupied(ctrl) = external_watchers_tx_hash_map.write().entry(tx_hash) {
+								if ctrl.get().is_closed() {
+									trace!(target: LOG_TARGET, ?tx_hash, "external watcher removed");
+									ctrl.remove();
+								}
+							}
+						},
 						Some(ControllerCommand::TransactionStatusRequest(request)) => {
 							let tx_hash = request.hash();
 							if let Entry::Occupied(mut ctrl) = external_watchers_tx_hash_map.write().entry(tx_hash) {
@@ -561,6 +638,28 @@ where
 		&self,
 		tx_hash: ExtrinsicHash<ChainApi>,
 	) -> Option<TxStatusStream<ChainApi>> {
+		self.create_external_watcher(tx_hash, None)
+			.map(|stream| stream.map(|event| event.status).boxed())
+	}
+
+	/// Creates an external stream of events for the transaction submitted at the given block.
+	///
+	/// Works as [`Self::create_external_watcher_for_tx`], but the first event of the stream also
+	/// carries the `submitted_at` block.
+	pub(crate) fn create_external_watcher_for_submitted_tx(
+		&self,
+		tx_hash: ExtrinsicHash<ChainApi>,
+		submitted_at: BlockHash<ChainApi>,
+	) -> Option<ExternalWatcherStream<ChainApi>> {
+		self.create_external_watcher(tx_hash, Some(submitted_at))
+	}
+
+	/// Creates an external stream of [`ExternalWatcherEvent`]s for given transaction.
+	fn create_external_watcher(
+		&self,
+		tx_hash: ExtrinsicHash<ChainApi>,
+		submitted_at: Option<BlockHash<ChainApi>>,
+	) -> Option<ExternalWatcherStream<ChainApi>> {
 		let external_ctx = match self.external_controllers.write().entry(tx_hash) {
 			Entry::Occupied(_) => return None,
 			Entry::Vacant(entry) => {
@@ -570,15 +669,11 @@ where
 					EXT_CONTROLLER_QUEUE_WARN_THRESHOLD,
 				);
 				entry.insert(tx);
-				ExternalWatcherContext::new(tx_hash, rx)
+				ExternalWatcherContext::new(tx_hash, submitted_at, rx, self.controller.clone())
 			},
 		};
 
-		trace!(
-			target: LOG_TARGET,
-			?tx_hash,
-			"create_external_watcher_for_tx"
-		);
+		trace!(target: LOG_TARGET, ?tx_hash, ?submitted_at, "create_external_watcher");
 
 		Some(
 			futures::stream::unfold(external_ctx, |mut ctx| async move {
@@ -592,24 +687,12 @@ where
 							match cmd? {
 								ExternalWatcherCommand::ViewTransactionStatus(view_hash, status) => {
 									if let Some(new_status) = ctx.handle_view_transaction_status(view_hash, status) {
-										trace!(
-											target: LOG_TARGET,
-											tx_hash = ?ctx.tx_hash,
-											?new_status,
-											"mvl sending out"
-										);
-										return Some((new_status, ctx))
+										return Some((ctx.new_event(new_status), ctx))
 									}
 								},
 								ExternalWatcherCommand::PoolTransactionStatus(request) => {
 									if let Some(new_status) = ctx.handle_pool_transaction_status(request) {
-										trace!(
-											target: LOG_TARGET,
-											tx_hash = ?ctx.tx_hash,
-											?new_status,
-											"mvl sending out"
-										);
-										return Some((new_status, ctx))
+										return Some((ctx.new_event(new_status), ctx))
 									}
 								}
 								ExternalWatcherCommand::AddView(h) => {
@@ -760,10 +843,71 @@ where
 		};
 	}
 
+	/// Notifies the external watcher of given transaction about the transaction's status in the
+	/// view associated with `block_hash`.
+	///
+	/// Intended for watchers created after the transaction was imported into the view, as such
+	/// watchers missed the events already emitted by the view's stream. The view is also added to
+	/// the set of views known to the watcher.
+	pub(crate) fn transaction_view_status(
+		&self,
+		tx_hash: ExtrinsicHash<ChainApi>,
+		block_hash: BlockHash<ChainApi>,
+		status: TransactionStatus<ExtrinsicHash<ChainApi>, BlockHash<ChainApi>>,
+	) {
+		trace!(target: LOG_TARGET, ?tx_hash, ?block_hash, ?status, "transaction_view_status");
+		if let Entry::Occupied(mut ctrl) = self.external_controllers.write().entry(tx_hash) {
+			let result = ctrl
+				.get_mut()
+				.unbounded_send(ExternalWatcherCommand::AddView(block_hash))
+				.and_then(|_| {
+					ctrl.get_mut().unbounded_send(ExternalWatcherCommand::ViewTransactionStatus(
+						block_hash, status,
+					))
+				});
+			if let Err(error) = result {
+				trace!(
+					target: LOG_TARGET,
+					?tx_hash,
+					%error,
+					"transaction_view_status: send message failed"
+				);
+				ctrl.remove();
+			}
+		}
+	}
+
 	/// Removes stale controllers.
 	pub(crate) fn remove_stale_controllers(&self) {
 		self.external_controllers.write().retain(|_, c| !c.is_closed());
 	}
+
+	/// Removes the controllers of all the external watchers.
+	///
+	/// Every external stream is sent the terminal [`TransactionStatus::Dropped`] event before its
+	/// controller is removed, so the watchers are not closed silently.
+	pub(crate) fn remove_all_external_watchers(&self) {
+		for (tx_hash, ctrl) in self.external_controllers.write().drain() {
+			let dropped =
+				TransactionStatusUpdate::TransactionDropped(tx_hash, DroppedReason::LimitsEnforced);
+			if let Err(error) =
+				ctrl.unbounded_send(ExternalWatcherCommand::PoolTransactionStatus(dropped))
+			{
+				trace!(
+					target: LOG_TARGET,
+					?tx_hash,
+					%error,
+					"remove_all_external_watchers: send message failed"
+				);
+			}
+		}
+	}
+
+	/// Returns the number of controllers of the external watchers.
+	#[cfg(test)]
+	pub(crate) fn external_watchers_count(&self) -> usize {
+		self.external_controllers.read().len()
+	}
 }
 
 #[cfg(test)]
@@ -1072,4 +1216,76 @@ mod tests {
 		let _ = terminate_listener.send(());
 		let _ = listener_task.await.unwrap();
 	}
+
+	#[tokio::test]
+	async fn dropping_external_watcher_removes_its_controller() {
+		sp_tracing::try_init_simple();
+		let (listener, terminate_listener, listener_task) = create_multi_view_listener();
+
+		let tx_hash0 = H256::repeat_byte(0x0a);
+		let tx_hash1 = H256::repeat_byte(0x0b);
+		let external_watcher0 = listener.create_external_watcher_for_tx(tx_hash0).unwrap();
+		let external_watcher1 = listener.create_external_watcher_for_tx(tx_hash1).unwrap();
+		assert_eq!(listener.external_watchers_count(), 2);
+
+		// Stream was never polled, the cleanup shall not depend on it.
+		drop(external_watcher0);
+		drop(external_watcher1);
+
+		tokio::time::timeout(std::time::Duration::from_secs(5), async {
+			while listener.external_watchers_count() > 0 {
+				tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+			}
+		})
+		.await
+		.expect("external watchers shall be removed once their streams are dropped");
+
+		// The transaction can be watched again.
+		assert!(listener.create_external_watcher_for_tx(tx_hash0).is_some());
+		assert_eq!(listener.external_watchers_count(), 1);
+
+		let _ = terminate_listener.send(());
+		let _ = listener_task.await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn submitted_at_is_carried_by_first_event() {
+		sp_tracing::try_init_simple();
+		let (listener, terminate_listener, listener_task) = create_multi_view_listener();
+
+		let block_hash = H256::repeat_byte(0x01);
+		let submitted_at = H256::repeat_byte(0x02);
+		let tx_hash = H256::repeat_byte(0x0a);
+		let events = vec![
+			TransactionStatus::Ready,
+			TransactionStatus::InBlock((block_hash, 0)),
+			TransactionStatus::Finalized((block_hash, 0)),
+		];
+
+		let external_watcher = listener
+			.create_external_watcher_for_submitted_tx(tx_hash, submitted_at)
+			.unwrap();
+		let handle = tokio::spawn(async move { external_watcher.collect::<Vec<_>>().await });
+
+		let view_stream =
+			futures::stream::iter(std::iter::repeat(tx_hash).zip(events.clone().into_iter()));
+
+		listener.add_view_aggregated_stream(block_hash, view_stream.boxed());
+
+		let out = handle.await.unwrap();
+		assert_eq!(
+			out,
+			events
+				.into_iter()
+				.enumerate()
+				.map(|(i, status)| ExternalWatcherEvent {
+					status,
+					submitted_at: (i == 0).then_some(submitted_at),
+				})
+				.collect::<Vec<_>>()
+		);
+
+		let _ = terminate_listener.send(());
+		let _ = listener_task.await.unwrap();
+	}
 }
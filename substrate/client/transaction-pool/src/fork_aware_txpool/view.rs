@@ -155,6 +155,21 @@ where
 		}
 	}
 
+	/// Creates a new empty view sharing the configuration of the other view.
+	pub(super) fn new_empty_like(
+		&self,
+		api: Arc<ChainApi>,
+		at: &HashAndNumber<ChainApi::Block>,
+	) -> Self {
+		self.metrics.report(|metrics| metrics.non_cloned_views.inc());
+		View {
+			at: at.clone(),
+			pool: self.pool.new_empty_like(api),
+			revalidation_worker_channels: Mutex::from(None),
+			metrics: self.metrics.clone(),
+		}
+	}
+
 	/// Imports single unvalidated extrinsic into the view.
 	pub(super) async fn submit_one(
 		&self,
@@ -185,6 +200,14 @@ where
 		}
 	}
 
+	/// Validates many unvalidated extrinsics against the view without importing them.
+	pub(super) async fn validate_many(
+		&self,
+		xts: impl IntoIterator<Item = (TimedTransactionSource, ExtrinsicFor<ChainApi>)>,
+	) -> Vec<Result<ValidatedPoolSubmitOutcome<ChainApi>, ChainApi::Error>> {
+		self.pool.validate_at(&self.at, xts).await
+	}
+
 	/// Synchronously imports single unvalidated extrinsics into the view.
 	pub(super) fn submit_local(
 		&self,
@@ -238,6 +261,19 @@ where
 		self.pool.validated_pool().status()
 	}
 
+	/// Prunes the given transactions, known to be included in the view's block, from the pool
+	/// associated with the view.
+	///
+	/// Refer to [`crate::graph::Pool::prune_known`] for more details.
+	pub(super) fn prune_known(&self, hashes: &[ExtrinsicHash<ChainApi>]) -> usize {
+		self.pool.prune_known(&self.at, hashes)
+	}
+
+	/// Returns true if the pool associated with the view has reached its limits.
+	pub(super) fn is_full(&self) -> bool {
+		self.pool.validated_pool().is_full()
+	}
+
 	/// Revalidates some part of transaction from the internal pool.
 	///
 	/// Intended to be called from the revalidation worker. The revalidation process can be
@@ -19,29 +19,41 @@
 //! Transaction pool view store. Basically block hash to view map with some utility methods.
 
 use super::{
-	multi_view_listener::{MultiViewListener, TxStatusStream},
+	multi_view_listener::{ExternalWatcherStream, MultiViewListener, TxStatusStream},
 	view::View,
 };
 use crate::{
+	common::tracing_log_xt::log_xt_debug,
 	fork_aware_txpool::dropped_watcher::MultiViewDroppedWatcherController,
 	graph::{
 		self,
 		base_pool::{TimedTransactionSource, Transaction},
-		BaseSubmitOutcome, ExtrinsicFor, ExtrinsicHash, TransactionFor, ValidatedPoolSubmitOutcome,
+		BaseSubmitOutcome, BlockHash, ExtrinsicFor, ExtrinsicHash, TransactionFor,
+		ValidatedPoolSubmitOutcome, ValidatedTransaction,
 	},
 	ReadyIteratorFor, LOG_TARGET,
 };
+use futures::{
+	future::{AbortRegistration, Abortable},
+	stream::FuturesUnordered,
+	Future, StreamExt,
+};
 use itertools::Itertools;
-use parking_lot::RwLock;
-use sc_transaction_pool_api::{error::Error as PoolError, PoolStatus};
-use sp_blockchain::TreeRoute;
-use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use parking_lot::{Mutex, RwLock};
+use sc_transaction_pool_api::{error::Error as PoolError, PoolStatus, TransactionStatus};
+use serde::Serialize;
+use sp_blockchain::{HashAndNumber, TreeRoute};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{AtLeast32BitUnsigned, Block as BlockT, NumberFor},
+};
 use std::{
-	collections::{hash_map::Entry, HashMap, HashSet},
+	collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
 	sync::Arc,
-	time::Instant,
+	time::{Duration, Instant},
 };
-use tracing::{trace, warn};
+use tokio::select;
+use tracing::{debug, trace, warn};
 
 /// Helper struct to maintain the context for pending transaction submission, executed for
 /// newly inserted views.
@@ -61,6 +73,12 @@ where
 type RemovalListener<ChainApi> =
 	Arc<dyn Fn(&mut crate::graph::Listener<ChainApi>, ExtrinsicHash<ChainApi>) + Send + Sync>;
 
+/// Callback invoked after a new view is inserted into the [`ViewStore`].
+type ViewCreatedCallback<ChainApi> = Arc<Mutex<dyn FnMut(&Arc<View<ChainApi>>) + Send>>;
+
+/// Callback invoked with the hashes of the views removed from the [`ViewStore`] on finalization.
+type ViewsRemovedCallback<Hash> = Arc<Mutex<dyn FnMut(&[Hash]) + Send>>;
+
 /// Helper struct to maintain the context for pending transaction removal, executed for
 /// newly inserted views.
 struct PendingTxRemoval<ChainApi>
@@ -165,11 +183,142 @@ where
 	///
 	/// The map's key is hash of actionable extrinsic (to avoid duplicated entries).
 	pending_txs_tasks: RwLock<HashMap<ExtrinsicHash<ChainApi>, PendingPreInsertTask<ChainApi>>>,
+	/// The index of transactions held by the views.
+	///
+	/// Maps the transaction hash to the hashes of the blocks of all the views (both active and
+	/// inactive) holding the transaction, so it can be found without scanning every view. The
+	/// index is updated when views are inserted or removed and when transactions are submitted to
+	/// or removed from the views. Views may also drop transactions on their own (e.g. during
+	/// revalidation), so the entries are verified against the views on lookup.
+	///
+	/// Shall be locked after `active_views` and `inactive_views`.
+	tx_index: RwLock<HashMap<ExtrinsicHash<ChainApi>, HashSet<Block::Hash>>>,
+	/// The views exempted from the removal on finalization, refer to [`ViewStore::pin_view`].
+	///
+	/// Shall be locked after `active_views` and `inactive_views`.
+	pinned_views: ViewPins<Block::Hash>,
+	/// Callbacks registered with [`ViewStore::on_view_created`].
+	view_created_callbacks: Mutex<Vec<ViewCreatedCallback<ChainApi>>>,
+	/// Callbacks registered with [`ViewStore::on_views_removed`].
+	views_removed_callbacks: Mutex<Vec<ViewsRemovedCallback<Block::Hash>>>,
+	/// The log of the most recent view lifecycle events, if enabled.
+	///
+	/// Shall be locked last.
+	event_log: Option<EventLog<Block::Hash>>,
+	/// The transactions included in the recently finalized blocks.
+	///
+	/// Submissions of these transactions are rejected without being validated. Never held along
+	/// with any other lock.
+	recently_finalized: RwLock<RecentlyFinalized<ExtrinsicHash<ChainApi>, NumberFor<Block>>>,
+	/// The maximum time a single view is given to process the submitted transactions.
+	///
+	/// Refer to [`ViewStore::submit`].
+	submit_timeout: Duration,
+}
+
+/// The default maximum time a single view is given to process the submitted transactions.
+const SUBMIT_VIEW_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Views taking longer than this to process the submitted transactions are logged, together with
+/// the submitted transactions, at debug level.
+const SLOW_VIEW_SUBMIT_THRESHOLD: Duration = Duration::from_secs(1);
+
+/// The maximum number of distinct transactions kept in [`RecentlyFinalized`].
+const RECENTLY_FINALIZED_MAX_COUNT: usize = 8192;
+
+/// The number of blocks the transactions are kept in [`RecentlyFinalized`] for, counting from the
+/// most recently finalized block.
+const RECENTLY_FINALIZED_MAX_AGE: u32 = 64;
+
+/// The bounded set of the transactions included in the recently finalized blocks.
+struct RecentlyFinalized<Hash, Number> {
+	/// The finalized transactions, grouped by the number of finalized block, from the oldest.
+	blocks: VecDeque<(Number, Vec<Hash>)>,
+	/// The number of occurrences in `blocks` of every kept transaction.
+	hashes: HashMap<Hash, usize>,
+}
+
+impl<Hash, Number> Default for RecentlyFinalized<Hash, Number> {
+	fn default() -> Self {
+		Self { blocks: Default::default(), hashes: Default::default() }
+	}
+}
+
+impl<Hash, Number> RecentlyFinalized<Hash, Number>
+where
+	Hash: std::hash::Hash + Eq + Copy,
+	Number: AtLeast32BitUnsigned + Copy,
+{
+	/// Adds the transactions finalized with the block at given number.
+	///
+	/// The transactions older than [`RECENTLY_FINALIZED_MAX_AGE`] blocks are expired, and the
+	/// oldest ones are discarded until at most [`RECENTLY_FINALIZED_MAX_COUNT`] are kept.
+	fn insert(&mut self, number: Number, xts: Vec<Hash>) {
+		xts.iter().for_each(|xt| *self.hashes.entry(*xt).or_default() += 1);
+		self.blocks.push_back((number, xts));
+
+		let expired = number.saturating_sub(RECENTLY_FINALIZED_MAX_AGE.into());
+		while let Some((oldest, _)) = self.blocks.front() {
+			if *oldest > expired && self.hashes.len() <= RECENTLY_FINALIZED_MAX_COUNT {
+				break
+			}
+			self.remove_oldest();
+		}
+	}
+
+	fn remove_oldest(&mut self) {
+		let Some((_, xts)) = self.blocks.pop_front() else { return };
+		for xt in xts {
+			if let Entry::Occupied(mut entry) = self.hashes.entry(xt) {
+				*entry.get_mut() -= 1;
+				if *entry.get() == 0 {
+					entry.remove();
+				}
+			}
+		}
+	}
+
+	fn contains(&self, xt: &Hash) -> bool {
+		self.hashes.contains_key(xt)
+	}
+
+	fn is_empty(&self) -> bool {
+		self.hashes.is_empty()
+	}
+}
+
+/// The bounded in-memory log of the most recent [`ViewStoreEvent`]s.
+struct EventLog<Hash> {
+	/// The maximum number of events kept, the oldest events are discarded first.
+	capacity: usize,
+	/// The recorded events, from the oldest to the newest.
+	events: Mutex<VecDeque<ViewStoreEvent<Hash>>>,
+}
+
+impl<Hash: Clone> EventLog<Hash> {
+	fn new(capacity: usize) -> Self {
+		Self { capacity, events: Mutex::new(VecDeque::with_capacity(capacity)) }
+	}
+
+	fn record(&self, kind: ViewStoreEventKind<Hash>) {
+		if self.capacity == 0 {
+			return
+		}
+		let mut events = self.events.lock();
+		if events.len() == self.capacity {
+			events.pop_front();
+		}
+		events.push_back(ViewStoreEvent { timestamp: Instant::now(), kind });
+	}
+
+	fn events(&self) -> Vec<ViewStoreEvent<Hash>> {
+		self.events.lock().iter().cloned().collect()
+	}
 }
 
 /// Type alias to outcome of submission to `ViewStore`.
 pub(super) type ViewStoreSubmitOutcome<ChainApi> =
-	BaseSubmitOutcome<ChainApi, TxStatusStream<ChainApi>>;
+	BaseSubmitOutcome<ChainApi, ExternalWatcherStream<ChainApi>>;
 
 impl<ChainApi: graph::ChainApi> From<ValidatedPoolSubmitOutcome<ChainApi>>
 	for ViewStoreSubmitOutcome<ChainApi>
@@ -179,6 +328,216 @@ impl<ChainApi: graph::ChainApi> From<ValidatedPoolSubmitOutcome<ChainApi>>
 	}
 }
 
+/// The state of a view at the end of [`ViewStore::submit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ViewSubmitStatus {
+	/// The view was still active when the submission completed.
+	Active,
+	/// The view was removed from the active views while the submission was in flight, e.g. by a
+	/// concurrent reorg. Its outcomes no longer reflect the current topology, and the submission
+	/// should be retried against the new active views.
+	ViewRemovedDuringSubmit,
+	/// The submission was aborted before the view completed it, see
+	/// [`ViewStore::submit_abortable`]. There are no outcomes for the view.
+	Cancelled,
+}
+
+/// The outcomes of [`ViewStore::submit`] for a single view.
+pub(super) struct ViewSubmitResults<ChainApi: graph::ChainApi> {
+	/// The number of the block the view is associated with.
+	pub(super) number: NumberFor<ChainApi::Block>,
+	/// Whether the view was still active when the submission completed.
+	pub(super) status: ViewSubmitStatus,
+	/// Whether the view was full when the extrinsics were submitted, so it was skipped without
+	/// validating them.
+	pub(super) saturated: bool,
+	/// The outcomes, in the order of the submitted extrinsics. Empty for
+	/// [`ViewSubmitStatus::Cancelled`] views.
+	pub(super) results: Vec<Result<ViewStoreSubmitOutcome<ChainApi>, ChainApi::Error>>,
+}
+
+/// The outcomes of [`ViewStore::submit_one`].
+pub(super) struct SubmitOneResults<ChainApi: graph::ChainApi> {
+	/// The outcome for every active view.
+	pub(super) results:
+		HashMap<BlockHash<ChainApi>, Result<ValidatedPoolSubmitOutcome<ChainApi>, ChainApi::Error>>,
+	/// The views in which the extrinsic is ready after the submission. The views accepting it as
+	/// a future transaction are only present in `results`.
+	pub(super) ready_in: Vec<BlockHash<ChainApi>>,
+}
+
+/// Returns `true` if every view was full when the [`ViewStore::submit`] call producing the
+/// `results` was made, so every view was skipped and the extrinsics were not accepted anywhere.
+///
+/// Meant as a back-pressure signal for the pool frontend, which reports it with the
+/// `substrate_sub_txpool_all_views_saturated_submissions_total` metric. Returns `false` if there
+/// were no views.
+pub(super) fn all_views_saturated<ChainApi: graph::ChainApi>(
+	results: &HashMap<BlockHash<ChainApi>, ViewSubmitResults<ChainApi>>,
+) -> bool {
+	!results.is_empty() && results.values().all(|results| results.saturated)
+}
+
+/// Strips the block numbers from the outcomes returned by [`ViewStore::submit`].
+///
+/// [`ViewSubmitStatus::Cancelled`] views carry no outcomes and are skipped.
+pub(super) fn submit_results_by_hash<ChainApi: graph::ChainApi>(
+	results: HashMap<BlockHash<ChainApi>, ViewSubmitResults<ChainApi>>,
+) -> HashMap<BlockHash<ChainApi>, Vec<Result<ViewStoreSubmitOutcome<ChainApi>, ChainApi::Error>>> {
+	results
+		.into_iter()
+		.filter_map(|(at, results)| {
+			match results.status {
+				ViewSubmitStatus::Active => {},
+				ViewSubmitStatus::ViewRemovedDuringSubmit => {
+					debug!(target: LOG_TARGET, ?at, "submit: view removed during submission");
+				},
+				ViewSubmitStatus::Cancelled => {
+					debug!(target: LOG_TARGET, ?at, "submit: submission cancelled");
+					return None
+				},
+			}
+			Some((at, results.results))
+		})
+		.collect()
+}
+
+/// Expands the outcomes of a deduplicated batch back to the positions of the original batch.
+///
+/// `positions` maps every extrinsic of the original batch to its index in the deduplicated one,
+/// and `hashes` holds the hashes of the deduplicated extrinsics. The outcome of the first
+/// occurrence of an extrinsic is kept, and replicated for its duplicates. As errors can't be
+/// copied, duplicates of a rejected extrinsic are reported as [`PoolError::AlreadyImported`].
+fn replicate_duplicates<ChainApi: graph::ChainApi>(
+	results: Vec<Result<ViewStoreSubmitOutcome<ChainApi>, ChainApi::Error>>,
+	positions: &[usize],
+	hashes: &[ExtrinsicHash<ChainApi>],
+) -> Vec<Result<ViewStoreSubmitOutcome<ChainApi>, ChainApi::Error>> {
+	let mut results = results.into_iter().map(Some).collect::<Vec<_>>();
+	let mut first_occurrences = HashSet::new();
+	let is_first = positions.iter().map(|i| first_occurrences.insert(*i)).collect::<Vec<_>>();
+	// Duplicates are replicated before the outcome of their first occurrence is moved out.
+	let mut expanded = positions
+		.iter()
+		.zip(&is_first)
+		.rev()
+		.map(|(&i, &is_first)| {
+			if is_first {
+				return results[i].take().expect("The first occurrence is taken exactly once. qed.")
+			}
+			match results[i].as_ref().expect("The first occurrence is taken last. qed.") {
+				Ok(outcome) => Ok(ViewStoreSubmitOutcome::new(outcome.hash(), outcome.priority())),
+				Err(_) => Err(PoolError::AlreadyImported(Box::new(hashes[i])).into()),
+			}
+		})
+		.collect::<Vec<_>>();
+	expanded.reverse();
+	expanded
+}
+
+/// The summary of a single view, as captured by [`ViewStoreSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ViewSnapshot<Hash, Number> {
+	/// The hash of the block the view is associated with.
+	pub hash: Hash,
+	/// The number of the block the view is associated with.
+	pub number: Number,
+	/// The number of ready transactions in the view.
+	pub ready_count: usize,
+	/// The number of future transactions in the view.
+	pub future_count: usize,
+}
+
+/// The point-in-time summary of the views topology held by the view store.
+///
+/// Views are sorted by block number and then by hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ViewStoreSnapshot<Hash, Number> {
+	/// Views at the tips of the forks.
+	pub active_views: Vec<ViewSnapshot<Hash, Number>>,
+	/// Views at intermediary blocks that are no longer tips of the forks.
+	pub retracted_views: Vec<ViewSnapshot<Hash, Number>>,
+	/// Most recent block processed by the transaction pool.
+	pub most_recent_view: Option<Hash>,
+}
+
+/// The view lifecycle event, as recorded by the view store event log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViewStoreEventKind<Hash> {
+	/// The view at given block was inserted.
+	ViewInserted(Hash),
+	/// The view at given block was removed.
+	ViewRemoved(Hash),
+	/// The view at given block was moved to the retracted (inactive) views.
+	ViewRetracted(Hash),
+	/// The given block was finalized.
+	Finalized(Hash),
+	/// The most recent view was changed to the view at given block, or cleared.
+	MostRecentViewChanged(Option<Hash>),
+}
+
+/// The timestamped entry of the view store event log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewStoreEvent<Hash> {
+	/// The moment the event was recorded at.
+	pub timestamp: Instant,
+	/// The event itself.
+	pub kind: ViewStoreEventKind<Hash>,
+}
+
+/// The classification of a transaction within a single view, as reported by [`TxDivergence`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TxClassification {
+	/// The transaction is in the ready queue of the view.
+	Ready,
+	/// The transaction is in the future queue of the view.
+	Future,
+}
+
+/// The transaction which is classified differently by the views holding it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TxDivergence<TxHash, Hash> {
+	/// The hash of the transaction.
+	pub tx_hash: TxHash,
+	/// The classification of the transaction in every active view holding it.
+	///
+	/// Views are sorted by block number and then by hash.
+	pub views: Vec<(Hash, TxClassification)>,
+}
+
+/// The number of the pins held for every pinned view.
+type ViewPins<Hash> = Arc<Mutex<HashMap<Hash, usize>>>;
+
+/// The guard exempting a view from being removed on finalization, as long as it is held.
+///
+/// Returned by [`crate::ForkAwareTxPool::pin_view`]. Once all the guards of the view are dropped,
+/// the view is removed by one of the following finalizations.
+pub struct ViewPin<Hash: Eq + std::hash::Hash> {
+	/// The hash of the block the pinned view is associated with.
+	at: Hash,
+	/// The pins of the view store the view belongs to.
+	pins: ViewPins<Hash>,
+}
+
+impl<Hash: Eq + std::hash::Hash> ViewPin<Hash> {
+	/// Returns the hash of the block the pinned view is associated with.
+	pub fn at(&self) -> &Hash {
+		&self.at
+	}
+}
+
+impl<Hash: Eq + std::hash::Hash> Drop for ViewPin<Hash> {
+	fn drop(&mut self) {
+		let mut pins = self.pins.lock();
+		if let Some(count) = pins.get_mut(&self.at) {
+			*count -= 1;
+			if *count == 0 {
+				pins.remove(&self.at);
+			}
+		}
+	}
+}
+
 impl<ChainApi, Block> ViewStore<ChainApi, Block>
 where
 	Block: BlockT,
@@ -186,10 +545,14 @@ where
 	<Block as BlockT>::Hash: Unpin,
 {
 	/// Creates a new empty view store.
+	///
+	/// If `event_log_capacity` is provided, the most recent view lifecycle events are recorded, up
+	/// to the given number of events. Refer to [`Self::recent_events`].
 	pub(super) fn new(
 		api: Arc<ChainApi>,
 		listener: Arc<MultiViewListener<ChainApi>>,
 		dropped_stream_controller: MultiViewDroppedWatcherController<ChainApi>,
+		event_log_capacity: Option<usize>,
 	) -> Self {
 		Self {
 			api,
@@ -199,37 +562,351 @@ where
 			most_recent_view: RwLock::from(None),
 			dropped_stream_controller,
 			pending_txs_tasks: Default::default(),
+			tx_index: Default::default(),
+			pinned_views: Default::default(),
+			view_created_callbacks: Default::default(),
+			views_removed_callbacks: Default::default(),
+			event_log: event_log_capacity.map(EventLog::new),
+			recently_finalized: Default::default(),
+			submit_timeout: SUBMIT_VIEW_TIMEOUT,
 		}
 	}
 
-	/// Imports a bunch of unverified extrinsics to every active view.
-	pub(super) async fn submit(
+	/// Returns the most recent view lifecycle events, from the oldest to the newest.
+	///
+	/// Intended for post-mortem debugging. Empty if the event log was not enabled when the view
+	/// store was created.
+	pub(super) fn recent_events(&self) -> Vec<ViewStoreEvent<Block::Hash>> {
+		self.event_log.as_ref().map(EventLog::events).unwrap_or_default()
+	}
+
+	/// Records the given event in the event log, if enabled.
+	fn record_event(&self, kind: ViewStoreEventKind<Block::Hash>) {
+		if let Some(event_log) = &self.event_log {
+			event_log.record(kind);
+		}
+	}
+
+	/// Registers a callback invoked after every new view is inserted into the view store.
+	///
+	/// Callbacks are invoked in the order of registration, without holding any of the view store
+	/// locks, so they are free to call back into the view store.
+	pub(super) fn on_view_created(
 		&self,
-		xts: impl IntoIterator<Item = (TimedTransactionSource, ExtrinsicFor<ChainApi>)> + Clone,
-	) -> HashMap<Block::Hash, Vec<Result<ViewStoreSubmitOutcome<ChainApi>, ChainApi::Error>>> {
-		let submit_futures = {
+		callback: impl FnMut(&Arc<View<ChainApi>>) + Send + 'static,
+	) {
+		let callback: ViewCreatedCallback<ChainApi> = Arc::new(Mutex::new(callback));
+		self.view_created_callbacks.lock().push(callback);
+	}
+
+	/// Invokes the callbacks registered with [`Self::on_view_created`] for the given view.
+	fn notify_view_created(&self, view: &Arc<View<ChainApi>>) {
+		// Release the list before invoking the callbacks, so they can register further callbacks.
+		let callbacks = self.view_created_callbacks.lock().clone();
+		for callback in callbacks {
+			let mut callback = callback.lock();
+			(*callback)(view);
+		}
+	}
+
+	/// Registers a callback invoked with the hashes of the views removed from the view store
+	/// when a block is finalized.
+	///
+	/// Callbacks are invoked in the order of registration, without holding any of the view store
+	/// locks, so they are free to call back into the view store.
+	pub(super) fn on_views_removed(&self, callback: impl FnMut(&[Block::Hash]) + Send + 'static) {
+		let callback: ViewsRemovedCallback<Block::Hash> = Arc::new(Mutex::new(callback));
+		self.views_removed_callbacks.lock().push(callback);
+	}
+
+	/// Invokes the callbacks registered with [`Self::on_views_removed`] for the given views.
+	fn notify_views_removed(&self, removed_views: &[Block::Hash]) {
+		if removed_views.is_empty() {
+			return
+		}
+		// Release the list before invoking the callbacks, so they can register further callbacks.
+		let callbacks = self.views_removed_callbacks.lock().clone();
+		for callback in callbacks {
+			let mut callback = callback.lock();
+			(*callback)(removed_views);
+		}
+	}
+
+	/// Returns the hashes of the given extrinsics which were included in the recently finalized
+	/// blocks, `None` for all the others.
+	fn recently_finalized_hashes<'a>(
+		&self,
+		xts: impl IntoIterator<Item = &'a ExtrinsicFor<ChainApi>>,
+	) -> Vec<Option<ExtrinsicHash<ChainApi>>> {
+		let recently_finalized = self.recently_finalized.read();
+		xts.into_iter()
+			.map(|xt| {
+				if recently_finalized.is_empty() {
+					return None
+				}
+				let tx_hash = self.api.hash_and_length(xt).0;
+				recently_finalized.contains(&tx_hash).then_some(tx_hash)
+			})
+			.collect()
+	}
+
+	/// Runs `f` concurrently for every active view and collects the outcomes by view hash.
+	///
+	/// The active views are snapshotted under the read lock, which is released before any of the
+	/// futures is polled. Views added or removed while the futures are running are not accounted
+	/// for.
+	pub(super) async fn for_each_view_async<F, Fut, R>(&self, f: F) -> HashMap<Block::Hash, R>
+	where
+		F: Fn(Arc<View<ChainApi>>) -> Fut,
+		Fut: Future<Output = R>,
+	{
+		let futures = {
 			let active_views = self.active_views.read();
 			active_views
 				.iter()
-				.map(|(_, view)| {
-					let view = view.clone();
-					let xts = xts.clone();
-					async move {
+				.map(|(hash, view)| {
+					let hash = *hash;
+					let future = f(view.clone());
+					async move { (hash, future.await) }
+				})
+				.collect::<Vec<_>>()
+		};
+		futures::future::join_all(futures).await.into_iter().collect()
+	}
+
+	/// Same as [`Self::for_each_view_async`], but stops waiting for the views once `abort` is
+	/// triggered.
+	///
+	/// Every view is reported along with its block, and the outcome of `f` if it completed
+	/// before the abort.
+	pub(super) async fn for_each_view_abortable<F, Fut, R>(
+		&self,
+		f: F,
+		abort: AbortRegistration,
+	) -> HashMap<Block::Hash, (HashAndNumber<Block>, Option<R>)>
+	where
+		F: Fn(Arc<View<ChainApi>>) -> Fut,
+		Fut: Future<Output = R>,
+	{
+		let (mut results, futures): (HashMap<_, _>, FuturesUnordered<_>) = {
+			let active_views = self.active_views.read();
+			active_views
+				.iter()
+				.map(|(hash, view)| {
+					let hash = *hash;
+					let future = f(view.clone());
+					((hash, (view.at.clone(), None)), async move { (hash, future.await) })
+				})
+				.unzip()
+		};
+		let mut completed = Abortable::new(futures, abort);
+		while let Some((hash, result)) = completed.next().await {
+			if let Some((_, outcome)) = results.get_mut(&hash) {
+				*outcome = Some(result);
+			}
+		}
+		results
+	}
+
+	/// Imports a bunch of unverified extrinsics to every active view.
+	///
+	/// Views which are already full are skipped, the extrinsics are reported as
+	/// [`PoolError::ImmediatelyDropped`] for them without being validated. Such views are marked as
+	/// saturated, refer to [`all_views_saturated`] for the aggregate signal. The views which are
+	/// not full still receive the extrinsics.
+	///
+	/// The extrinsics included in the recently finalized blocks are reported as
+	/// [`PoolError::AlreadyImported`] for every view without being validated.
+	///
+	/// Every view is given a limited time to process the extrinsics, so a single slow view does
+	/// not stall the whole submission. If the view does not complete in time, the extrinsics are
+	/// reported as [`PoolError::ValidationTimeout`] for it. Views exceeding
+	/// [`SLOW_VIEW_SUBMIT_THRESHOLD`] are logged at debug level, if enabled.
+	///
+	/// Extrinsics occurring more than once in the batch are validated only once, see
+	/// [`replicate_duplicates`] for how their outcome is reported.
+	///
+	/// The outcomes are keyed by the hash of the view, and carry its block number. Views which
+	/// were removed from the active views while the submission was in flight are reported with
+	/// [`ViewSubmitStatus::ViewRemovedDuringSubmit`].
+	pub(super) async fn submit(
+		&self,
+		xts: impl IntoIterator<Item = (TimedTransactionSource, ExtrinsicFor<ChainApi>)> + Clone,
+	) -> HashMap<Block::Hash, ViewSubmitResults<ChainApi>> {
+		self.submit_abortable(xts, None).await
+	}
+
+	/// Same as [`Self::submit`], but the submission can be aborted with the
+	/// [`futures::future::AbortHandle`] paired with `abort`, e.g. on shutdown.
+	///
+	/// Once aborted, the views which did not complete the submission yet are reported with
+	/// [`ViewSubmitStatus::Cancelled`] and no outcomes. The extrinsics they were processing may
+	/// still land in them.
+	pub(super) async fn submit_abortable(
+		&self,
+		xts: impl IntoIterator<Item = (TimedTransactionSource, ExtrinsicFor<ChainApi>)> + Clone,
+		abort: Option<AbortRegistration>,
+	) -> HashMap<Block::Hash, ViewSubmitResults<ChainApi>> {
+		let xts = xts.into_iter().collect::<Vec<_>>();
+		let finalized = self.recently_finalized_hashes(xts.iter().map(|(_, xt)| xt));
+		let to_be_submitted = xts
+			.into_iter()
+			.zip(&finalized)
+			.filter_map(|(xt, finalized)| finalized.is_none().then_some(xt))
+			.collect::<Vec<_>>();
+
+		let mut unique_positions = HashMap::new();
+		let mut unique = Vec::new();
+		let positions = to_be_submitted
+			.into_iter()
+			.map(|(source, xt)| {
+				let tx_hash = self.api.hash_and_length(&xt).0;
+				*unique_positions.entry(tx_hash).or_insert_with(|| {
+					unique.push((tx_hash, (source, xt)));
+					unique.len() - 1
+				})
+			})
+			.collect::<Vec<_>>();
+		let (unique_hashes, to_be_submitted): (Vec<_>, Vec<_>) = unique.into_iter().unzip();
+
+		let timeout = self.submit_timeout;
+		let slow_submit_hashes = tracing::enabled!(target: LOG_TARGET, tracing::Level::DEBUG)
+			.then(|| unique_hashes.clone());
+		let submit_view = |view: Arc<View<ChainApi>>| {
+			let xts = to_be_submitted.clone();
+			let slow_submit_hashes = slow_submit_hashes.clone();
+			async move {
+				let count = xts.len();
+				if view.is_full() {
+					trace!(
+						target: LOG_TARGET,
+						at_hash = ?view.at.hash,
+						"submit: view is full, skipping"
+					);
+					let dropped = (0..count)
+						.map(|_| Err(PoolError::ImmediatelyDropped.into()))
+						.collect::<Vec<_>>();
+					return (view.at.clone(), true, dropped)
+				}
+				let start = Instant::now();
+				select! {
+					results = view.submit_many(xts) => {
+						let elapsed = start.elapsed();
+						if let Some(tx_hashes) = slow_submit_hashes {
+							if elapsed > SLOW_VIEW_SUBMIT_THRESHOLD {
+								log_xt_debug!(
+									data: hash,
+									target: LOG_TARGET,
+									&tx_hashes,
+									"submit: slow view at:{:?} took:{:?}",
+									view.at.hash,
+									elapsed
+								);
+							}
+						}
 						(
-							view.at.hash,
-							view.submit_many(xts)
-								.await
-								.into_iter()
-								.map(|r| r.map(Into::into))
-								.collect::<Vec<_>>(),
+							view.at.clone(),
+							false,
+							results.into_iter().map(|r| r.map(Into::into)).collect::<Vec<_>>(),
 						)
+					},
+					_ = futures_timer::Delay::new(timeout) => {
+						warn!(
+							target: LOG_TARGET,
+							at_hash = ?view.at.hash,
+							?timeout,
+							"submit: view timed out"
+						);
+						let timed_out = (0..count)
+							.map(|_| Err(PoolError::ValidationTimeout.into()))
+							.collect::<Vec<_>>();
+						(view.at.clone(), false, timed_out)
 					}
+				}
+			}
+		};
+		let outcomes = match abort {
+			None => self
+				.for_each_view_async(submit_view)
+				.await
+				.into_values()
+				.map(|(at, saturated, results)| (at, Some((saturated, results))))
+				.collect::<Vec<_>>(),
+			Some(abort) => self
+				.for_each_view_abortable(submit_view, abort)
+				.await
+				.into_values()
+				.map(|(at, outcome)| {
+					(at, outcome.map(|(_, saturated, results)| (saturated, results)))
 				})
-				.collect::<Vec<_>>()
+				.collect::<Vec<_>>(),
 		};
-		let results = futures::future::join_all(submit_futures).await;
+		let results = outcomes
+			.into_iter()
+			.map(|(at, outcome)| {
+				let outcome = outcome.map(|(saturated, results)| {
+					let mut results =
+						replicate_duplicates(results, &positions, &unique_hashes).into_iter();
+					let results = finalized
+						.iter()
+						.map(|finalized| match finalized {
+							Some(tx_hash) =>
+								Err(PoolError::AlreadyImported(Box::new(*tx_hash)).into()),
+							None => results.next().expect(
+								"One result for every submitted extrinsic is returned. qed.",
+							),
+						})
+						.collect::<Vec<_>>();
+					(saturated, results)
+				});
+				(at, outcome)
+			})
+			.collect::<Vec<_>>();
+
+		self.index_transactions(results.iter().flat_map(|(at, outcome)| {
+			outcome
+				.iter()
+				.flat_map(|(_, results)| results.iter())
+				.filter_map(|result| result.as_ref().ok())
+				.map(move |outcome| (outcome.hash(), at.hash))
+		}));
 
-		HashMap::<_, _>::from_iter(results.into_iter())
+		let active_views = self.active_views.read();
+		results
+			.into_iter()
+			.map(|(at, outcome)| {
+				let (status, saturated, results) = match outcome {
+					None => (ViewSubmitStatus::Cancelled, false, Vec::new()),
+					Some((saturated, results)) if active_views.contains_key(&at.hash) =>
+						(ViewSubmitStatus::Active, saturated, results),
+					Some((saturated, results)) =>
+						(ViewSubmitStatus::ViewRemovedDuringSubmit, saturated, results),
+				};
+				(at.hash, ViewSubmitResults { number: at.number, status, saturated, results })
+			})
+			.collect()
+	}
+
+	/// Validates a bunch of unverified extrinsics against every active view, without importing
+	/// them.
+	///
+	/// This is a dry run of [`Self::submit`]: the per-view outcomes are the same, but no view is
+	/// altered, no watcher is created and the transactions are not indexed.
+	pub(super) async fn validate(
+		&self,
+		xts: impl IntoIterator<Item = (TimedTransactionSource, ExtrinsicFor<ChainApi>)> + Clone,
+	) -> HashMap<Block::Hash, Vec<Result<ViewStoreSubmitOutcome<ChainApi>, ChainApi::Error>>> {
+		self.for_each_view_async(|view| {
+			let xts = xts.clone();
+			async move {
+				view.validate_many(xts)
+					.await
+					.into_iter()
+					.map(|r| r.map(Into::into))
+					.collect::<Vec<_>>()
+			}
+		})
+		.await
 	}
 
 	/// Synchronously imports single unverified extrinsics into every active view.
@@ -245,14 +922,17 @@ where
 			.collect::<Vec<_>>();
 
 		let tx_hash = self.api.hash_and_length(&xt).0;
+		if self.recently_finalized.read().contains(&tx_hash) {
+			return Err(PoolError::AlreadyImported(Box::new(tx_hash)).into())
+		}
 
 		let result = active_views
 			.iter()
-			.map(|view| view.submit_local(xt.clone()))
-			.find_or_first(Result::is_ok);
+			.map(|view| (view.at.hash, view.submit_local(xt.clone())))
+			.find_or_first(|(_, result)| result.is_ok());
 
 		match result {
-			Some(Err(error)) => {
+			Some((_, Err(error))) => {
 				trace!(
 					target: LOG_TARGET,
 					?tx_hash,
@@ -262,42 +942,80 @@ where
 				Err(error)
 			},
 			None => Ok(ViewStoreSubmitOutcome::new(tx_hash, None)),
-			Some(Ok(r)) => Ok(r.into()),
+			Some((at, Ok(r))) => {
+				self.index_transactions([(tx_hash, at)]);
+				Ok(r.into())
+			},
 		}
 	}
 
+	/// Imports a single unverified extrinsic to every active view.
+	///
+	/// Along with the outcome for every view, the views in which the extrinsic became ready are
+	/// reported, which is handy to check whether the extrinsic landed anywhere.
+	pub(super) async fn submit_one(
+		&self,
+		source: TimedTransactionSource,
+		xt: ExtrinsicFor<ChainApi>,
+	) -> SubmitOneResults<ChainApi> {
+		let tx_hash = self.api.hash_and_length(&xt).0;
+		let results = self
+			.for_each_view_async(|view| {
+				let xt = xt.clone();
+				let source = source.clone();
+				async move {
+					let result = view.submit_one(source, xt).await;
+					let is_ready = result.is_ok() &&
+						view.pool.validated_pool().ready_by_hash(&tx_hash).is_some();
+					(result, is_ready)
+				}
+			})
+			.await;
+		self.index_transactions(
+			results
+				.iter()
+				.filter(|(_, (result, _))| result.is_ok())
+				.map(|(at, _)| (tx_hash, *at)),
+		);
+		let ready_in = results
+			.iter()
+			.filter_map(|(at, (_, is_ready))| is_ready.then_some(*at))
+			.collect();
+		let results = results
+			.into_iter()
+			.map(|(at, (result, _))| (at, result))
+			.collect::<HashMap<_, _>>();
+		SubmitOneResults { results, ready_in }
+	}
+
 	/// Import a single extrinsic and starts to watch its progress in the pool.
 	///
 	/// The extrinsic is imported to every view, and the individual streams providing the progress
 	/// of this transaction within every view are added to the multi view listener.
 	///
 	/// The external stream of aggregated/processed events provided by the `MultiViewListener`
-	/// instance is returned.
+	/// instance is returned. The `at` block is not used for the submission, it is only recorded
+	/// in the watcher's metadata and carried by the first event of the stream.
 	pub(super) async fn submit_and_watch(
 		&self,
-		_at: Block::Hash,
+		at: Block::Hash,
 		source: TimedTransactionSource,
 		xt: ExtrinsicFor<ChainApi>,
 	) -> Result<ViewStoreSubmitOutcome<ChainApi>, ChainApi::Error> {
 		let tx_hash = self.api.hash_and_length(&xt).0;
-		let Some(external_watcher) = self.listener.create_external_watcher_for_tx(tx_hash) else {
+		if self.recently_finalized.read().contains(&tx_hash) {
+			return Err(PoolError::AlreadyImported(Box::new(tx_hash)).into())
+		}
+		let Some(external_watcher) =
+			self.listener.create_external_watcher_for_submitted_tx(tx_hash, at)
+		else {
 			return Err(PoolError::AlreadyImported(Box::new(tx_hash)).into())
 		};
-		let submit_futures = {
-			let active_views = self.active_views.read();
-			active_views
-				.iter()
-				.map(|(_, view)| {
-					let view = view.clone();
-					let xt = xt.clone();
-					let source = source.clone();
-					async move { view.submit_one(source, xt).await }
-				})
-				.collect::<Vec<_>>()
-		};
-		let result = futures::future::join_all(submit_futures)
+		let result = self
+			.submit_one(source, xt)
 			.await
-			.into_iter()
+			.results
+			.into_values()
 			.find_or_first(Result::is_ok);
 
 		match result {
@@ -316,6 +1034,109 @@ where
 		}
 	}
 
+	/// Resubmits the transactions from the retracted blocks of the `tree_route` to the given view.
+	///
+	/// Transactions held by any of the views at the retracted blocks (or at the common ancestor)
+	/// were already validated on the retracted fork. Similarly to new views being cloned from the
+	/// best existing view (see [`Self::find_best_view`]), they are trusted and inserted into the
+	/// view without being validated again. All the other transactions are fully validated at the
+	/// view's block.
+	///
+	/// Shall only be used for the transactions included in the retracted blocks of the
+	/// `tree_route`, external submissions are always validated.
+	pub(super) async fn resubmit_retracted(
+		&self,
+		view: &View<ChainApi>,
+		tree_route: &TreeRoute<Block>,
+		xts: Vec<(TimedTransactionSource, ExtrinsicFor<ChainApi>)>,
+	) {
+		let retracted_views = {
+			let active_views = self.active_views.read();
+			let inactive_views = self.inactive_views.read();
+			tree_route
+				.retracted()
+				.iter()
+				.chain(std::iter::once(tree_route.common_block()))
+				.filter_map(|block| {
+					active_views.get(&block.hash).or_else(|| inactive_views.get(&block.hash))
+				})
+				.cloned()
+				.collect::<Vec<_>>()
+		};
+
+		let mut trusted = Vec::new();
+		let mut untrusted = Vec::new();
+		for (source, xt) in xts {
+			let tx_hash = self.api.hash_and_length(&xt).0;
+			let known = retracted_views.iter().find_map(|retracted_view| {
+				retracted_view
+					.pool
+					.validated_pool()
+					.pool
+					.read()
+					.by_hashes(&[tx_hash])
+					.pop()
+					.flatten()
+			});
+			match known {
+				Some(tx) => trusted
+					.push(ValidatedTransaction::Valid(Transaction { source, ..tx.duplicate() })),
+				None => untrusted.push((source, xt)),
+			}
+		}
+
+		trace!(
+			target: LOG_TARGET,
+			at = ?view.at,
+			trusted = trusted.len(),
+			untrusted = untrusted.len(),
+			"resubmit_retracted"
+		);
+
+		view.pool.validated_pool().submit(trusted);
+		let _ = view.pool.resubmit_at(&view.at, untrusted).await;
+	}
+
+	/// Starts to watch the progress of the transaction which is already present in the views.
+	///
+	/// The external watcher is created and immediately notified about the current status of the
+	/// transaction (`Ready` or `Future`) in every active view holding it. Subsequent events are
+	/// delivered by the views' streams, as for transactions submitted with `submit_and_watch`.
+	///
+	/// Returns `None` if no active view holds the transaction, or if the transaction is already
+	/// watched.
+	pub(super) async fn watch_existing_transaction(
+		&self,
+		tx_hash: ExtrinsicHash<ChainApi>,
+	) -> Option<TxStatusStream<ChainApi>> {
+		let statuses = self
+			.active_views
+			.read()
+			.values()
+			.filter(|view| view.is_imported(&tx_hash))
+			.map(|view| {
+				let status = if view.pool.validated_pool().ready_by_hash(&tx_hash).is_some() {
+					TransactionStatus::Ready
+				} else {
+					TransactionStatus::Future
+				};
+				(view.at.hash, status)
+			})
+			.collect::<Vec<_>>();
+
+		if statuses.is_empty() {
+			trace!(target: LOG_TARGET, ?tx_hash, "watch_existing_transaction: not in any view");
+			return None
+		}
+
+		let external_watcher = self.listener.create_external_watcher_for_tx(tx_hash)?;
+		for (block_hash, status) in statuses {
+			self.listener.transaction_view_status(tx_hash, block_hash, status);
+		}
+
+		Some(external_watcher)
+	}
+
 	/// Returns the pool status for every active view.
 	pub(super) fn status(&self) -> HashMap<Block::Hash, PoolStatus> {
 		self.active_views.read().iter().map(|(h, v)| (*h, v.status())).collect()
@@ -339,26 +1160,68 @@ where
 	/// Search path is:
 	/// [E1, C, R3, R2, R1]
 	/// ```
+	///
+	/// The first view found along the path is chosen. Every block on the path is a distinct
+	/// position, so the search order alone decides, regardless of how many transactions the
+	/// views hold.
 	pub(super) fn find_best_view(
 		&self,
 		tree_route: &TreeRoute<Block>,
 	) -> Option<Arc<View<ChainApi>>> {
 		let active_views = self.active_views.read();
-		let best_view = {
-			tree_route
-				.retracted()
-				.iter()
-				.chain(std::iter::once(tree_route.common_block()))
-				.chain(tree_route.enacted().iter())
-				.rev()
-				.find(|block| active_views.contains_key(&block.hash))
-		};
-		best_view.map(|h| {
-			active_views
-				.get(&h.hash)
-				.expect("hash was just found in the map's keys. qed")
-				.clone()
-		})
+		tree_route
+			.retracted()
+			.iter()
+			.chain(std::iter::once(tree_route.common_block()))
+			.chain(tree_route.enacted().iter())
+			.rev()
+			.find_map(|block| active_views.get(&block.hash).cloned())
+	}
+
+	/// Returns the hash of the most recent view.
+	///
+	/// If the view referenced by `most_recent_view` no longer exists, e.g. it was removed while
+	/// racing with finalization, the highest active view is selected instead and becomes the most
+	/// recent view.
+	pub(super) fn most_recent_view_hash(&self) -> Option<Block::Hash> {
+		{
+			let most_recent_view = self.most_recent_view.read();
+			let active_views = self.active_views.read();
+			match *most_recent_view {
+				Some(hash)
+					if active_views.contains_key(&hash) ||
+						self.inactive_views.read().contains_key(&hash) =>
+					return Some(hash),
+				None if active_views.is_empty() => return None,
+				_ => {},
+			}
+		}
+
+		let mut most_recent_view_lock = self.most_recent_view.write();
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+		if let Some(hash) = *most_recent_view_lock {
+			// The pointer was updated in the meantime.
+			if active_views.contains_key(&hash) || inactive_views.contains_key(&hash) {
+				return Some(hash)
+			}
+		}
+
+		let fallback = active_views
+			.values()
+			.max_by_key(|view| (view.at.number, view.at.hash))
+			.map(|view| view.at.hash);
+		if *most_recent_view_lock != fallback {
+			debug!(
+				target: LOG_TARGET,
+				stale = ?*most_recent_view_lock,
+				?fallback,
+				"most_recent_view_hash: most recent view is gone, falling back"
+			);
+			*most_recent_view_lock = fallback;
+			self.record_event(ViewStoreEventKind::MostRecentViewChanged(fallback));
+		}
+		fallback
 	}
 
 	/// Returns an iterator for ready transactions for the most recently notified best block.
@@ -367,8 +1230,7 @@ where
 	/// for which maintain process was accomplished, exists.
 	pub(super) fn ready(&self) -> ReadyIteratorFor<ChainApi> {
 		let ready_iterator = self
-			.most_recent_view
-			.read()
+			.most_recent_view_hash()
 			.map(|at| self.get_view_at(at, true))
 			.flatten()
 			.map(|(v, _)| v.pool.validated_pool().ready());
@@ -387,8 +1249,7 @@ where
 	pub(super) fn futures(
 		&self,
 	) -> Vec<Transaction<ExtrinsicHash<ChainApi>, ExtrinsicFor<ChainApi>>> {
-		self.most_recent_view
-			.read()
+		self.most_recent_view_hash()
 			.map(|at| self.futures_at(at))
 			.flatten()
 			.unwrap_or_default()
@@ -403,10 +1264,40 @@ where
 			.map(|(v, _)| v.pool.validated_pool().pool.read().futures().cloned().collect())
 	}
 
+	/// Returns the future transactions of all the views (both active and inactive), each paired
+	/// with the hash of the block of the view holding it.
+	///
+	/// Views are visited in order of block number and then hash. A transaction which is future in
+	/// several views is reported once per view, so on busy pools with many forks the returned
+	/// list can be much larger than the pool itself. Intended for diagnostics only.
+	pub(super) fn all_futures(
+		&self,
+	) -> Vec<(Block::Hash, Transaction<ExtrinsicHash<ChainApi>, ExtrinsicFor<ChainApi>>)> {
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+
+		active_views
+			.values()
+			.chain(inactive_views.values())
+			.sorted_by_key(|view| (view.at.number, view.at.hash))
+			.flat_map(|view| {
+				view.pool
+					.validated_pool()
+					.pool
+					.read()
+					.futures()
+					.map(|tx| (view.at.hash, tx.clone()))
+					.collect::<Vec<_>>()
+			})
+			.collect()
+	}
+
 	/// Collects all the transactions included in the blocks on the provided `tree_route` and
 	/// triggers finalization event for them.
 	///
-	/// The finalization event is sent using side-channel of the multi view `listener`.
+	/// The finalization event is sent using side-channel of the multi view `listener`. The events
+	/// are sent one by one, following the order of the blocks in the `tree_route` and the order
+	/// of the extrinsics within every block, and the listener processes them in the same order.
 	///
 	/// Returns the list of finalized transactions hashes.
 	pub(super) async fn finalize_route(
@@ -466,6 +1357,255 @@ where
 			.and_then(|v| v.pool.validated_pool().ready_by_hash(tx_hash))
 	}
 
+	/// Returns true if the transaction with given hash is present in any of the views.
+	///
+	/// Both active and inactive views are checked. Only the views referenced by the transactions
+	/// index are inspected.
+	pub(super) fn contains_transaction(&self, tx_hash: &ExtrinsicHash<ChainApi>) -> bool {
+		!self.views_with_transaction(tx_hash).is_empty()
+	}
+
+	/// Returns the hashes of the blocks of all the views (both active and inactive) holding the
+	/// transaction with given hash.
+	pub(super) fn views_with_transaction(
+		&self,
+		tx_hash: &ExtrinsicHash<ChainApi>,
+	) -> Vec<Block::Hash> {
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+		let tx_index = self.tx_index.read();
+
+		tx_index
+			.get(tx_hash)
+			.into_iter()
+			.flatten()
+			.filter(|at| {
+				active_views
+					.get(*at)
+					.or_else(|| inactive_views.get(*at))
+					.is_some_and(|view| view.pool.validated_pool().pool.read().is_imported(tx_hash))
+			})
+			.copied()
+			.collect()
+	}
+
+	/// Returns the number of distinct transactions held by the views.
+	///
+	/// Both active and inactive views are considered. Transactions held by several views (e.g.
+	/// on different forks) are counted only once. The count is built from the transactions index,
+	/// with entries verified against the views.
+	pub(super) fn transaction_count(&self) -> usize {
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+		let tx_index = self.tx_index.read();
+
+		tx_index
+			.iter()
+			.filter(|(tx_hash, views)| {
+				views.iter().any(|at| {
+					active_views.get(at).or_else(|| inactive_views.get(at)).is_some_and(|view| {
+						view.pool.validated_pool().pool.read().is_imported(tx_hash)
+					})
+				})
+			})
+			.count()
+	}
+
+	/// Adds the given `(transaction, view)` pairs to the transactions index.
+	///
+	/// Pairs referring to views which are no longer held by the view store are skipped.
+	fn index_transactions(
+		&self,
+		transactions: impl IntoIterator<Item = (ExtrinsicHash<ChainApi>, Block::Hash)>,
+	) {
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+		let mut tx_index = self.tx_index.write();
+
+		transactions
+			.into_iter()
+			.filter(|(_, at)| active_views.contains_key(at) || inactive_views.contains_key(at))
+			.for_each(|(tx_hash, at)| {
+				tx_index.entry(tx_hash).or_default().insert(at);
+			});
+	}
+
+	/// Removes the given `(transaction, view)` pairs from the transactions index.
+	fn unindex_transactions(
+		&self,
+		transactions: impl IntoIterator<Item = (ExtrinsicHash<ChainApi>, Block::Hash)>,
+	) {
+		let mut tx_index = self.tx_index.write();
+		for (tx_hash, at) in transactions {
+			if let Entry::Occupied(mut entry) = tx_index.entry(tx_hash) {
+				entry.get_mut().remove(&at);
+				if entry.get().is_empty() {
+					entry.remove();
+				}
+			}
+		}
+	}
+
+	/// Adds all the transactions held by the given view to the transactions index.
+	///
+	/// Shall be called with the views locks held, so the index stays in sync with them.
+	fn index_view(
+		tx_index: &mut HashMap<ExtrinsicHash<ChainApi>, HashSet<Block::Hash>>,
+		view: &View<ChainApi>,
+	) {
+		let pool = view.pool.validated_pool().pool.read();
+		pool.ready().map(|tx| tx.hash).chain(pool.futures().map(|tx| tx.hash)).for_each(
+			|tx_hash| {
+				tx_index.entry(tx_hash).or_default().insert(view.at.hash);
+			},
+		);
+	}
+
+	/// Adds all the transactions currently held by the given view to the transactions index.
+	///
+	/// Intended for views filled after being inserted into the view store, e.g. by
+	/// [`Self::rebuild_from`]. Does nothing if the view is no longer held by the view store.
+	pub(super) fn reindex_view(&self, view: &View<ChainApi>) {
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+		if active_views.contains_key(&view.at.hash) || inactive_views.contains_key(&view.at.hash) {
+			Self::index_view(&mut self.tx_index.write(), view);
+		}
+	}
+
+	/// Removes all the references to the given views from the transactions index.
+	///
+	/// Shall be called with the views locks held, so the index stays in sync with them.
+	fn unindex_views(
+		tx_index: &mut HashMap<ExtrinsicHash<ChainApi>, HashSet<Block::Hash>>,
+		views: &[Block::Hash],
+	) {
+		if views.is_empty() {
+			return
+		}
+		tx_index.retain(|_, at| {
+			views.iter().for_each(|view| {
+				at.remove(view);
+			});
+			!at.is_empty()
+		});
+	}
+
+	/// Removes the view at given block hash and returns all the transactions it held.
+	///
+	/// Both active and inactive views are considered. The returned list contains the ready
+	/// transactions (in the order they would be included into the block) followed by the future
+	/// transactions. The view is also removed from the multi view listener and dropped stream
+	/// controller, so the caller can decide where the transactions shall be re-submitted.
+	///
+	/// Returns `None` if there is no view at given block hash.
+	pub(super) fn drain_view_transactions(
+		&self,
+		at: Block::Hash,
+	) -> Option<Vec<ExtrinsicFor<ChainApi>>> {
+		let view = {
+			let mut most_recent_view_lock = self.most_recent_view.write();
+			let mut active_views = self.active_views.write();
+			let mut inactive_views = self.inactive_views.write();
+
+			let view = active_views.remove(&at).or_else(|| inactive_views.remove(&at))?;
+			self.record_event(ViewStoreEventKind::ViewRemoved(at));
+			if *most_recent_view_lock == Some(at) {
+				most_recent_view_lock.take();
+				self.record_event(ViewStoreEventKind::MostRecentViewChanged(None));
+			}
+			Self::unindex_views(&mut self.tx_index.write(), &[at]);
+			view
+		};
+
+		self.listener.remove_view(at);
+		self.dropped_stream_controller.remove_view(at);
+
+		let validated_pool = view.pool.validated_pool();
+		let xts = validated_pool
+			.ready()
+			.map(|tx| tx.data.clone())
+			.chain(validated_pool.pool.read().futures().map(|tx| tx.data.clone()))
+			.collect::<Vec<_>>();
+
+		trace!(
+			target: LOG_TARGET,
+			?at,
+			count = xts.len(),
+			"drain_view_transactions"
+		);
+
+		Some(xts)
+	}
+
+	/// Captures the summary of all the views held by the view store.
+	///
+	/// All the locks are held for the whole capture, so the snapshot is consistent.
+	pub(super) fn export_snapshot(&self) -> ViewStoreSnapshot<Block::Hash, NumberFor<Block>> {
+		let most_recent_view = self.most_recent_view.read();
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+
+		let summarize = |views: &HashMap<Block::Hash, Arc<View<ChainApi>>>| {
+			views
+				.values()
+				.map(|view| {
+					let status = view.status();
+					ViewSnapshot {
+						hash: view.at.hash,
+						number: view.at.number,
+						ready_count: status.ready,
+						future_count: status.future,
+					}
+				})
+				.sorted_by_key(|view| (view.number, view.hash))
+				.collect::<Vec<_>>()
+		};
+
+		ViewStoreSnapshot {
+			active_views: summarize(&active_views),
+			retracted_views: summarize(&inactive_views),
+			most_recent_view: *most_recent_view,
+		}
+	}
+
+	/// Lists the transactions which are ready in some active views and future in others.
+	///
+	/// Such disagreement usually signals a state or validation bug. Every transaction held by
+	/// every active view is inspected, so the cost is O(views × pool). This is a diagnostic aid
+	/// intended for occasional invocation, it shall not be called on the hot path.
+	///
+	/// The report is sorted by transaction hash.
+	pub(super) fn divergence_report(
+		&self,
+	) -> Vec<TxDivergence<ExtrinsicHash<ChainApi>, Block::Hash>> {
+		let active_views = self.active_views.read();
+
+		let mut classifications =
+			HashMap::<ExtrinsicHash<ChainApi>, Vec<(Block::Hash, TxClassification)>>::new();
+		for view in active_views.values().sorted_by_key(|view| (view.at.number, view.at.hash)) {
+			let pool = view.pool.validated_pool().pool.read();
+			pool.ready()
+				.map(|tx| (tx.hash, TxClassification::Ready))
+				.chain(pool.futures().map(|tx| (tx.hash, TxClassification::Future)))
+				.for_each(|(tx_hash, classification)| {
+					classifications
+						.entry(tx_hash)
+						.or_default()
+						.push((view.at.hash, classification));
+				});
+		}
+
+		classifications
+			.into_iter()
+			.filter(|(_, views)| {
+				!views.iter().map(|(_, classification)| classification).all_equal()
+			})
+			.map(|(tx_hash, views)| TxDivergence { tx_hash, views })
+			.sorted_by_key(|divergence| divergence.tx_hash)
+			.collect()
+	}
+
 	/// Inserts new view into the view store.
 	///
 	/// All the views associated with the blocks which are on enacted path (including common
@@ -476,56 +1616,394 @@ where
 	/// The `most_recent_view` is updated with the reference to the newly inserted view.
 	///
 	/// If there are any pending tx replacments, they are applied to the new view.
+	///
+	/// Returns the hashes of the transactions held by the views moved to the inactive views set,
+	/// which are not held by any of the active views (including the new one). Refer to
+	/// [`Self::insert_new_view_with_included`] to leave out the transactions included in the
+	/// enacted blocks.
 	pub(super) async fn insert_new_view(
 		&self,
 		view: Arc<View<ChainApi>>,
 		tree_route: &TreeRoute<Block>,
-	) {
+	) -> HashSet<ExtrinsicHash<ChainApi>> {
+		self.insert_new_view_with_included(view, tree_route, &HashSet::new()).await
+	}
+
+	/// Same as [`Self::insert_new_view`], but the transactions from `included_txs`, known to be
+	/// included in the enacted blocks, are not reported as displaced.
+	///
+	/// The returned transactions are thus the candidates for re-submission to the new view.
+	pub(super) async fn insert_new_view_with_included(
+		&self,
+		view: Arc<View<ChainApi>>,
+		tree_route: &TreeRoute<Block>,
+		included_txs: &HashSet<ExtrinsicHash<ChainApi>>,
+	) -> HashSet<ExtrinsicHash<ChainApi>> {
 		self.apply_pending_tx_replacements(view.clone()).await;
 
 		//note: most_recent_view must be synced with changes in in/active_views.
-		{
+		let displaced_txs = {
 			let mut most_recent_view_lock = self.most_recent_view.write();
 			let mut active_views = self.active_views.write();
 			let mut inactive_views = self.inactive_views.write();
 
+			let mut displaced_views = vec![];
 			std::iter::once(tree_route.common_block())
 				.chain(tree_route.enacted().iter())
 				.map(|block| block.hash)
 				.for_each(|hash| {
 					active_views.remove(&hash).map(|view| {
+						displaced_views.push(view.clone());
 						inactive_views.insert(hash, view);
 					});
 				});
 			active_views.insert(view.at.hash, view.clone());
-			most_recent_view_lock.replace(view.at.hash);
+			Self::index_view(&mut self.tx_index.write(), &view);
+			self.record_event(ViewStoreEventKind::ViewInserted(view.at.hash));
+			let previous_most_recent_view = *most_recent_view_lock;
+			Self::set_most_recent_view(
+				&mut most_recent_view_lock,
+				&active_views,
+				&inactive_views,
+				&view,
+			);
+			if *most_recent_view_lock != previous_most_recent_view {
+				self.record_event(ViewStoreEventKind::MostRecentViewChanged(
+					*most_recent_view_lock,
+				));
+			}
+
+			let mut displaced_txs = Self::displaced_transactions(&active_views, &displaced_views);
+			displaced_txs.retain(|tx_hash| !included_txs.contains(tx_hash));
+			displaced_txs
 		};
 		trace!(
 			target: LOG_TARGET,
 			inactive_views = ?self.inactive_views.read().keys(),
+			displaced_txs_count = displaced_txs.len(),
 			"insert_new_view"
 		);
+		self.notify_view_created(&view);
+		self.debug_assert_consistent();
+		displaced_txs
+	}
+
+	/// Verifies the invariants of the views topology, panicking with the whole topology on
+	/// violation: the `most_recent_view` shall reference one of the views, and no view shall be
+	/// both active and inactive.
+	///
+	/// Compiled out in release builds.
+	fn debug_assert_consistent(&self) {
+		#[cfg(debug_assertions)]
+		{
+			let most_recent_view = *self.most_recent_view.read();
+			let active_views = self.active_views.read();
+			let inactive_views = self.inactive_views.read();
+			let topology = || {
+				format!(
+					"most_recent_view: {most_recent_view:?}, active_views: {:?}, inactive_views: {:?}",
+					active_views.keys().collect::<Vec<_>>(),
+					inactive_views.keys().collect::<Vec<_>>(),
+				)
+			};
+
+			if let Some(hash) = most_recent_view {
+				assert!(
+					active_views.contains_key(&hash) || inactive_views.contains_key(&hash),
+					"ViewStore: most recent view {hash:?} is not held by the store, {}",
+					topology(),
+				);
+			}
+			let duplicated = active_views
+				.keys()
+				.filter(|hash| inactive_views.contains_key(hash))
+				.collect_vec();
+			assert!(
+				duplicated.is_empty(),
+				"ViewStore: views {duplicated:?} are both active and inactive, {}",
+				topology(),
+			);
+		}
+	}
+
+	/// Returns the hashes of the transactions held by any of the `displaced_views`, but not by any
+	/// of the `active_views`.
+	fn displaced_transactions(
+		active_views: &HashMap<Block::Hash, Arc<View<ChainApi>>>,
+		displaced_views: &[Arc<View<ChainApi>>],
+	) -> HashSet<ExtrinsicHash<ChainApi>> {
+		let mut displaced_txs = HashSet::new();
+		for view in displaced_views {
+			let pool = view.pool.validated_pool().pool.read();
+			displaced_txs.extend(pool.ready().map(|tx| tx.hash));
+			displaced_txs.extend(pool.futures().map(|tx| tx.hash));
+		}
+		if displaced_txs.is_empty() {
+			return displaced_txs
+		}
+		for view in active_views.values() {
+			let pool = view.pool.validated_pool().pool.read();
+			displaced_txs.retain(|tx_hash| !pool.is_imported(tx_hash));
+		}
+		displaced_txs
+	}
+
+	/// Updates the `most_recent_view` with the given view.
+	///
+	/// The update is ignored if the given view is at a lower block than the current most recent
+	/// view, which may happen when blocks are enacted out of order. Shall be called with the views
+	/// locks held, so the `most_recent_view` stays in sync with them.
+	fn set_most_recent_view(
+		most_recent_view: &mut Option<Block::Hash>,
+		active_views: &HashMap<Block::Hash, Arc<View<ChainApi>>>,
+		inactive_views: &HashMap<Block::Hash, Arc<View<ChainApi>>>,
+		view: &View<ChainApi>,
+	) {
+		let current = most_recent_view
+			.and_then(|hash| active_views.get(&hash).or_else(|| inactive_views.get(&hash)));
+
+		if let Some(current) = current {
+			if view.at.number < current.at.number {
+				debug!(
+					target: LOG_TARGET,
+					current = ?current.at,
+					ignored = ?view.at,
+					"set_most_recent_view: ignoring stale update"
+				);
+				return
+			}
+		}
+
+		most_recent_view.replace(view.at.hash);
+	}
+
+	/// Prunes the given transactions from the view at given block hash only.
+	///
+	/// Intended for transactions known to be included in the view's block. Transactions providing
+	/// the same tags are also pruned, other views are left untouched. Unknown hashes are ignored.
+	///
+	/// Returns the number of transactions removed from the view, or `None` if there is no view
+	/// (either active or inactive) at given block hash.
+	pub(super) fn prune_in_view(
+		&self,
+		at: Block::Hash,
+		hashes: &[ExtrinsicHash<ChainApi>],
+	) -> Option<usize> {
+		let (view, _) = self.get_view_at(at, true)?;
+		let removed = view.prune_known(hashes);
+		self.unindex_transactions(
+			hashes
+				.iter()
+				.filter(|tx_hash| !view.is_imported(tx_hash))
+				.map(|tx_hash| (*tx_hash, at)),
+		);
+
+		trace!(target: LOG_TARGET, ?at, count = hashes.len(), removed, "prune_in_view");
+
+		Some(removed)
+	}
+
+	/// Rebuilds the view store from the given block.
+	///
+	/// Intended as a recovery mechanism when the view store is found to be inconsistent. All the
+	/// views (both active and inactive) are removed, and a fresh, empty view at `base` is inserted
+	/// and set as the most recent view. The new view shares the configuration of the removed ones.
+	///
+	/// The removed views are handled as on finalization: the removals are recorded in the event
+	/// log, and the callbacks registered with [`Self::on_views_removed`] are notified.
+	///
+	/// Note: all in-flight external watchers are dropped, their streams are terminated with the
+	/// [`TransactionStatus::Dropped`] event. Transactions which were held only as future by the
+	/// removed views may be reported as dropped.
+	///
+	/// Once the returned view is filled, [`Self::reindex_view`] shall be called for it, so the
+	/// transactions index reflects its content.
+	///
+	/// Returns the new view, or `None` if the view store holds no view to take the configuration
+	/// from, or the number of the `base` block cannot be determined. In that case the view store is
+	/// left untouched.
+	pub(super) async fn rebuild_from(
+		&self,
+		base: Block::Hash,
+		api: Arc<ChainApi>,
+	) -> Option<Arc<View<ChainApi>>> {
+		let most_recent_view = self.most_recent_view_hash();
+		let Some(template) = most_recent_view
+			.and_then(|hash| self.get_view_at(hash, true))
+			.map(|(view, _)| view)
+			.or_else(|| self.active_views.read().values().next().cloned())
+			.or_else(|| self.inactive_views.read().values().next().cloned())
+		else {
+			warn!(target: LOG_TARGET, ?base, "rebuild_from: no view to take configuration from");
+			return None
+		};
+
+		let number = match api.block_id_to_number(&BlockId::Hash(base)) {
+			Ok(Some(number)) => number,
+			error => {
+				warn!(target: LOG_TARGET, ?base, ?error, "rebuild_from: unknown block number");
+				return None
+			},
+		};
+
+		let view = Arc::from(template.new_empty_like(api, &HashAndNumber { hash: base, number }));
+
+		let dropped_views = {
+			let mut most_recent_view_lock = self.most_recent_view.write();
+			let mut active_views = self.active_views.write();
+			let mut inactive_views = self.inactive_views.write();
+			let mut tx_index = self.tx_index.write();
+
+			let dropped_views = active_views
+				.drain()
+				.chain(inactive_views.drain())
+				.map(|(hash, _)| hash)
+				.collect::<Vec<_>>();
+			tx_index.clear();
+			most_recent_view_lock.take();
+			for hash in &dropped_views {
+				self.record_event(ViewStoreEventKind::ViewRemoved(*hash));
+			}
+
+			active_views.insert(view.at.hash, view.clone());
+			Self::index_view(&mut tx_index, &view);
+			self.record_event(ViewStoreEventKind::ViewInserted(view.at.hash));
+			Self::set_most_recent_view(
+				&mut most_recent_view_lock,
+				&active_views,
+				&inactive_views,
+				&view,
+			);
+			self.record_event(ViewStoreEventKind::MostRecentViewChanged(*most_recent_view_lock));
+			dropped_views
+		};
+
+		self.listener.remove_all_external_watchers();
+		self.dropped_stream_controller.add_view(
+			view.at.hash,
+			view.pool.validated_pool().create_dropped_by_limits_stream().boxed(),
+		);
+		self.listener.add_view_aggregated_stream(
+			view.at.hash,
+			view.pool.validated_pool().create_aggregated_stream().boxed(),
+		);
+		let removed_views =
+			dropped_views.iter().copied().filter(|hash| *hash != base).collect::<Vec<_>>();
+		self.detach_removed_views(&removed_views);
+
+		debug!(target: LOG_TARGET, ?base, ?dropped_views, "rebuild_from");
+		self.notify_view_created(&view);
+
+		Some(view)
+	}
+
+	/// Returns an optional reference to the view at given hash.
+	///
+	/// If `allow_retracted` flag is set, inactive views are also searched.
+	///
+	/// If the view at provided hash does not exist `None` is returned.
+	pub(super) fn get_view_at(
+		&self,
+		at: Block::Hash,
+		allow_inactive: bool,
+	) -> Option<(Arc<View<ChainApi>>, bool)> {
+		if let Some(view) = self.active_views.read().get(&at) {
+			return Some((view.clone(), false));
+		}
+		if allow_inactive {
+			if let Some(view) = self.inactive_views.read().get(&at) {
+				return Some((view.clone(), true))
+			}
+		};
+		None
+	}
+
+	/// Moves every active view which does not satisfy the given predicate to the retracted
+	/// (inactive) views.
+	///
+	/// Retracted views are kept in the store, so they can still serve as the base for new views,
+	/// and the `most_recent_view` and the transactions index remain valid. Every move is recorded
+	/// in the event log as [`ViewStoreEventKind::ViewRetracted`]. The views are removed on
+	/// finalization, as any other retracted view.
+	///
+	/// Shall not be called concurrently with [`Self::handle_finalized`]: a view retracted while the
+	/// finalization is in progress could outlive the finalized block.
+	///
+	/// Returns the block hashes of the retracted views.
+	pub(super) fn retain_views(
+		&self,
+		predicate: impl Fn(&View<ChainApi>) -> bool,
+	) -> Vec<Block::Hash> {
+		let retracted = {
+			let mut active_views = self.active_views.write();
+			let mut inactive_views = self.inactive_views.write();
+			let retracted = active_views
+				.iter()
+				.filter(|(_, view)| !predicate(view))
+				.map(|(hash, _)| *hash)
+				.collect::<Vec<_>>();
+			for hash in &retracted {
+				if let Some(view) = active_views.remove(hash) {
+					inactive_views.insert(*hash, view);
+					self.record_event(ViewStoreEventKind::ViewRetracted(*hash));
+				}
+			}
+			retracted
+		};
+		trace!(target: LOG_TARGET, ?retracted, "retain_views");
+		self.debug_assert_consistent();
+		retracted
+	}
+
+	/// Returns the number of retracted (inactive) views.
+	pub(super) fn retracted_view_count(&self) -> usize {
+		self.inactive_views.read().len()
+	}
+
+	/// Returns the block hashes of the retracted (inactive) views, sorted.
+	///
+	/// Only the inactive views lock is held, for reading.
+	pub(super) fn retracted_hashes(&self) -> Vec<Block::Hash> {
+		self.inactive_views.read().keys().copied().sorted().collect()
 	}
 
-	/// Returns an optional reference to the view at given hash.
+	/// Returns all the views, both active and inactive, associated with the blocks at the given
+	/// number.
 	///
-	/// If `allow_retracted` flag is set, inactive views are also searched.
+	/// There may be several such views, one for every fork. They are sorted by the block hash.
+	pub(super) fn views_at_number(&self, number: NumberFor<Block>) -> Vec<Arc<View<ChainApi>>> {
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+		active_views
+			.values()
+			.chain(inactive_views.values())
+			.filter(|view| view.at.number == number)
+			.cloned()
+			.sorted_by_key(|view| view.at.hash)
+			.collect()
+	}
+
+	/// Pins the view at given block hash, so it is not removed on finalization as long as the
+	/// returned guard is held.
 	///
-	/// If the view at provided hash does not exist `None` is returned.
-	pub(super) fn get_view_at(
-		&self,
-		at: Block::Hash,
-		allow_inactive: bool,
-	) -> Option<(Arc<View<ChainApi>>, bool)> {
-		if let Some(view) = self.active_views.read().get(&at) {
-			return Some((view.clone(), false));
+	/// Both active and inactive views can be pinned. Returns `None` if there is no view at given
+	/// hash.
+	pub(super) fn pin_view(&self, at: Block::Hash) -> Option<ViewPin<Block::Hash>> {
+		// The views are locked, so the view cannot be removed before it is pinned.
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+		if !active_views.contains_key(&at) && !inactive_views.contains_key(&at) {
+			return None
 		}
-		if allow_inactive {
-			if let Some(view) = self.inactive_views.read().get(&at) {
-				return Some((view.clone(), true))
-			}
-		};
-		None
+		*self.pinned_views.lock().entry(at).or_default() += 1;
+		Some(ViewPin { at, pins: self.pinned_views.clone() })
+	}
+
+	/// Returns the hashes of all the currently pinned views.
+	///
+	/// Shall be called with `active_views` or `inactive_views` locked.
+	fn pinned_views(&self) -> HashSet<Block::Hash> {
+		self.pinned_views.lock().keys().copied().collect()
 	}
 
 	/// The pre-finalization event handle for the view store.
@@ -540,9 +2018,11 @@ where
 		{
 			let active_views = self.active_views.read();
 			let inactive_views = self.inactive_views.read();
+			let pinned_views = self.pinned_views();
 
 			active_views
 				.iter()
+				.filter(|(hash, _)| !pinned_views.contains(*hash))
 				.filter(|(hash, v)| !match finalized_number {
 					Err(_) | Ok(None) => **hash == finalized_hash,
 					Ok(Some(n)) if v.at.number == n => **hash == finalized_hash,
@@ -553,6 +2033,7 @@ where
 
 			inactive_views
 				.iter()
+				.filter(|(hash, _)| !pinned_views.contains(*hash))
 				.filter(|(_, v)| !match finalized_number {
 					Err(_) | Ok(None) => false,
 					Ok(Some(n)) => v.at.number >= n,
@@ -580,9 +2061,17 @@ where
 	/// that are not finalized will stay in the view store. They will be removed in the future, once
 	/// new finalized blocks will be notified. This is to avoid scanning for common ancestors.
 	///
+	/// The views pinned with [`Self::pin_view`] are never removed.
+	///
+	/// If the most recent view is removed, the reference to it is cleared, see
+	/// [`Self::most_recent_view_hash`].
+	///
 	/// All watched transactions in the blocks from the tree_route will be notified with `Finalized`
 	/// event.
 	///
+	/// The callbacks registered with [`Self::on_views_removed`] are notified about all the removed
+	/// views, once the view store locks are released.
+	///
 	/// Returns the list of hashes of all finalized transactions along the provided `tree_route`.
 	pub(crate) async fn handle_finalized(
 		&self,
@@ -591,18 +2080,24 @@ where
 	) -> Vec<ExtrinsicHash<ChainApi>> {
 		let finalized_xts = self.finalize_route(finalized_hash, tree_route).await;
 		let finalized_number = self.api.block_id_to_number(&BlockId::Hash(finalized_hash));
+		if let Ok(Some(number)) = finalized_number {
+			self.recently_finalized.write().insert(number, finalized_xts.clone());
+		}
 
 		let mut dropped_views = vec![];
 		//clean up older then finalized
 		{
+			let mut most_recent_view_lock = self.most_recent_view.write();
 			let mut active_views = self.active_views.write();
 			let mut inactive_views = self.inactive_views.write();
+			let pinned_views = self.pinned_views();
 			active_views.retain(|hash, v| {
-				let retain = match finalized_number {
-					Err(_) | Ok(None) => *hash == finalized_hash,
-					Ok(Some(n)) if v.at.number == n => *hash == finalized_hash,
-					Ok(Some(n)) => v.at.number > n,
-				};
+				let retain = pinned_views.contains(hash) ||
+					match finalized_number {
+						Err(_) | Ok(None) => *hash == finalized_hash,
+						Ok(Some(n)) if v.at.number == n => *hash == finalized_hash,
+						Ok(Some(n)) => v.at.number > n,
+					};
 				if !retain {
 					dropped_views.push(*hash);
 				}
@@ -610,16 +2105,22 @@ where
 			});
 
 			inactive_views.retain(|hash, v| {
-				let retain = match finalized_number {
-					Err(_) | Ok(None) => false,
-					Ok(Some(n)) => v.at.number >= n,
-				};
+				let retain = pinned_views.contains(hash) ||
+					match finalized_number {
+						Err(_) | Ok(None) => false,
+						Ok(Some(n)) => v.at.number >= n,
+					};
 				if !retain {
 					dropped_views.push(*hash);
 				}
 				retain
 			});
 
+			Self::unindex_views(&mut self.tx_index.write(), &dropped_views);
+
+			self.record_event(ViewStoreEventKind::Finalized(finalized_hash));
+			self.record_views_removed(&mut most_recent_view_lock, &dropped_views);
+
 			trace!(
 				target: LOG_TARGET,
 				inactive_views = ?inactive_views.keys(),
@@ -637,31 +2138,51 @@ where
 		self.dropped_stream_controller.remove_finalized_txs(finalized_xts.clone());
 
 		self.listener.remove_view(finalized_hash);
-		for view in dropped_views {
-			self.listener.remove_view(view);
-			self.dropped_stream_controller.remove_view(view);
-		}
+		self.detach_removed_views(&dropped_views);
+		self.debug_assert_consistent();
 
 		finalized_xts
 	}
 
+	/// Records the removal of the given views in the event log, and clears the reference to the
+	/// most recent view if it is one of them.
+	///
+	/// Shall be called with the views locks held, once the views are removed from the store.
+	fn record_views_removed(
+		&self,
+		most_recent_view_lock: &mut Option<Block::Hash>,
+		removed_views: &[Block::Hash],
+	) {
+		for hash in removed_views {
+			self.record_event(ViewStoreEventKind::ViewRemoved(*hash));
+		}
+		if most_recent_view_lock.is_some_and(|hash| removed_views.contains(&hash)) {
+			most_recent_view_lock.take();
+			self.record_event(ViewStoreEventKind::MostRecentViewChanged(None));
+		}
+	}
+
+	/// Removes the given views, no longer held by the store, from the multi view listener and the
+	/// dropped stream controller, and notifies the callbacks registered with
+	/// [`Self::on_views_removed`].
+	///
+	/// Shall be called once the view store locks are released.
+	fn detach_removed_views(&self, removed_views: &[Block::Hash]) {
+		for view in removed_views {
+			self.listener.remove_view(*view);
+			self.dropped_stream_controller.remove_view(*view);
+		}
+		self.notify_views_removed(removed_views);
+	}
+
 	/// Terminates all the ongoing background views revalidations triggered at the end of maintain
 	/// process.
 	///
 	/// Refer to [*View revalidation*](../index.html#view-revalidation) for more details.
 	pub(crate) async fn finish_background_revalidations(&self) {
 		let start = Instant::now();
-		let finish_revalidation_futures = {
-			let active_views = self.active_views.read();
-			active_views
-				.iter()
-				.map(|(_, view)| {
-					let view = view.clone();
-					async move { view.finish_revalidation().await }
-				})
-				.collect::<Vec<_>>()
-		};
-		futures::future::join_all(finish_revalidation_futures).await;
+		self.for_each_view_async(|view| async move { view.finish_revalidation().await })
+			.await;
 		trace!(
 			target: LOG_TARGET,
 			duration = ?start.elapsed(),
@@ -742,14 +2263,17 @@ where
 		xt: ExtrinsicFor<ChainApi>,
 		tx_hash: ExtrinsicHash<ChainApi>,
 	) {
-		if let Err(error) = view.submit_one(source, xt).await {
-			trace!(
-				target: LOG_TARGET,
-				?tx_hash,
-				at_hash = ?view.at.hash,
-				%error,
-				"replace_transaction: submit failed"
-			);
+		match view.submit_one(source, xt).await {
+			Ok(_) => self.index_transactions([(tx_hash, view.at.hash)]),
+			Err(error) => {
+				trace!(
+					target: LOG_TARGET,
+					?tx_hash,
+					at_hash = ?view.at.hash,
+					%error,
+					"replace_transaction: submit failed"
+				);
+			},
 		}
 	}
 
@@ -819,15 +2343,25 @@ where
 			));
 		};
 
-		let mut seen = HashSet::new();
-
 		let removed = self
 			.active_views
 			.read()
 			.iter()
 			.chain(self.inactive_views.read().iter())
 			.filter(|(_, view)| view.is_imported(&xt_hash))
-			.flat_map(|(_, view)| view.remove_subtree(xt_hash, &listener_action))
+			.flat_map(|(at, view)| {
+				let at = *at;
+				view.remove_subtree(xt_hash, &listener_action)
+					.into_iter()
+					.map(move |xt_hash| (xt_hash, at))
+			})
+			.collect::<Vec<_>>();
+		self.unindex_transactions(removed.iter().copied());
+
+		let mut seen = HashSet::new();
+		let removed = removed
+			.into_iter()
+			.map(|(xt_hash, _)| xt_hash)
 			.filter(|xt_hash| seen.insert(*xt_hash))
 			.collect();
 
@@ -838,3 +2372,1428 @@ where
 		removed
 	}
 }
+
+#[cfg(test)]
+mod view_store_tests {
+	use super::*;
+	use crate::{
+		common::tests::{uxt, TestApi},
+		graph::ChainApi,
+	};
+	use substrate_test_runtime::{AccountId, Block, Transfer, H256};
+	use substrate_test_runtime_client::Sr25519Keyring::*;
+
+	fn xt(nonce: u64) -> ExtrinsicFor<TestApi> {
+		Arc::from(uxt(Transfer {
+			from: Alice.into(),
+			to: AccountId::from_h256(H256::from_low_u64_be(2)),
+			amount: 5,
+			nonce,
+		}))
+	}
+
+	fn new_view_store(api: Arc<TestApi>) -> ViewStore<TestApi, Block> {
+		let (listener, _) = MultiViewListener::new_with_worker();
+		let (dropped_stream_controller, _) = MultiViewDroppedWatcherController::new();
+		ViewStore::new(api, Arc::from(listener), dropped_stream_controller, None)
+	}
+
+	fn new_view(api: &Arc<TestApi>, number: u64) -> Arc<View<TestApi>> {
+		Arc::from(View::new(
+			api.clone(),
+			api.expect_hash_and_number(number),
+			Default::default(),
+			Default::default(),
+			true.into(),
+		))
+	}
+
+	#[tokio::test]
+	async fn contains_transaction_checks_all_views() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view0 = new_view(&api, 0);
+		let view1 = new_view(&api, 1);
+
+		let xt0 = xt(1);
+		let xt1 = xt(2);
+		let source = TimedTransactionSource::new_external(false);
+		view_store
+			.insert_new_view(view0.clone(), &TreeRoute::new(vec![view0.at.clone()], 0).unwrap())
+			.await;
+		view_store.submit([(source.clone(), xt0.clone())]).await;
+		view_store
+			.insert_new_view(
+				view1.clone(),
+				&TreeRoute::new(vec![view0.at.clone(), view1.at.clone()], 0).unwrap(),
+			)
+			.await;
+		view_store.submit([(source, xt1.clone())]).await;
+		assert!(view_store.inactive_views.read().contains_key(&view0.at.hash));
+
+		assert!(view_store.contains_transaction(&api.hash_and_length(&xt0).0));
+		assert!(view_store.contains_transaction(&api.hash_and_length(&xt1).0));
+		assert!(!view_store.contains_transaction(&api.hash_and_length(&xt(3)).0));
+	}
+
+	#[tokio::test]
+	async fn drain_view_transactions_returns_ready_and_future() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		view_store.active_views.write().insert(view1.at.hash, view1.clone());
+		view_store.inactive_views.write().insert(view2.at.hash, view2.clone());
+		*view_store.most_recent_view.write() = Some(view1.at.hash);
+
+		let ready_xt = xt(1);
+		let future_xt = xt(3);
+		let source = TimedTransactionSource::new_external(false);
+		view1.submit_one(source.clone(), ready_xt.clone()).await.unwrap();
+		view1.submit_one(source, future_xt.clone()).await.unwrap();
+
+		assert!(view_store.drain_view_transactions(H256::from_low_u64_be(3)).is_none());
+
+		let drained = view_store.drain_view_transactions(view1.at.hash).unwrap();
+		assert_eq!(drained, vec![ready_xt, future_xt]);
+		assert!(view_store.get_view_at(view1.at.hash, true).is_none());
+		assert!(view_store.most_recent_view.read().is_none());
+
+		assert_eq!(view_store.drain_view_transactions(view2.at.hash), Some(vec![]));
+		assert!(view_store.is_empty());
+	}
+
+	#[tokio::test]
+	async fn watch_existing_transaction_reports_current_status() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view1 = new_view(&api, 1);
+		view_store.active_views.write().insert(view1.at.hash, view1.clone());
+
+		let ready_xt = xt(1);
+		let future_xt = xt(3);
+		let source = TimedTransactionSource::new_external(false);
+		view1.submit_one(source.clone(), ready_xt.clone()).await.unwrap();
+		view1.submit_one(source, future_xt.clone()).await.unwrap();
+
+		let ready_hash = api.hash_and_length(&ready_xt).0;
+		let future_hash = api.hash_and_length(&future_xt).0;
+
+		let mut ready_watcher = view_store.watch_existing_transaction(ready_hash).await.unwrap();
+		let mut future_watcher = view_store.watch_existing_transaction(future_hash).await.unwrap();
+		assert_eq!(ready_watcher.next().await, Some(TransactionStatus::Ready));
+		assert_eq!(future_watcher.next().await, Some(TransactionStatus::Future));
+
+		// Already watched.
+		assert!(view_store.watch_existing_transaction(ready_hash).await.is_none());
+		// Not in any view.
+		let unknown_hash = api.hash_and_length(&xt(2)).0;
+		assert!(view_store.watch_existing_transaction(unknown_hash).await.is_none());
+	}
+
+	#[tokio::test]
+	async fn export_snapshot_captures_views_topology() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view0 = new_view(&api, 0);
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		view_store.inactive_views.write().insert(view0.at.hash, view0.clone());
+		view_store.active_views.write().insert(view2.at.hash, view2.clone());
+		view_store.active_views.write().insert(view1.at.hash, view1.clone());
+		*view_store.most_recent_view.write() = Some(view2.at.hash);
+
+		let source = TimedTransactionSource::new_external(false);
+		view1.submit_one(source.clone(), xt(1)).await.unwrap();
+		view1.submit_one(source.clone(), xt(3)).await.unwrap();
+		view2.submit_one(source, xt(2)).await.unwrap();
+
+		let snapshot = view_store.export_snapshot();
+		assert_eq!(
+			snapshot,
+			ViewStoreSnapshot {
+				active_views: vec![
+					ViewSnapshot {
+						hash: view1.at.hash,
+						number: 1,
+						ready_count: 1,
+						future_count: 1
+					},
+					ViewSnapshot {
+						hash: view2.at.hash,
+						number: 2,
+						ready_count: 1,
+						future_count: 0
+					},
+				],
+				retracted_views: vec![ViewSnapshot {
+					hash: view0.at.hash,
+					number: 0,
+					ready_count: 0,
+					future_count: 0,
+				}],
+				most_recent_view: Some(view2.at.hash),
+			}
+		);
+	}
+
+	#[tokio::test]
+	async fn divergence_report_lists_transactions_classified_differently() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		view_store.active_views.write().insert(view2.at.hash, view2.clone());
+		view_store.active_views.write().insert(view1.at.hash, view1.clone());
+
+		// Nonce 2 is future at block 1 and ready at block 2, nonce 3 is future at both blocks.
+		let source = TimedTransactionSource::new_external(false);
+		for view in [&view1, &view2] {
+			view.submit_one(source.clone(), xt(2)).await.unwrap();
+			view.submit_one(source.clone(), xt(3)).await.unwrap();
+		}
+
+		assert_eq!(
+			view_store.divergence_report(),
+			vec![TxDivergence {
+				tx_hash: api.hash_and_length(&xt(2)).0,
+				views: vec![
+					(view1.at.hash, TxClassification::Future),
+					(view2.at.hash, TxClassification::Ready),
+				],
+			}]
+		);
+	}
+
+	#[tokio::test]
+	async fn prune_in_view_leaves_other_views_untouched() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		for view in [&view1, &view2] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+
+		let source = TimedTransactionSource::new_external(false);
+		view_store.submit([(source.clone(), xt(2)), (source, xt(3))]).await;
+		let xt2_hash = api.hash_and_length(&xt(2)).0;
+		let xt3_hash = api.hash_and_length(&xt(3)).0;
+		assert_eq!(view_store.views_with_transaction(&xt2_hash).len(), 2);
+
+		let unknown = Block::Hash::repeat_byte(0x42);
+		assert_eq!(view_store.prune_in_view(unknown, &[xt2_hash]), None);
+
+		assert_eq!(view_store.prune_in_view(view2.at.hash, &[xt2_hash]), Some(1));
+		assert!(!view2.is_imported(&xt2_hash));
+		assert!(view2.is_imported(&xt3_hash));
+		assert_eq!(view2.status().ready, 1);
+
+		assert!(view1.is_imported(&xt2_hash));
+		assert!(view1.is_imported(&xt3_hash));
+		assert_eq!(view_store.views_with_transaction(&xt2_hash), vec![view1.at.hash]);
+	}
+
+	#[tokio::test]
+	async fn rebuild_from_replaces_all_views_with_fresh_one() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let (listener, listener_task) = MultiViewListener::new_with_worker();
+		let listener_task = tokio::spawn(listener_task);
+		let (dropped_stream_controller, _) = MultiViewDroppedWatcherController::new();
+		let view_store =
+			ViewStore::new(api.clone(), Arc::from(listener), dropped_stream_controller, Some(16));
+		let removed = Arc::new(Mutex::new(Vec::new()));
+		view_store.on_views_removed({
+			let removed = removed.clone();
+			move |hashes| removed.lock().push(hashes.to_vec())
+		});
+
+		let unknown = Block::Hash::repeat_byte(0x42);
+		assert!(view_store.rebuild_from(unknown, api.clone()).await.is_none());
+
+		// View at block 1 becomes inactive once view at block 2 is inserted on top of it.
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		view_store
+			.insert_new_view(
+				view2.clone(),
+				&TreeRoute::new(vec![view1.at.clone(), view2.at.clone()], 0).unwrap(),
+			)
+			.await;
+
+		let source = TimedTransactionSource::new_external(false);
+		view_store.submit([(source.clone(), xt(2))]).await;
+		let xt2_hash = api.hash_and_length(&xt(2)).0;
+		assert!(view_store.contains_transaction(&xt2_hash));
+		let watcher = view_store
+			.submit_and_watch(view2.at.hash, source, xt(3))
+			.await
+			.unwrap()
+			.expect_watcher();
+
+		assert!(view_store.rebuild_from(unknown, api.clone()).await.is_none());
+		assert_eq!(view_store.active_views.read().len(), 1);
+		assert_eq!(view_store.inactive_views.read().len(), 1);
+
+		let base = api.expect_hash_and_number(3);
+		let view3 = view_store.rebuild_from(base.hash, api.clone()).await.unwrap();
+		assert_eq!((view3.at.hash, view3.at.number), (base.hash, base.number));
+		assert_eq!(view3.status().ready, 0);
+		assert_eq!(
+			view_store.active_views.read().keys().cloned().collect::<Vec<_>>(),
+			vec![base.hash]
+		);
+		assert!(view_store.inactive_views.read().is_empty());
+		assert_eq!(*view_store.most_recent_view.read(), Some(base.hash));
+		assert!(!view_store.contains_transaction(&xt2_hash));
+
+		// The removed views are reported as on finalization...
+		let mut expected = vec![view1.at.hash, view2.at.hash];
+		expected.sort();
+		let mut recorded = view_store
+			.recent_events()
+			.into_iter()
+			.filter_map(|event| match event.kind {
+				ViewStoreEventKind::ViewRemoved(hash) => Some(hash),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		recorded.sort();
+		assert_eq!(recorded, expected);
+		let removed = removed.lock().concat().into_iter().sorted().collect::<Vec<_>>();
+		assert_eq!(removed, expected);
+
+		// ... and the watchers are terminated with the `Dropped` event.
+		let events = tokio::time::timeout(Duration::from_secs(5), watcher.collect::<Vec<_>>())
+			.await
+			.expect("watcher is terminated");
+		assert_eq!(events.last().map(|event| &event.status), Some(&TransactionStatus::Dropped));
+		assert_eq!(view_store.listener.external_watchers_count(), 0);
+		listener_task.abort();
+	}
+
+	#[tokio::test]
+	async fn event_log_keeps_most_recent_events() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let (listener, _) = MultiViewListener::new_with_worker();
+		let (dropped_stream_controller, _) = MultiViewDroppedWatcherController::new();
+		let view_store =
+			ViewStore::new(api.clone(), Arc::from(listener), dropped_stream_controller, Some(4));
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		view_store
+			.insert_new_view(
+				view2.clone(),
+				&TreeRoute::new(vec![view1.at.clone(), view2.at.clone()], 0).unwrap(),
+			)
+			.await;
+		view_store.drain_view_transactions(view1.at.hash).unwrap();
+
+		// The oldest event (insertion of view1) was discarded.
+		let events = view_store.recent_events();
+		assert!(events.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+		assert_eq!(
+			events.into_iter().map(|e| e.kind).collect::<Vec<_>>(),
+			vec![
+				ViewStoreEventKind::MostRecentViewChanged(Some(view1.at.hash)),
+				ViewStoreEventKind::ViewInserted(view2.at.hash),
+				ViewStoreEventKind::MostRecentViewChanged(Some(view2.at.hash)),
+				ViewStoreEventKind::ViewRemoved(view1.at.hash),
+			]
+		);
+
+		// The event log is disabled by default.
+		let view_store = new_view_store(api.clone());
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		assert!(view_store.recent_events().is_empty());
+	}
+
+	#[tokio::test]
+	async fn all_futures_collects_future_transactions_of_all_views() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		view_store.inactive_views.write().insert(view1.at.hash, view1.clone());
+		view_store.active_views.write().insert(view2.at.hash, view2.clone());
+
+		// Nonce 2 is future at block 1 and ready at block 2, nonce 4 is future at both blocks.
+		let source = TimedTransactionSource::new_external(false);
+		for view in [&view1, &view2] {
+			view.submit_one(source.clone(), xt(2)).await.unwrap();
+			view.submit_one(source.clone(), xt(4)).await.unwrap();
+		}
+
+		let xt2_hash = api.hash_and_length(&xt(2)).0;
+		let xt4_hash = api.hash_and_length(&xt(4)).0;
+		let futures = view_store
+			.all_futures()
+			.into_iter()
+			.map(|(at, tx)| (at, tx.hash))
+			.sorted()
+			.collect::<Vec<_>>();
+		let expected =
+			vec![(view1.at.hash, xt2_hash), (view1.at.hash, xt4_hash), (view2.at.hash, xt4_hash)]
+				.into_iter()
+				.sorted()
+				.collect::<Vec<_>>();
+		assert_eq!(futures, expected);
+	}
+
+	#[tokio::test]
+	async fn insert_new_view_does_not_regress_most_recent_view() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		let view3 = new_view(&api, 3);
+		let tree_route = |view: &View<TestApi>| TreeRoute::new(vec![view.at.clone()], 0).unwrap();
+
+		view_store.insert_new_view(view2.clone(), &tree_route(&view2)).await;
+		assert_eq!(*view_store.most_recent_view.read(), Some(view2.at.hash));
+
+		// Out-of-order lower view is inserted, but does not become the most recent one.
+		view_store.insert_new_view(view1.clone(), &tree_route(&view1)).await;
+		assert!(view_store.active_views.read().contains_key(&view1.at.hash));
+		assert_eq!(*view_store.most_recent_view.read(), Some(view2.at.hash));
+
+		view_store.insert_new_view(view3.clone(), &tree_route(&view3)).await;
+		assert_eq!(*view_store.most_recent_view.read(), Some(view3.at.hash));
+	}
+
+	#[tokio::test]
+	async fn most_recent_view_falls_back_to_highest_active_view() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		assert_eq!(view_store.most_recent_view_hash(), None);
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		let view3 = new_view(&api, 3);
+		for view in [&view1, &view2, &view3] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+		let source = TimedTransactionSource::new_external(false);
+		view_store.submit([(source, xt(2))]).await;
+		assert_eq!(view_store.most_recent_view_hash(), Some(view3.at.hash));
+
+		// The pointed view is gone without the pointer being updated.
+		view_store.active_views.write().remove(&view3.at.hash);
+		assert_eq!(*view_store.most_recent_view.read(), Some(view3.at.hash));
+
+		// The highest remaining view is selected and becomes the most recent one.
+		assert_eq!(view_store.most_recent_view_hash(), Some(view2.at.hash));
+		assert_eq!(*view_store.most_recent_view.read(), Some(view2.at.hash));
+		assert_eq!(view_store.ready().count(), 1);
+
+		// Also when the pointer was cleared.
+		view_store.active_views.write().remove(&view2.at.hash);
+		view_store.most_recent_view.write().take();
+		assert_eq!(view_store.most_recent_view_hash(), Some(view1.at.hash));
+		assert_eq!(view_store.ready().count(), 0);
+
+		view_store.active_views.write().remove(&view1.at.hash);
+		assert_eq!(view_store.most_recent_view_hash(), None);
+		assert_eq!(*view_store.most_recent_view.read(), None);
+	}
+
+	fn assert_tx_index_matches_views(
+		view_store: &ViewStore<TestApi, Block>,
+		api: &TestApi,
+		xts: &[ExtrinsicFor<TestApi>],
+	) {
+		let mut expected = HashMap::<_, HashSet<_>>::new();
+		for view in view_store
+			.active_views
+			.read()
+			.values()
+			.chain(view_store.inactive_views.read().values())
+		{
+			for xt in xts {
+				let tx_hash = api.hash_and_length(xt).0;
+				if view.pool.validated_pool().pool.read().is_imported(&tx_hash) {
+					expected.entry(tx_hash).or_default().insert(view.at.hash);
+				}
+			}
+		}
+		assert_eq!(*view_store.tx_index.read(), expected);
+	}
+
+	#[tokio::test]
+	async fn tx_index_stays_consistent_across_reorgs() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+		let xts = (1..=4).map(xt).collect::<Vec<_>>();
+
+		// B1 <- B2
+		let view1 = new_view(&api, 1);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		view_store.submit([(source.clone(), xts[0].clone())]).await;
+		assert_tx_index_matches_views(&view_store, &api, &xts);
+
+		let view2 = Arc::from(view1.new_from_other(&api.expect_hash_and_number(2)));
+		view_store
+			.insert_new_view(
+				view2.clone(),
+				&TreeRoute::new(vec![view1.at.clone(), view2.at.clone()], 0).unwrap(),
+			)
+			.await;
+		view_store
+			.submit([(source.clone(), xts[1].clone()), (source.clone(), xts[2].clone())])
+			.await;
+		assert_tx_index_matches_views(&view_store, &api, &xts);
+
+		// Reorg: B3 is built on top of B1, B2 is retracted.
+		let view3 = Arc::from(view1.new_from_other(&api.expect_hash_and_number(3)));
+		view_store
+			.insert_new_view(
+				view3.clone(),
+				&TreeRoute::new(vec![view2.at.clone(), view1.at.clone(), view3.at.clone()], 1)
+					.unwrap(),
+			)
+			.await;
+		view_store.submit([(source, xts[3].clone())]).await;
+		assert_tx_index_matches_views(&view_store, &api, &xts);
+		let xt4_hash = api.hash_and_length(&xts[3]).0;
+		assert_eq!(
+			view_store.views_with_transaction(&xt4_hash).into_iter().collect::<HashSet<_>>(),
+			HashSet::from([view2.at.hash, view3.at.hash])
+		);
+
+		// Removes the subtree from B2 only, B3 keeps the future transaction.
+		let xt2_hash = api.hash_and_length(&xts[1]).0;
+		let removed = view_store.remove_transaction_subtree(xt2_hash, |_, _| {});
+		assert_eq!(removed.len(), 3);
+		assert_tx_index_matches_views(&view_store, &api, &xts);
+		assert_eq!(view_store.views_with_transaction(&xt4_hash), vec![view3.at.hash]);
+
+		view_store.handle_finalized(view3.at.hash, &[]).await;
+		assert_tx_index_matches_views(&view_store, &api, &xts);
+		assert!(!view_store.contains_transaction(&xt2_hash));
+		assert!(view_store.contains_transaction(&xt4_hash));
+		assert!(view_store.tx_index.read().values().flatten().all(|at| *at == view3.at.hash));
+	}
+
+	#[tokio::test]
+	async fn transaction_count_is_deduplicated_across_views() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+		assert_eq!(view_store.transaction_count(), 0);
+
+		let view1 = new_view(&api, 1);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		view_store.submit([(source.clone(), xt(1)), (source.clone(), xt(2))]).await;
+		assert_eq!(view_store.transaction_count(), 2);
+
+		// View at B2 inherits the transactions of B1, which is kept as an inactive view.
+		let view2 = Arc::from(view1.new_from_other(&api.expect_hash_and_number(2)));
+		view_store
+			.insert_new_view(
+				view2.clone(),
+				&TreeRoute::new(vec![view1.at.clone(), view2.at.clone()], 0).unwrap(),
+			)
+			.await;
+		view_store.submit([(source, xt(3))]).await;
+
+		assert_eq!(view_store.views_with_transaction(&api.hash_and_length(&xt(1)).0).len(), 2);
+		assert_eq!(view_store.transaction_count(), 3);
+	}
+
+	#[tokio::test]
+	async fn resubmit_retracted_skips_validation_for_known_transactions() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let view1 = new_view(&api, 1);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		view_store.submit([(source.clone(), xt(1))]).await;
+		assert_eq!(api.validation_requests().len(), 1);
+
+		// B2 (including xt(1)) is retracted, B3 is built on top of B1.
+		let view3 = new_view(&api, 3);
+		let tree_route = TreeRoute::new(
+			vec![api.expect_hash_and_number(2), view1.at.clone(), view3.at.clone()],
+			1,
+		)
+		.unwrap();
+		view_store
+			.resubmit_retracted(
+				&view3,
+				&tree_route,
+				vec![(source.clone(), xt(1)), (source.clone(), xt(4))],
+			)
+			.await;
+
+		// xt(1) is known to the view at B1 and is trusted, xt(4) is validated.
+		assert_eq!(api.validation_requests(), vec![(*xt(1)).clone(), (*xt(4)).clone()]);
+		let view3_pool = view3.pool.validated_pool().pool.read();
+		assert!(view3_pool.is_imported(&api.hash_and_length(&xt(1)).0));
+		assert!(view3_pool.is_imported(&api.hash_and_length(&xt(4)).0));
+		drop(view3_pool);
+
+		// External submissions are always validated.
+		view_store.insert_new_view(view3.clone(), &tree_route).await;
+		view_store.submit([(source, xt(5))]).await;
+		assert_eq!(api.validation_requests().len(), 3);
+	}
+
+	#[tokio::test]
+	async fn validate_leaves_views_untouched() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		for view in [&view1, &view2] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+		view_store.submit([(source.clone(), xt(2))]).await;
+		let statuses = [view1.status(), view2.status()];
+
+		let results = view_store.validate([(source, xt(3))]).await;
+
+		let xt3_hash = api.hash_and_length(&xt(3)).0;
+		for view in [&view1, &view2] {
+			assert!(matches!(
+				results[&view.at.hash].as_slice(),
+				[Ok(outcome)] if outcome.hash() == xt3_hash
+			));
+		}
+		for (view, status) in [&view1, &view2].into_iter().zip(statuses) {
+			let new_status = view.status();
+			assert_eq!((new_status.ready, new_status.future), (status.ready, status.future));
+			assert!(!view.pool.validated_pool().pool.read().is_imported(&xt3_hash));
+		}
+		assert!(view_store.views_with_transaction(&xt3_hash).is_empty());
+		assert_eq!(view_store.listener.external_watchers_count(), 0);
+	}
+
+	#[tokio::test]
+	async fn submit_rejects_recently_finalized_transactions() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		view_store
+			.insert_new_view(view2.clone(), &TreeRoute::new(vec![view2.at.clone()], 0).unwrap())
+			.await;
+
+		api.block_bodies.lock().insert(view1.at.hash, vec![(*xt(1)).clone()]);
+		view_store.handle_finalized(view1.at.hash, &[]).await;
+		let requests = api.validation_requests().len();
+
+		let results = view_store.submit([(source.clone(), xt(1)), (source.clone(), xt(2))]).await;
+
+		// The finalized transaction is rejected without being validated, the other one is
+		// validated in both views...
+		assert_eq!(api.validation_requests().len(), requests + 2);
+		assert!(matches!(
+			results[&view2.at.hash].results.as_slice(),
+			[Err(PoolError::AlreadyImported(_)), Ok(_)]
+		));
+		assert!(!view_store.contains_transaction(&api.hash_and_length(&xt(1)).0));
+		assert!(view_store.contains_transaction(&api.hash_and_length(&xt(2)).0));
+
+		// ... also when watched.
+		assert!(matches!(
+			view_store.submit_and_watch(view2.at.hash, source, xt(1)).await,
+			Err(PoolError::AlreadyImported(_))
+		));
+		assert_eq!(view_store.listener.external_watchers_count(), 0);
+	}
+
+	#[tokio::test]
+	async fn insert_new_view_reports_displaced_transactions() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		let other_view = new_view(&api, 3);
+		view1
+			.submit_many([
+				(source.clone(), xt(1)),
+				(source.clone(), xt(2)),
+				(source.clone(), xt(5)),
+			])
+			.await;
+		view2.submit_many([(source.clone(), xt(2))]).await;
+		other_view.submit_many([(source, xt(5))]).await;
+
+		for view in [&view1, &other_view] {
+			let displaced = view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+			assert!(displaced.is_empty());
+		}
+
+		// view1 is displaced by view2: xt(2) is held by the new view and xt(5) by the other tip.
+		let displaced = view_store
+			.insert_new_view(
+				view2.clone(),
+				&TreeRoute::new(vec![view1.at.clone(), view2.at.clone()], 0).unwrap(),
+			)
+			.await;
+		assert_eq!(displaced, HashSet::from([api.hash_and_length(&xt(1)).0]));
+	}
+
+	#[tokio::test]
+	async fn insert_new_view_with_included_skips_enacted_transactions() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		view1.submit_many([(source.clone(), xt(1)), (source, xt(2))]).await;
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+
+		// xt(1) was included in the enacted block, so only xt(2) shall be re-submitted.
+		let included = HashSet::from([api.hash_and_length(&xt(1)).0]);
+		let displaced = view_store
+			.insert_new_view_with_included(
+				view2.clone(),
+				&TreeRoute::new(vec![view1.at.clone(), view2.at.clone()], 0).unwrap(),
+				&included,
+			)
+			.await;
+		assert_eq!(displaced, HashSet::from([api.hash_and_length(&xt(2)).0]));
+	}
+
+	#[tokio::test]
+	async fn submit_reports_saturated_full_views() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let options = graph::Options {
+			ready: graph::base_pool::Limit { count: 1, total_bytes: usize::MAX },
+			future: graph::base_pool::Limit { count: 0, total_bytes: usize::MAX },
+			..Default::default()
+		};
+		let full_view = Arc::from(View::new(
+			api.clone(),
+			api.expect_hash_and_number(1),
+			options,
+			Default::default(),
+			true.into(),
+		));
+		full_view.submit_many([(source.clone(), xt(1))]).await;
+		assert!(full_view.is_full());
+
+		let view2 = new_view(&api, 2);
+		assert!(!view2.is_full());
+
+		for view in [&full_view, &view2] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+		assert_eq!(api.validation_requests().len(), 1);
+
+		let results = view_store.submit([(source, xt(2))]).await;
+
+		// The full view was skipped without validating the transaction...
+		assert_eq!(api.validation_requests().len(), 2);
+		assert!(matches!(
+			results[&full_view.at.hash].results.as_slice(),
+			[Err(PoolError::ImmediatelyDropped)]
+		));
+		// ... while the other view still received it.
+		assert!(matches!(results[&view2.at.hash].results.as_slice(), [Ok(_)]));
+		assert_eq!(results[&view2.at.hash].number, view2.at.number);
+		assert_eq!(view2.status().ready, 1);
+		assert_eq!(
+			view_store.views_with_transaction(&api.hash_and_length(&xt(2)).0),
+			vec![view2.at.hash]
+		);
+		assert!(results[&full_view.at.hash].saturated);
+		assert!(!results[&view2.at.hash].saturated);
+		assert!(!all_views_saturated(&results));
+	}
+
+	#[tokio::test]
+	async fn submit_reports_all_views_saturated() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let options = graph::Options {
+			ready: graph::base_pool::Limit { count: 1, total_bytes: usize::MAX },
+			future: graph::base_pool::Limit { count: 0, total_bytes: usize::MAX },
+			..Default::default()
+		};
+		for number in [1, 2] {
+			let view = Arc::from(View::new(
+				api.clone(),
+				api.expect_hash_and_number(number),
+				options.clone(),
+				Default::default(),
+				true.into(),
+			));
+			view.submit_many([(source.clone(), xt(number))]).await;
+			assert!(view.is_full());
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+		let requests = api.validation_requests().len();
+
+		let results = view_store.submit([(source.clone(), xt(3))]).await;
+		assert_eq!(api.validation_requests().len(), requests);
+		assert_eq!(results.len(), 2);
+		assert!(results
+			.values()
+			.all(|r| matches!(r.results.as_slice(), [Err(PoolError::ImmediatelyDropped)])));
+		assert!(all_views_saturated(&results));
+
+		// No views, no saturation.
+		let empty_view_store = new_view_store(api.clone());
+		assert!(!all_views_saturated(&empty_view_store.submit([(source, xt(3))]).await));
+	}
+
+	#[tokio::test]
+	async fn submit_reports_timeout_for_slow_views() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let mut view_store = new_view_store(api.clone());
+		view_store.submit_timeout = Duration::from_millis(100);
+		let source = TimedTransactionSource::new_external(false);
+
+		let slow_view = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		for view in [&slow_view, &view2] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+		api.stuck_blocks.lock().insert(slow_view.at.hash);
+
+		let results = view_store.submit([(source, xt(2))]).await;
+		assert!(results.values().all(|r| r.status == ViewSubmitStatus::Active));
+
+		// The slow view reports the timeout...
+		assert_eq!(results.len(), 2);
+		assert!(matches!(
+			results[&slow_view.at.hash].results.as_slice(),
+			[Err(PoolError::ValidationTimeout)]
+		));
+		assert_eq!(slow_view.status().ready + slow_view.status().future, 0);
+		// ... while the other view still completed the submission.
+		assert!(matches!(results[&view2.at.hash].results.as_slice(), [Ok(_)]));
+		assert_eq!(view2.status().ready, 1);
+		assert_eq!(
+			view_store.views_with_transaction(&api.hash_and_length(&xt(2)).0),
+			vec![view2.at.hash]
+		);
+	}
+
+	#[tokio::test]
+	async fn submit_validates_duplicates_within_batch_once() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let view1 = new_view(&api, 1);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		let requests = api.validation_requests().len();
+
+		let results = view_store
+			.submit([(source.clone(), xt(2)), (source.clone(), xt(1)), (source, xt(2))])
+			.await;
+
+		// The repeated transaction was validated once...
+		assert_eq!(api.validation_requests().len(), requests + 2);
+		// ... and its outcome is replicated at every position of the batch.
+		let xt2_hash = api.hash_and_length(&xt(2)).0;
+		let xt1_hash = api.hash_and_length(&xt(1)).0;
+		let hashes = results[&view1.at.hash]
+			.results
+			.iter()
+			.map(|r| r.as_ref().map(|outcome| outcome.hash()).ok())
+			.collect::<Vec<_>>();
+		assert_eq!(hashes, vec![Some(xt2_hash), Some(xt1_hash), Some(xt2_hash)]);
+		assert_eq!(view1.status().ready + view1.status().future, 2);
+	}
+
+	#[tokio::test]
+	async fn submit_marks_views_removed_during_submission() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let mut view_store = new_view_store(api.clone());
+		view_store.submit_timeout = Duration::from_millis(100);
+		let source = TimedTransactionSource::new_external(false);
+
+		let view1 = new_view(&api, 1);
+		let other_view = new_view(&api, 3);
+		for view in [&view1, &other_view] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+		// Keep the submission to view1 in flight until the view gets removed.
+		api.stuck_blocks.lock().insert(view1.at.hash);
+
+		let view2 = view1.new_from_other(&api.expect_hash_and_number(2));
+		let (results, _) = futures::join!(
+			view_store.submit([(source, xt(2))]),
+			view_store.insert_new_view(
+				view2.clone(),
+				&TreeRoute::new(vec![view1.at.clone(), view2.at.clone()], 0).unwrap(),
+			),
+		);
+
+		// view1 was moved out of the active views by the concurrent update...
+		assert_eq!(results.len(), 2);
+		assert_eq!(results[&view1.at.hash].status, ViewSubmitStatus::ViewRemovedDuringSubmit);
+		assert!(!view_store.active_views.read().contains_key(&view1.at.hash));
+		// ... while the other view completed the submission normally.
+		assert_eq!(results[&other_view.at.hash].status, ViewSubmitStatus::Active);
+		assert!(matches!(results[&other_view.at.hash].results.as_slice(), [Ok(_)]));
+	}
+
+	#[tokio::test]
+	async fn views_at_number_returns_views_of_all_forks() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		// B1 has two children at height 2: B2 and B2'.
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		let fork_view2 = Arc::from(View::new(
+			api.clone(),
+			HashAndNumber { hash: H256::repeat_byte(0xaa), number: 2 },
+			Default::default(),
+			Default::default(),
+			true.into(),
+		));
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		for view in [&view2, &fork_view2] {
+			view_store
+				.insert_new_view(
+					view.clone(),
+					&TreeRoute::new(vec![view1.at.clone(), view.at.clone()], 0).unwrap(),
+				)
+				.await;
+		}
+
+		let mut expected = vec![view2.at.hash, fork_view2.at.hash];
+		expected.sort();
+		let hashes = |number| {
+			view_store
+				.views_at_number(number)
+				.into_iter()
+				.map(|view| view.at.hash)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(hashes(2), expected);
+		// Inactive views are included as well.
+		assert_eq!(hashes(1), vec![view1.at.hash]);
+		assert!(hashes(3).is_empty());
+	}
+
+	#[tokio::test]
+	async fn find_best_view_follows_search_order() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		// B1 has two children at height 2: B2 and B2'.
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		let fork_view2 = Arc::from(View::new(
+			api.clone(),
+			HashAndNumber { hash: H256::repeat_byte(0xaa), number: 2 },
+			Default::default(),
+			Default::default(),
+			true.into(),
+		));
+		view2.submit_many([(source.clone(), xt(2)), (source, xt(3))]).await;
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		for view in [&view2, &fork_view2] {
+			view_store
+				.insert_new_view(
+					view.clone(),
+					&TreeRoute::new(vec![view1.at.clone(), view.at.clone()], 0).unwrap(),
+				)
+				.await;
+		}
+
+		// B3' is built on top of B2': the enacted B2' is found first, even though the retracted B2
+		// is at the same height and already holds more transactions.
+		let block3 = HashAndNumber { hash: H256::repeat_byte(0xbb), number: 3 };
+		let tree_route = TreeRoute::new(
+			vec![view2.at.clone(), view1.at.clone(), fork_view2.at.clone(), block3],
+			1,
+		)
+		.unwrap();
+		assert_eq!(view_store.find_best_view(&tree_route).unwrap().at.hash, fork_view2.at.hash);
+
+		// The common block's view also precedes the retracted ones.
+		let fork_block2 = HashAndNumber { hash: H256::repeat_byte(0xcc), number: 2 };
+		let tree_route =
+			TreeRoute::new(vec![view2.at.clone(), view1.at.clone(), fork_block2], 1).unwrap();
+		assert_eq!(view_store.find_best_view(&tree_route).unwrap().at.hash, view1.at.hash);
+	}
+
+	#[tokio::test]
+	async fn retracted_views_accessors_list_inactive_views() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let views = (1..=3).map(|n| new_view(&api, n)).collect::<Vec<_>>();
+		view_store
+			.insert_new_view(
+				views[0].clone(),
+				&TreeRoute::new(vec![views[0].at.clone()], 0).unwrap(),
+			)
+			.await;
+		assert_eq!(view_store.retracted_view_count(), 0);
+		assert!(view_store.retracted_hashes().is_empty());
+
+		for pair in views.windows(2) {
+			view_store
+				.insert_new_view(
+					pair[1].clone(),
+					&TreeRoute::new(vec![pair[0].at.clone(), pair[1].at.clone()], 0).unwrap(),
+				)
+				.await;
+		}
+
+		let mut expected = vec![views[0].at.hash, views[1].at.hash];
+		expected.sort();
+		assert_eq!(view_store.retracted_view_count(), 2);
+		assert_eq!(view_store.retracted_hashes(), expected);
+	}
+
+	#[tokio::test]
+	async fn pinned_view_survives_finalization() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let views = (1..=3).map(|n| new_view(&api, n)).collect::<Vec<_>>();
+		view_store
+			.insert_new_view(
+				views[0].clone(),
+				&TreeRoute::new(vec![views[0].at.clone()], 0).unwrap(),
+			)
+			.await;
+		for parent_and_view in views.windows(2) {
+			let (parent, view) = (&parent_and_view[0], &parent_and_view[1]);
+			view_store
+				.insert_new_view(
+					view.clone(),
+					&TreeRoute::new(vec![parent.at.clone(), view.at.clone()], 0).unwrap(),
+				)
+				.await;
+		}
+
+		assert!(view_store.pin_view(api.expect_hash_from_number(4)).is_none());
+		let pin = view_store.pin_view(views[0].at.hash).unwrap();
+		let another_pin = view_store.pin_view(views[0].at.hash).unwrap();
+		assert_eq!(*pin.at(), views[0].at.hash);
+
+		// The pinned view is kept, while the other view below the finalized block is removed.
+		view_store.handle_finalized(views[2].at.hash, &[]).await;
+		assert!(view_store.get_view_at(views[0].at.hash, true).is_some());
+		assert!(view_store.get_view_at(views[1].at.hash, true).is_none());
+
+		// The view is pinned until all the guards are dropped.
+		drop(pin);
+		view_store.handle_finalized(views[2].at.hash, &[]).await;
+		assert!(view_store.get_view_at(views[0].at.hash, true).is_some());
+
+		drop(another_pin);
+		view_store.handle_finalized(views[2].at.hash, &[]).await;
+		assert!(view_store.get_view_at(views[0].at.hash, true).is_none());
+		assert!(view_store.pinned_views.lock().is_empty());
+	}
+
+	#[tokio::test]
+	async fn on_view_created_callbacks_are_invoked_for_inserted_views() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = Arc::new(new_view_store(api.clone()));
+
+		let created = Arc::new(Mutex::new(Vec::new()));
+		view_store.on_view_created({
+			let created = created.clone();
+			let view_store = view_store.clone();
+			move |view| {
+				// The view store locks are not held while the callback runs.
+				assert!(view_store.get_view_at(view.at.hash, false).is_some());
+				created.lock().push(view.at.hash);
+			}
+		});
+
+		let view1 = new_view(&api, 1);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		let view2 = new_view(&api, 2);
+		view_store
+			.insert_new_view(
+				view2.clone(),
+				&TreeRoute::new(vec![view1.at.clone(), view2.at.clone()], 0).unwrap(),
+			)
+			.await;
+
+		assert_eq!(*created.lock(), vec![view1.at.hash, view2.at.hash]);
+	}
+
+	#[tokio::test]
+	async fn on_views_removed_callbacks_are_invoked_on_finalization() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = Arc::new(new_view_store(api.clone()));
+
+		let removed = Arc::new(Mutex::new(Vec::new()));
+		view_store.on_views_removed({
+			let removed = removed.clone();
+			let view_store = view_store.clone();
+			move |hashes| {
+				// The view store locks are not held while the callback runs.
+				assert!(hashes.iter().all(|hash| view_store.get_view_at(*hash, true).is_none()));
+				removed.lock().push(hashes.to_vec());
+			}
+		});
+
+		let view1 = new_view(&api, 1);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		let view2 = new_view(&api, 2);
+		view_store
+			.insert_new_view(
+				view2.clone(),
+				&TreeRoute::new(vec![view1.at.clone(), view2.at.clone()], 0).unwrap(),
+			)
+			.await;
+
+		// Nothing is removed, so the callbacks are not invoked.
+		view_store.handle_finalized(view1.at.hash, &[]).await;
+		assert!(removed.lock().is_empty());
+
+		view_store.handle_finalized(view2.at.hash, &[]).await;
+		assert_eq!(*removed.lock(), vec![vec![view1.at.hash]]);
+	}
+
+	#[tokio::test]
+	async fn submit_one_reports_views_in_which_transaction_is_ready() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		for view in [&view1, &view2] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+
+		// Future at view1, ready at view2.
+		let outcome = view_store.submit_one(source.clone(), xt(2)).await;
+		assert!(outcome.results.values().all(Result::is_ok));
+		assert_eq!(outcome.ready_in, vec![view2.at.hash]);
+
+		// Future at both views.
+		let outcome = view_store.submit_one(source.clone(), xt(5)).await;
+		assert!(outcome.results.values().all(Result::is_ok));
+		assert!(outcome.ready_in.is_empty());
+
+		// Ready at view1, stale at view2.
+		let outcome = view_store.submit_one(source.clone(), xt(1)).await;
+		assert!(outcome.results[&view1.at.hash].is_ok());
+		assert!(outcome.results[&view2.at.hash].is_err());
+		assert_eq!(outcome.ready_in, vec![view1.at.hash]);
+
+		// Stale at both views.
+		let outcome = view_store.submit_one(source, xt(0)).await;
+		assert!(outcome.results.values().all(Result::is_err));
+		assert!(outcome.ready_in.is_empty());
+	}
+
+	#[tokio::test]
+	async fn handle_finalized_clears_removed_most_recent_view() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view2 = new_view(&api, 2);
+		let fork_view = Arc::from(View::new(
+			api.clone(),
+			HashAndNumber { hash: H256::repeat_byte(0xaa), number: 2 },
+			Default::default(),
+			Default::default(),
+			true.into(),
+		));
+		for view in [&view2, &fork_view] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+		assert_eq!(*view_store.most_recent_view.read(), Some(fork_view.at.hash));
+
+		view_store.handle_finalized(view2.at.hash, &[]).await;
+		assert_eq!(*view_store.most_recent_view.read(), None);
+		assert_eq!(view_store.most_recent_view_hash(), Some(view2.at.hash));
+	}
+
+	#[cfg(debug_assertions)]
+	#[tokio::test]
+	#[should_panic(expected = "are both active and inactive")]
+	async fn debug_assert_consistent_detects_views_both_active_and_inactive() {
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		let view1 = new_view(&api, 1);
+		view_store
+			.insert_new_view(view1.clone(), &TreeRoute::new(vec![view1.at.clone()], 0).unwrap())
+			.await;
+		view_store.inactive_views.write().insert(view1.at.hash, view1.clone());
+		view_store.debug_assert_consistent();
+	}
+
+	#[tokio::test]
+	async fn finalize_route_notifies_watchers_in_extrinsic_order() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let (listener, listener_task) = MultiViewListener::new_with_worker();
+		let listener_task = tokio::spawn(listener_task);
+		let (dropped_stream_controller, _) = MultiViewDroppedWatcherController::new();
+		let view_store =
+			ViewStore::new(api.clone(), Arc::from(listener), dropped_stream_controller, None);
+
+		let block = api.expect_hash_from_number(1);
+		let xts = (1..=3).map(xt).collect::<Vec<_>>();
+		api.block_bodies
+			.lock()
+			.insert(block, xts.iter().map(|xt| (**xt).clone()).collect());
+		let watchers = xts
+			.iter()
+			.map(|xt| {
+				let tx_hash = api.hash_and_length(xt).0;
+				view_store.listener.create_external_watcher_for_tx(tx_hash).unwrap()
+			})
+			.collect::<Vec<_>>();
+
+		let finalized = view_store.finalize_route(block, &[]).await;
+		assert_eq!(finalized, xts.iter().map(|xt| api.hash_and_length(xt).0).collect::<Vec<_>>());
+
+		// Every watcher is notified with the index of its extrinsic within the block.
+		let statuses = futures::stream::iter(watchers)
+			.then(|watcher| watcher.collect::<Vec<_>>())
+			.collect::<Vec<_>>()
+			.await;
+		assert_eq!(
+			statuses,
+			(0..xts.len())
+				.map(|i| vec![TransactionStatus::Finalized((block, i))])
+				.collect::<Vec<_>>()
+		);
+		listener_task.abort();
+	}
+
+	#[tokio::test]
+	async fn for_each_view_async_runs_for_every_active_view() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+
+		assert!(view_store
+			.for_each_view_async(|view| async move { view.at.number })
+			.await
+			.is_empty());
+
+		let view1 = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		for view in [&view1, &view2] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+
+		let numbers = view_store.for_each_view_async(|view| async move { view.at.number }).await;
+		assert_eq!(numbers, HashMap::from([(view1.at.hash, 1), (view2.at.hash, 2)]));
+
+		// Inactive views are skipped.
+		view_store.active_views.write().remove(&view1.at.hash);
+		view_store.inactive_views.write().insert(view1.at.hash, view1.clone());
+		let numbers = view_store.for_each_view_async(|view| async move { view.at.number }).await;
+		assert_eq!(numbers, HashMap::from([(view2.at.hash, 2)]));
+	}
+
+	#[tokio::test]
+	async fn submit_abortable_reports_cancelled_views() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let view_store = new_view_store(api.clone());
+		let source = TimedTransactionSource::new_external(false);
+
+		let stuck_view = new_view(&api, 1);
+		let view2 = new_view(&api, 2);
+		for view in [&stuck_view, &view2] {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+		api.stuck_blocks.lock().insert(stuck_view.at.hash);
+
+		// Abort once view2 completed the submission, while the stuck view is still validating.
+		let (abort_handle, abort_registration) = futures::future::AbortHandle::new_pair();
+		let abort = async {
+			while view2.status().ready == 0 {
+				tokio::time::sleep(Duration::from_millis(10)).await;
+			}
+			abort_handle.abort();
+		};
+		let (results, _) = futures::join!(
+			view_store.submit_abortable([(source, xt(2))], Some(abort_registration)),
+			abort,
+		);
+
+		assert_eq!(results.len(), 2);
+		assert_eq!(results[&stuck_view.at.hash].status, ViewSubmitStatus::Cancelled);
+		assert!(results[&stuck_view.at.hash].results.is_empty());
+		assert_eq!(results[&view2.at.hash].status, ViewSubmitStatus::Active);
+		assert!(matches!(results[&view2.at.hash].results.as_slice(), [Ok(_)]));
+		assert_eq!(
+			view_store.views_with_transaction(&api.hash_and_length(&xt(2)).0),
+			vec![view2.at.hash]
+		);
+
+		// Cancelled views carry no outcomes to be reduced.
+		let by_hash = submit_results_by_hash(results);
+		assert_eq!(by_hash.keys().collect::<Vec<_>>(), vec![&view2.at.hash]);
+	}
+
+	#[tokio::test]
+	async fn retain_views_keeps_only_matching_views() {
+		sp_tracing::try_init_simple();
+		let api = Arc::from(TestApi::default());
+		let (listener, _) = MultiViewListener::new_with_worker();
+		let (dropped_stream_controller, _) = MultiViewDroppedWatcherController::new();
+		let view_store =
+			ViewStore::new(api.clone(), Arc::from(listener), dropped_stream_controller, Some(16));
+
+		let removed = Arc::new(Mutex::new(Vec::new()));
+		view_store.on_views_removed({
+			let removed = removed.clone();
+			move |hashes| removed.lock().push(hashes.to_vec())
+		});
+
+		let views = (1..=4).map(|n| new_view(&api, n)).collect::<Vec<_>>();
+		for view in &views {
+			view_store
+				.insert_new_view(view.clone(), &TreeRoute::new(vec![view.at.clone()], 0).unwrap())
+				.await;
+		}
+		assert_eq!(view_store.active_views.read().len(), 4);
+
+		let mut retracted = view_store.retain_views(|view| view.at.number % 2 == 0);
+		retracted.sort();
+
+		// The odd-height views are retracted, not removed.
+		let mut expected = vec![views[0].at.hash, views[2].at.hash];
+		expected.sort();
+		assert_eq!(retracted, expected);
+		assert_eq!(view_store.retracted_hashes(), expected);
+		assert!(expected.iter().all(|hash| view_store.get_view_at(*hash, true).is_some()));
+
+		let mut active = view_store.active_views.read().keys().copied().collect::<Vec<_>>();
+		active.sort();
+		let mut expected_active = vec![views[1].at.hash, views[3].at.hash];
+		expected_active.sort();
+		assert_eq!(active, expected_active);
+
+		// The retraction is recorded, but not reported as a removal.
+		let mut recorded = view_store
+			.recent_events()
+			.into_iter()
+			.filter_map(|event| match event.kind {
+				ViewStoreEventKind::ViewRetracted(hash) => Some(hash),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		recorded.sort();
+		assert_eq!(recorded, expected);
+		assert!(removed.lock().is_empty());
+
+		// The most recent view is still held by the store.
+		assert_eq!(view_store.most_recent_view_hash(), Some(views[3].at.hash));
+	}
+}
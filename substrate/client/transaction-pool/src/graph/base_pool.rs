@@ -610,6 +610,11 @@ impl Limit {
 	pub fn is_exceeded(&self, count: usize, bytes: usize) -> bool {
 		self.count < count || self.total_bytes < bytes
 	}
+
+	/// Returns true if any of the provided values reaches the limit.
+	pub fn is_reached(&self, count: usize, bytes: usize) -> bool {
+		self.count <= count || self.total_bytes <= bytes
+	}
 }
 
 #[cfg(test)]
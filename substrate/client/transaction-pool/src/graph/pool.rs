@@ -145,6 +145,11 @@ pub struct Options {
 	pub reject_future_transactions: bool,
 	/// How long the extrinsic is banned for.
 	pub ban_time: Duration,
+	/// The number of the most recent view lifecycle events recorded by the view store of the
+	/// fork-aware transaction pool. The event log is disabled if `None`.
+	///
+	/// Ignored by the single-state transaction pool.
+	pub view_store_event_log_capacity: Option<usize>,
 }
 
 impl Default for Options {
@@ -154,6 +159,7 @@ impl Default for Options {
 			future: base::Limit { count: 512, total_bytes: 1 * 1024 * 1024 },
 			reject_future_transactions: false,
 			ban_time: Duration::from_secs(60 * 30),
+			view_store_event_log_capacity: None,
 		}
 	}
 }
@@ -209,6 +215,26 @@ impl<B: ChainApi> Pool<B> {
 		self.validated_pool.submit(validated_transactions.into_values())
 	}
 
+	/// Validates a bunch of unverified extrinsics at given block without importing them.
+	///
+	/// Returns the outcomes [`Self::submit_at`] would produce, but the pool is left untouched.
+	pub async fn validate_at(
+		&self,
+		at: &HashAndNumber<B::Block>,
+		xts: impl IntoIterator<Item = (base::TimedTransactionSource, ExtrinsicFor<B>)>,
+	) -> Vec<Result<ValidatedPoolSubmitOutcome<B>, B::Error>> {
+		self.verify(at, xts, CheckBannedBeforeVerify::Yes)
+			.await
+			.into_values()
+			.map(|tx| match tx {
+				ValidatedTransaction::Valid(tx) =>
+					Ok(ValidatedPoolSubmitOutcome::new(tx.hash, Some(tx.priority))),
+				ValidatedTransaction::Invalid(_, err) | ValidatedTransaction::Unknown(_, err) =>
+					Err(err),
+			})
+			.collect()
+	}
+
 	/// Resubmit the given extrinsics to the pool.
 	///
 	/// This does not check if a transaction is banned, before we verify it again.
@@ -265,16 +291,21 @@ impl<B: ChainApi> Pool<B> {
 	/// Used to clear the pool from transactions that were part of recently imported block.
 	/// The main difference from the `prune` is that we do not revalidate any transactions
 	/// and ignore unknown passed hashes.
-	pub fn prune_known(&self, at: &HashAndNumber<B::Block>, hashes: &[ExtrinsicHash<B>]) {
+	///
+	/// Returns the number of transactions removed from the pool.
+	pub fn prune_known(&self, at: &HashAndNumber<B::Block>, hashes: &[ExtrinsicHash<B>]) -> usize {
 		// Get details of all extrinsics that are already in the pool
 		let in_pool_tags =
 			self.validated_pool.extrinsics_tags(hashes).into_iter().flatten().flatten();
 
 		// Prune all transactions that provide given tags
 		let prune_status = self.validated_pool.prune_tags(in_pool_tags);
+		let removed = prune_status.pruned.len() + prune_status.failed.len();
 		let pruned_transactions =
 			hashes.iter().cloned().chain(prune_status.pruned.iter().map(|tx| tx.hash));
 		self.validated_pool.fire_pruned(at, pruned_transactions);
+
+		removed
 	}
 
 	/// Prunes ready transactions.
@@ -500,6 +531,11 @@ impl<B: ChainApi> Pool<B> {
 		let other: ValidatedPool<B> = (*self.validated_pool).clone();
 		Self { validated_pool: Arc::from(other) }
 	}
+
+	/// Creates a new empty pool sharing the configuration of this one.
+	pub fn new_empty_like(&self, api: Arc<B>) -> Self {
+		Self { validated_pool: Arc::new(self.validated_pool.new_empty_like(api)) }
+	}
 }
 
 #[cfg(test)]
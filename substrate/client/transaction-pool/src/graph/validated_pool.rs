@@ -21,7 +21,10 @@ use std::{
 	sync::Arc,
 };
 
-use crate::{common::tracing_log_xt::log_xt_trace, LOG_TARGET};
+use crate::{
+	common::tracing_log_xt::{log_xt_debug, log_xt_trace},
+	LOG_TARGET,
+};
 use futures::channel::mpsc::{channel, Sender};
 use parking_lot::{Mutex, RwLock};
 use sc_transaction_pool_api::{error, PoolStatus, ReadyTransactions, TransactionPriority};
@@ -201,6 +204,11 @@ impl<B: ChainApi> ValidatedPool<B> {
 		)
 	}
 
+	/// Create a new empty transaction pool sharing the configuration of this one.
+	pub fn new_empty_like(&self, api: Arc<B>) -> Self {
+		Self::new(self.options.clone(), self.is_validator.clone(), api)
+	}
+
 	fn new_with_rotator(
 		options: Options,
 		is_validator: IsValidator,
@@ -265,14 +273,23 @@ impl<B: ChainApi> ValidatedPool<B> {
 			Default::default()
 		};
 
-		results
+		let results = results
 			.into_iter()
 			.map(|res| match res {
 				Ok(outcome) if removed.contains(&outcome.hash) =>
 					Err(error::Error::ImmediatelyDropped.into()),
 				other => other,
 			})
-			.collect()
+			.collect::<Vec<_>>();
+
+		log_xt_debug!(
+			data: hash_priority,
+			target: LOG_TARGET,
+			results.iter().filter_map(|res| res.as_ref().ok()).map(|o| (o.hash, o.priority)),
+			"ValidatedPool::submit imported"
+		);
+
+		results
 	}
 
 	/// Submit single pre-validated transaction to the pool.
@@ -731,6 +748,15 @@ impl<B: ChainApi> ValidatedPool<B> {
 		self.pool.read().status()
 	}
 
+	/// Returns true if both the ready and the future queues have reached their limits.
+	///
+	/// No transaction can be added to a full pool without evicting some other transaction.
+	pub fn is_full(&self) -> bool {
+		let status = self.status();
+		self.options.ready.is_reached(status.ready, status.ready_bytes) &&
+			self.options.future.is_reached(status.future, status.future_bytes)
+	}
+
 	/// Notify all watchers that transactions in the block with hash have been finalized
 	pub async fn on_block_finalized(&self, block_hash: BlockHash<B>) -> Result<(), B::Error> {
 		log::trace!(
@@ -35,7 +35,11 @@ use std::sync::Arc;
 pub use api::FullChainApi;
 pub use builder::{Builder, TransactionPoolHandle, TransactionPoolOptions, TransactionPoolType};
 pub use common::notification_future;
-pub use fork_aware_txpool::{ForkAwareTxPool, ForkAwareTxPoolTask};
+pub use fork_aware_txpool::{
+	ExternalWatcherEvent, ExternalWatcherStream, ForkAwareTxPool, ForkAwareTxPoolTask,
+	TxClassification, TxDivergence, ViewPin, ViewSnapshot, ViewStoreEvent, ViewStoreEventKind,
+	ViewStoreSnapshot,
+};
 pub use graph::{
 	base_pool::{Limit as PoolLimit, TimedTransactionSource},
 	ChainApi, Options, Pool,
@@ -23,7 +23,9 @@ use fatp_common::{
 	test_chain_with_forks, LOG_TARGET, SOURCE,
 };
 use futures::{executor::block_on, task::Poll, FutureExt, StreamExt};
-use sc_transaction_pool::ChainApi;
+use sc_transaction_pool::{
+	ChainApi, ExternalWatcherEvent, ForkAwareTxPool, Options, ViewStoreEventKind,
+};
 use sc_transaction_pool_api::{
 	error::{Error as TxPoolError, IntoPoolError},
 	ChainEvent, MaintainedTransactionPool, TransactionPool, TransactionStatus,
@@ -31,7 +33,7 @@ use sc_transaction_pool_api::{
 use sp_runtime::transaction_validity::InvalidTransaction;
 use std::{sync::Arc, time::Duration};
 use substrate_test_runtime_client::Sr25519Keyring::*;
-use substrate_test_runtime_transaction_pool::uxt;
+use substrate_test_runtime_transaction_pool::{uxt, TestApi};
 
 pub mod fatp_common;
 
@@ -142,6 +144,30 @@ fn fatp_one_view_future_and_ready_submit_one_works() {
 	assert_pool_status!(header.hash(), &pool, 1, 1);
 }
 
+#[test]
+fn fatp_rebuild_from_indexes_mempool_transactions() {
+	sp_tracing::try_init_simple();
+
+	let (pool, api, _) = pool();
+
+	let header01 = api.push_block(1, vec![], true);
+	let event = new_best_block_event(&pool, None, header01.hash());
+	block_on(pool.maintain(event));
+
+	let xt0 = uxt(Alice, 200);
+	let xt1 = uxt(Alice, 202);
+	block_on(pool.submit_at(header01.hash(), SOURCE, vec![xt0.clone(), xt1.clone()])).unwrap();
+	assert_eq!(pool.transaction_count(), 2);
+
+	assert!(block_on(pool.rebuild_from(header01.hash())));
+
+	// The rebuilt view is filled from the mempool, and the transactions index follows it.
+	assert_pool_status!(header01.hash(), &pool, 1, 1);
+	assert!(pool.contains_transaction(&api.hash_and_length(&xt0).0));
+	assert!(pool.contains_transaction(&api.hash_and_length(&xt1).0));
+	assert_eq!(pool.transaction_count(), 2);
+}
+
 #[test]
 fn fatp_one_view_future_and_ready_submit_many_works() {
 	sp_tracing::try_init_simple();
@@ -877,6 +903,84 @@ fn fatp_watcher_invalid_fails_on_submission() {
 	));
 }
 
+#[test]
+fn fatp_watcher_first_event_carries_submission_block() {
+	sp_tracing::try_init_simple();
+
+	let (pool, api, _) = pool();
+
+	let header01 = api.push_block(1, vec![], true);
+	let event = new_best_block_event(&pool, None, header01.hash());
+	block_on(pool.maintain(event));
+
+	let xt0 = uxt(Alice, 200);
+	let xt0_watcher =
+		block_on(pool.submit_and_watch_at_block(header01.hash(), SOURCE, Arc::from(xt0.clone())))
+			.unwrap();
+
+	let header02 = api.push_block(2, vec![xt0], true);
+	let event = ChainEvent::Finalized {
+		hash: header02.hash(),
+		tree_route: Arc::from(vec![header01.hash()]),
+	};
+	block_on(pool.maintain(event));
+
+	let xt0_events = block_on(xt0_watcher.collect::<Vec<_>>());
+	assert_eq!(
+		xt0_events,
+		vec![
+			ExternalWatcherEvent {
+				status: TransactionStatus::Ready,
+				submitted_at: Some(header01.hash())
+			},
+			ExternalWatcherEvent {
+				status: TransactionStatus::InBlock((header02.hash(), 0)),
+				submitted_at: None
+			},
+			ExternalWatcherEvent {
+				status: TransactionStatus::Finalized((header02.hash(), 0)),
+				submitted_at: None
+			},
+		]
+	);
+}
+
+#[test]
+fn fatp_view_store_event_log_is_configured_by_options() {
+	sp_tracing::try_init_simple();
+
+	let api = Arc::from(TestApi::with_alice_nonce(200).enable_stale_check());
+	let new_pool = |options| {
+		ForkAwareTxPool::new_with_background_worker(
+			options,
+			true.into(),
+			api.clone(),
+			None,
+			sp_core::testing::TaskExecutor::new(),
+			api.genesis_hash(),
+			api.genesis_hash(),
+		)
+	};
+	// The event log is disabled by default...
+	let disabled_pool = new_pool(Options::default());
+	let enabled_pool =
+		new_pool(Options { view_store_event_log_capacity: Some(16), ..Default::default() });
+
+	let header01 = api.push_block(1, vec![], true);
+	for pool in [&disabled_pool, &enabled_pool] {
+		let event = new_best_block_event(pool, None, header01.hash());
+		block_on(pool.maintain(event));
+		assert_eq!(pool.active_views_count(), 1);
+	}
+
+	assert!(disabled_pool.recent_view_store_events().is_empty());
+	// ... and records the view lifecycle once enabled.
+	assert!(enabled_pool
+		.recent_view_store_events()
+		.iter()
+		.any(|event| event.kind == ViewStoreEventKind::ViewInserted(header01.hash())));
+}
+
 #[test]
 fn fatp_watcher_invalid_single_revalidation() {
 	sp_tracing::try_init_simple();
@@ -24,13 +24,13 @@ use fatp_common::{
 	finalized_block_event, invalid_hash, new_best_block_event, TestPoolBuilder, LOG_TARGET, SOURCE,
 };
 use futures::{executor::block_on, FutureExt};
-use sc_transaction_pool::ChainApi;
+use sc_transaction_pool::{ChainApi, ForkAwareTxPool, Options, PoolLimit};
 use sc_transaction_pool_api::{
 	error::Error as TxPoolError, MaintainedTransactionPool, TransactionPool, TransactionStatus,
 };
-use std::thread::sleep;
+use std::{sync::Arc, thread::sleep};
 use substrate_test_runtime_client::Sr25519Keyring::*;
-use substrate_test_runtime_transaction_pool::uxt;
+use substrate_test_runtime_transaction_pool::{uxt, TestApi};
 
 #[test]
 fn fatp_limits_no_views_mempool_count() {
@@ -829,3 +829,48 @@ fn fatp_limits_watcher_future_transactions_are_droped_when_view_is_dropped() {
 		assert_eq!(x_status, vec![TransactionStatus::Future]);
 	}
 }
+
+#[test]
+fn fatp_limits_all_views_saturated_submission_is_reported() {
+	sp_tracing::try_init_simple();
+
+	let api = Arc::from(TestApi::with_alice_nonce(200).enable_stale_check());
+	let registry = prometheus_endpoint::Registry::new();
+	let options = Options {
+		ready: PoolLimit { count: 1, total_bytes: 1_000_000 },
+		future: PoolLimit { count: 0, total_bytes: 1_000_000 },
+		..Default::default()
+	};
+	let pool = ForkAwareTxPool::new_with_background_worker(
+		options,
+		true.into(),
+		api.clone(),
+		Some(&registry),
+		sp_core::testing::TaskExecutor::new(),
+		api.genesis_hash(),
+		api.genesis_hash(),
+	);
+	let saturated_submissions = || {
+		registry
+			.gather()
+			.into_iter()
+			.find(|family| {
+				family.get_name() == "substrate_sub_txpool_all_views_saturated_submissions_total"
+			})
+			.map(|family| family.get_metric()[0].get_counter().get_value())
+			.expect("metric is registered")
+	};
+
+	let header01 = api.push_block(1, vec![], true);
+	let event = new_best_block_event(&pool, None, header01.hash());
+	block_on(pool.maintain(event));
+
+	// The only view has room for the ready transaction...
+	block_on(pool.submit_one(header01.hash(), SOURCE, uxt(Alice, 200))).unwrap();
+	assert_eq!(saturated_submissions(), 0.0);
+
+	// ... but is full once it is accepted, so the future transaction cannot enter any view.
+	let result = block_on(pool.submit_one(header01.hash(), SOURCE, uxt(Alice, 202)));
+	assert!(matches!(result.unwrap_err().0, TxPoolError::ImmediatelyDropped));
+	assert_eq!(saturated_submissions(), 1.0);
+}